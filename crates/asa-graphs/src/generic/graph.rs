@@ -1,32 +1,36 @@
 use std::{
-    rc::Rc,
-    cell::RefCell,
     cmp::Ordering::*,
     marker::PhantomData,
     fmt::{ Display, Formatter, Result as FmtResult },
-    io::{ self, Write }
+    io::{ self, Write },
+    ops::{ Bound, RangeBounds }
 };
 
 use anyhow::Result;
 
 use witchnet_common::{ 
     sensor::SensorData,
-    neuron::Neuron,
-    data::{ DataCategory, DataType, DataDeductor, DataTypeValue }, 
-    connection::collective::defining::{DefiningWeightingStrategy, ConstantOneWeight},
+    neuron::ActivationContext,
+    data::{ DataCategory, DataType, DataDeductor, DataTypeValue },
+    connection::collective::defining::ConstantOneWeight
 };
 
 use super::{
+    common::{
+        pointer::*,
+        neuron::Neuron,
+        collective::defining::DefiningWeightingStrategy
+    },
     element::Element,
     node::Node
 };
 
 pub struct ASAGraph<Key, const ORDER: usize = 25>
-where Key: SensorData, [(); ORDER + 1]: {
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
     pub id: u32,
-    pub(crate) root: Rc<RefCell<Node<Key, ORDER>>>,
-    pub element_min: Option<Rc<RefCell<Element<Key, ORDER>>>>,
-    pub element_max: Option<Rc<RefCell<Element<Key, ORDER>>>>,
+    pub(crate) root: Ptr<Lock<Node<Key, ORDER>>>,
+    pub element_min: Option<Ptr<Lock<Element<Key, ORDER>>>>,
+    pub element_max: Option<Ptr<Lock<Element<Key, ORDER>>>>,
     pub elements_counter: u32,
     pub key_min: Option<Key>,
     pub key_max: Option<Key>,
@@ -35,7 +39,7 @@ where Key: SensorData, [(); ORDER + 1]: {
 
 impl<Key, const ORDER: usize> ASAGraph<Key, ORDER> 
 where 
-    Key: SensorData, 
+    Key: SensorData + ThreadSafety, 
     [(); ORDER + 1]:, 
     PhantomData<Key>: DataDeductor,
     DataTypeValue: From<Key>
@@ -44,7 +48,7 @@ where
         if ORDER < 3 { panic!("Graph order must be >= 3") }
         ASAGraph {
             id,
-            root: Rc::new(RefCell::new(Node::<Key, ORDER>::new(true, None))),
+            root: Ptr::new(Lock::new(Node::<Key, ORDER>::new(true, None))),
             element_min: None,
             element_max: None,
             elements_counter: 0,
@@ -54,8 +58,8 @@ where
         }
     }
 
-    pub fn new_rc(id: u32) -> Rc<RefCell<ASAGraph<Key, ORDER>>> {
-        Rc::new(RefCell::new(ASAGraph::new(id)))
+    pub fn new_ptr(id: u32) -> Ptr<Lock<ASAGraph<Key, ORDER>>> {
+        Ptr::new(Lock::new(ASAGraph::new(id)))
     }
 
     pub fn new_box(id: u32) -> Box<ASAGraph<Key, ORDER>> {
@@ -71,7 +75,7 @@ where
     pub fn new_from_vec_custom(
         id: u32,
         data: &[Key],
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
         interelement_activation_threshold: f32,
         interelement_activation_exponent: i32
     ) -> Self {
@@ -85,18 +89,18 @@ where
         graph
     }
 
-    pub fn new_rc_from_vec(id: u32, data: &[Key]) -> Rc<RefCell<Self>> {
-        Rc::new(RefCell::new(Self::new_from_vec(id, data)))
+    pub fn new_ptr_from_vec(id: u32, data: &[Key]) -> Ptr<Lock<Self>> {
+        Ptr::new(Lock::new(Self::new_from_vec(id, data)))
     }
     
-    pub fn new_rc_from_vec_custom(
+    pub fn new_ptr_from_vec_custom(
         id: u32, 
         data: &[Key], 
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
         interelement_activation_threshold: f32,
         interelement_activation_exponent: i32
-    ) -> Rc<RefCell<Self>> {
-        Rc::new(RefCell::new(Self::new_from_vec_custom(
+    ) -> Ptr<Lock<Self>> {
+        Ptr::new(Lock::new(Self::new_from_vec_custom(
             id, 
             data, 
             weighting_strategy,
@@ -112,7 +116,7 @@ where
     pub fn new_box_from_vec_custom(
         id: u32, 
         data: &[Key], 
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
         interelement_activation_threshold: f32,
         interelement_activation_exponent: i32
     ) -> Box<Self> {
@@ -133,7 +137,7 @@ where
 
     pub fn fuzzy_search(
         &mut self, item: &Key, threshold: f32, perserve_inserted_neuron: bool
-    ) -> Option<(Rc<RefCell<Element<Key, ORDER>>>, f32)> {
+    ) -> Option<(Ptr<Lock<Element<Key, ORDER>>>, f32)> {
         if let Some(element) = self.search(item) {
             return Some((element, 1.0))
         } else {
@@ -152,7 +156,7 @@ where
                         let prev_element = prev.0.upgrade().unwrap();
                         let prev_weight = prev.1;
                         if next_weight > prev_weight {
-                            if next_weight >= threshold { 
+                            if next_weight >= threshold {
                                 should_remove = true;
                                 Some((next_element, next_weight)) 
                             } else {
@@ -210,24 +214,26 @@ where
         }
     }
 
-    pub fn search(&self, key: &Key) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+    pub fn search(&self, key: &Key) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
         let node = &self.root;
         
         let (key_min, key_max) = self.extreme_keys()?;
 
         if key.distance(key_max) > key.distance(key_min) {
-            return Self::search_left(key, &*node.borrow())
+            return Self::search_left(key, node.clone())
         } else {
-            return Self::search_right(key, &*node.borrow())
+            return Self::search_right(key, node.clone())
         }
     }
 
     fn search_left<'a, 'b>(
-        key: &'a Key, mut node: &'b Node<Key, ORDER>
-    ) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        key: &'a Key, mut node_ptr: Ptr<Lock<Node<Key, ORDER>>>
+    ) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
         loop {
-            let mut index = 0;
+            let next_node_ptr;
             {
+                let node = node_ptr.borrow();
+                let mut index = 0;
                 let mut current_key = node.keys[index].as_ref().unwrap();
                 
                 while index < node.size && key.partial_compare(current_key) == Some(Greater) {
@@ -236,26 +242,30 @@ where
                         current_key = node.keys[index].as_ref().unwrap();
                     }
                 }
-
+    
                 if index < node.size && key.equals(current_key) {
                     let element = node.elements[index].as_ref().unwrap().clone();
                     return Some(element)
                 } else if node.is_leaf {
                     return None
                 }
+
+                next_node_ptr = node.children[index].as_ref().unwrap().clone();
             }
-                
-            let node_ptr = node.children[index].as_ref().unwrap();
-            unsafe { node = node_ptr.try_borrow_unguarded().unwrap() };
+            
+            node_ptr = next_node_ptr;
         }
     }
 
     fn search_right<'a, 'b>(
-        key: &'a Key, mut node: &'b Node<Key, ORDER>
-    ) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        key: &'a Key, mut node_ptr: Ptr<Lock<Node<Key, ORDER>>>
+    ) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
         loop {
-            let mut index = node.size - 1;
+            let next_node_ptr;
             {
+                let node = node_ptr.borrow();
+                let mut index = node.size - 1;
+
                 let mut current_key = node.keys[index].as_ref().unwrap();
                 
                 while index > 0 && key.partial_compare(current_key) == Some(Less) {
@@ -271,23 +281,24 @@ where
                 } else if key.partial_compare(current_key) == Some(Greater) {
                     index += 1;
                 }
+
+                next_node_ptr = node.children[index].as_ref().unwrap().clone();
             }
-            let node_ptr = node.children[index].as_ref().unwrap();
-            unsafe { node = node_ptr.try_borrow_unguarded().unwrap() };
+            node_ptr = next_node_ptr;
         }
     }
 
-    pub fn insert(&mut self, key: &Key) -> Rc<RefCell<Element<Key, ORDER>>> {
-        self.insert_custom(key, Rc::new(ConstantOneWeight), 0.00001, 1)
+    pub fn insert(&mut self, key: &Key) -> Ptr<Lock<Element<Key, ORDER>>> {
+        self.insert_custom(key, Ptr::new(ConstantOneWeight), 0.00001, 1)
     }
 
     pub fn insert_custom(
         &mut self, 
         key: &Key, 
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
         interelement_activation_threshold: f32,
         interelement_activation_exponent: i32
-    ) -> Rc<RefCell<Element<Key, ORDER>>> {
+    ) -> Ptr<Lock<Element<Key, ORDER>>> {
         let mut node = self.root.clone();
 
         if node.borrow().size == 0 { return self.insert_first_element(
@@ -320,7 +331,7 @@ where
                     key,
                     element_id,
                     self.id,
-                    self.range(),
+                    self.span(),
                     weighting_strategy, 
                     interelement_activation_threshold,
                     interelement_activation_exponent
@@ -355,7 +366,8 @@ where
             None => return false
         };
         if key.equals(&key_min) {
-            let next_element_min = match &self.element_min.as_ref().unwrap().borrow().next {
+            let el_min = self.element_min.as_ref().unwrap();
+            let next_element_min = match &el_min.borrow().next {
                 Some((el, _)) => Some(el.upgrade().unwrap().clone()),
                 None => None
             };
@@ -366,7 +378,8 @@ where
             };
         }
         if key.equals(&key_max) {
-            let next_element_max = match &self.element_max.as_ref().unwrap().borrow().prev {
+            let el_max = self.element_max.as_ref().unwrap();
+            let next_element_max = match &el_max.borrow().prev {
                 Some((el, _)) => Some(el.upgrade().unwrap().clone()),
                 None => None
             };
@@ -400,8 +413,8 @@ where
         let mut current_node = Some(self.root.clone());
         if current_node.as_ref().unwrap().borrow().size == 0 { return false }
 
-        let mut parent: Option<Rc<RefCell<Node<Key, ORDER>>>> = None;
-        let mut parent_of_parent: Option<Rc<RefCell<Node<Key, ORDER>>>> = None;
+        let mut parent: Option<Ptr<Lock<Node<Key, ORDER>>>> = None;
+        let mut parent_of_parent: Option<Ptr<Lock<Node<Key, ORDER>>>> = None;
         let mut parent_index: Option<usize> = None;
         let mut child_index: Option<usize> = None;
 
@@ -422,14 +435,14 @@ where
                         // 3. remove the element storing this key from this leaf, switch pointers
                         //    from its predecessor and successor to point themselves 
                         //    as direct neighbors.
-                        node.borrow_mut().remove_element(index, self.range());
+                        node.borrow_mut().remove_element(index, self.span());
                          // 3. Next, if this leaf is not empty, finish the remove operation, 
                          //    else go to step 6 to fill or remove this empty leaf
                         if node.borrow().size > 0 {
                             return true
                         } else {
                             // 6. Empty leaf
-                            if !Rc::ptr_eq(&node, &self.root) {
+                            if !Ptr::ptr_eq(&node, &self.root) {
                                 self.empty_node(
                                     parent.unwrap().clone(),
                                     child_index.unwrap(),
@@ -476,7 +489,7 @@ where
                         if left_leaf_size >= 2 {
                             let mut node_deref = node.borrow_mut();
                             let mut left_leaf = left_leaf.as_mut().unwrap().borrow_mut();
-                            node_deref.remove_element_without_shift(index, self.range());
+                            node_deref.remove_element_without_shift(index, self.span());
                             node_deref.keys[index] = left_leaf.keys[left_leaf_size - 1].take();
                             node_deref.elements[index] = left_leaf.elements[left_leaf_size - 1].take();
                             left_leaf.size -= 1;
@@ -484,7 +497,7 @@ where
                         } else if right_leaf_size >= 2 {
                             let mut node_deref = node.borrow_mut();
                             let mut right_leaf = right_leaf.as_mut().unwrap().borrow_mut();
-                            node_deref.remove_element_without_shift(index, self.range());
+                            node_deref.remove_element_without_shift(index, self.span());
                             node_deref.keys[index] = right_leaf.keys[0].take();
                             node_deref.elements[index] = right_leaf.elements[0].take();
                             right_leaf.remove_element_soft(0);
@@ -499,14 +512,14 @@ where
                                     let mut left_leaf = left_leaf.as_mut().unwrap().borrow_mut();
                                     let mut node_deref = node.borrow_mut();
                                     node_deref.elements[index].as_mut().unwrap().borrow_mut()
-                                        .remove_connections(self.range());
+                                        .remove_connections(self.span());
                                     node_deref.keys[index] = left_leaf.keys[0].take();
                                     node_deref.elements[index] = left_leaf.elements[0].take();
                                     left_leaf.size -= 1;
                                 }
                                 // 6. Empty leaf
                                 let left_leaf_parent_ptr = left_leaf_parent.as_ref().unwrap();
-                                let leaf_index = if Rc::ptr_eq(left_leaf_parent_ptr, node) { 
+                                let leaf_index = if Ptr::ptr_eq(left_leaf_parent_ptr, node) { 
                                     index 
                                 } else {
                                     match left_leaf_parent_ptr.try_borrow() {
@@ -515,11 +528,11 @@ where
                                     }
                                 };
                                 let leaf_parent_index = if left_leaf_parent_of_parent.is_some() &&
-                                    Rc::ptr_eq(
+                                    Ptr::ptr_eq(
                                         left_leaf_parent_of_parent.as_ref().unwrap(), &node
                                     ) { Some(index) } else {
                                     if left_leaf_parent_of_parent.is_some() && 
-                                        parent.is_some() && Rc::ptr_eq(
+                                        parent.is_some() && Ptr::ptr_eq(
                                             left_leaf_parent_of_parent.as_ref().unwrap(), 
                                             &parent.as_ref().unwrap()
                                     ) { child_index } else {
@@ -543,21 +556,21 @@ where
                                     let mut right_leaf = right_leaf.as_mut().unwrap().borrow_mut();
                                     let mut node_deref = node.borrow_mut();
                                     node_deref.elements[index].as_mut().unwrap().borrow_mut()
-                                        .remove_connections(self.range());
+                                        .remove_connections(self.span());
                                     node_deref.keys[index] = right_leaf.keys[0].take();
                                     node_deref.elements[index] = right_leaf.elements[0].take();
                                     right_leaf.size -= 1;
                                 }
                                 // 6. Empty leaf
-                                let leaf_index = if Rc::ptr_eq(
+                                let leaf_index = if Ptr::ptr_eq(
                                     left_leaf_parent.as_ref().unwrap(), &node
                                 ) { index + 1 } else { 0 };
                                 let leaf_parent_index = if left_leaf_parent_of_parent.is_some() &&
-                                    Rc::ptr_eq(
+                                    Ptr::ptr_eq(
                                         left_leaf_parent_of_parent.as_ref().unwrap(), &node
                                 ) { Some(index + 1) } else {
                                     if left_leaf_parent_of_parent.is_some() && 
-                                        parent.is_some() && Rc::ptr_eq(
+                                        parent.is_some() && Ptr::ptr_eq(
                                             left_leaf_parent_of_parent.as_ref().unwrap(), 
                                             &parent.as_ref().unwrap()
                                     ) { child_index } else { 
@@ -598,9 +611,9 @@ where
 
     fn empty_node(
         &mut self, 
-        node: Rc<RefCell<Node<Key, ORDER>>>,
+        node: Ptr<Lock<Node<Key, ORDER>>>,
         index: usize,
-        parent: Option<Rc<RefCell<Node<Key, ORDER>>>>,
+        parent: Option<Ptr<Lock<Node<Key, ORDER>>>>,
         parent_index: Option<usize>
     ) {
         // 6. If one of the nearest siblings of the empty leaf contains 
@@ -737,7 +750,7 @@ where
                     }
                     // 9. Continue in step 9 to rebalance the tree because leaves 
                     //    are not at the same level. Next finish
-                    if !Rc::ptr_eq(&node, &self.root) {
+                    if !Ptr::ptr_eq(&node, &self.root) {
                         self.rebalance(
                             node.clone(),
                             &mut node_deref,
@@ -752,9 +765,9 @@ where
 
     pub fn rebalance(
         &mut self, 
-        node: Rc<RefCell<Node<Key, ORDER>>>, 
+        node: Ptr<Lock<Node<Key, ORDER>>>, 
         mut node_deref: &mut Node<Key, ORDER>, 
-        parent: Rc<RefCell<Node<Key, ORDER>>>,
+        parent: Ptr<Lock<Node<Key, ORDER>>>,
         parent_index: usize
     ) {
         // 9. If one of the sibling node of the reduced subtree root contains 
@@ -777,7 +790,7 @@ where
             let mut l_sibling_deref = l_sibling.as_ref().unwrap().borrow_mut();
             let l_sibling_size = l_sibling_deref.size;
 
-            let new_node = Rc::new(RefCell::new(Node::<Key, ORDER>::new(false, None)));
+            let new_node = Ptr::new(Lock::new(Node::<Key, ORDER>::new(false, None)));
             let mut new_node_deref = new_node.borrow_mut();
             new_node_deref.keys[0] = parent_deref.keys[parent_index - 1].take();
             new_node_deref.elements[0] = parent_deref.elements[parent_index - 1].take();
@@ -785,10 +798,10 @@ where
             new_node_deref.children[0] = l_sibling_deref.children[l_sibling_size].take();
             new_node_deref.children[1] = Some(node.clone());
 
-            new_node_deref.parent = Some(Rc::downgrade(&parent));
-            node_deref.parent = Some(Rc::downgrade(&new_node));
+            new_node_deref.parent = Some(Ptr::downgrade(&parent));
+            node_deref.parent = Some(Ptr::downgrade(&new_node));
             new_node_deref.children[0].as_mut().unwrap().borrow_mut().parent 
-                = Some(Rc::downgrade(&new_node));
+                = Some(Ptr::downgrade(&new_node));
 
             parent_deref.children[parent_index] = Some(new_node.clone());
             parent_deref.keys[parent_index - 1] 
@@ -801,7 +814,7 @@ where
         } else if r_sibling.is_some() && r_sibling.as_ref().unwrap().borrow().size > 1 {
             let mut r_sibling_deref = r_sibling.as_ref().unwrap().borrow_mut();
 
-            let new_node = Rc::new(RefCell::new(Node::<Key, ORDER>::new(false, None)));
+            let new_node = Ptr::new(Lock::new(Node::<Key, ORDER>::new(false, None)));
             let mut new_node_deref = new_node.borrow_mut();
             new_node_deref.keys[0] = parent_deref.keys[parent_index].take();
             new_node_deref.elements[0] = parent_deref.elements[parent_index].take();
@@ -809,10 +822,10 @@ where
             new_node_deref.children[0] = Some(node.clone());
             new_node_deref.children[1] = r_sibling_deref.children[0].take();
 
-            new_node_deref.parent = Some(Rc::downgrade(&parent));
-            node_deref.parent = Some(Rc::downgrade(&new_node));
+            new_node_deref.parent = Some(Ptr::downgrade(&parent));
+            node_deref.parent = Some(Ptr::downgrade(&new_node));
             new_node_deref.children[1].as_mut().unwrap().borrow_mut().parent 
-                = Some(Rc::downgrade(&new_node));
+                = Some(Ptr::downgrade(&new_node));
 
             parent_deref.children[parent_index] = Some(new_node.clone());
             parent_deref.keys[parent_index] = r_sibling_deref.keys[0].take();
@@ -839,7 +852,7 @@ where
             l_sibling_deref.children[l_sibling_size] = Some(node.clone());
             parent_deref.remove_element_soft(parent_index - 1);
             parent_deref.shift_left_children(parent_index);
-            node_deref.parent = Some(Rc::downgrade(&l_sibling));
+            node_deref.parent = Some(Ptr::downgrade(&l_sibling));
         } else if let Some(r_sibling) = r_sibling {
             let mut r_sibling_deref = r_sibling.borrow_mut();
 
@@ -851,11 +864,11 @@ where
             r_sibling_deref.children[0] = Some(node.clone());
             parent_deref.remove_element_soft(parent_index);
             parent_deref.shift_left_children(parent_index);
-            node_deref.parent = Some(Rc::downgrade(&r_sibling));
+            node_deref.parent = Some(Ptr::downgrade(&r_sibling));
         }
         if parent_deref.size >= 1 {
             return
-        } else if Rc::ptr_eq(&parent, &self.root) {
+        } else if Ptr::ptr_eq(&parent, &self.root) {
             self.root = parent_deref.children[0].as_ref().unwrap().clone();
             self.root.borrow_mut().parent = None;
             return
@@ -884,7 +897,9 @@ where
         }
     }
 
-    pub fn range(&self) -> f32 { 
+    /// Distance between the minimal and the maximal key, named `range()` before
+    /// `range(RangeBounds)` took that name
+    pub fn span(&self) -> f32 { 
         if self.key_min.is_none() || self.key_max.is_none() { return f32::NAN }
         let ret = self.key_min.as_ref().unwrap().distance(self.key_max.as_ref().unwrap()) as f32;
         if ret == 0.0f32 { 1.0f32 } else { ret }
@@ -893,7 +908,7 @@ where
     pub fn print_graph(&self) {
         let mut height = 0;
         let mut node = self.root.clone();
-        let mut queue: Vec<Vec<Rc<RefCell<Node<Key, ORDER>>>>> = vec![vec![]];
+        let mut queue: Vec<Vec<Ptr<Lock<Node<Key, ORDER>>>>> = vec![vec![]];
         queue[0].push(node.clone());
 
         loop {
@@ -927,7 +942,7 @@ where
     pub fn test_graph(&self, print_if_ok: bool) -> bool {
         let mut height = 0;
         let mut node = self.root.clone();
-        let mut queue: Vec<Vec<Rc<RefCell<Node<Key, ORDER>>>>> = vec![vec![]];
+        let mut queue: Vec<Vec<Ptr<Lock<Node<Key, ORDER>>>>> = vec![vec![]];
         queue[0].push(node.clone());
 
         let mut is_ok = true;
@@ -983,12 +998,12 @@ where
 
     fn insert_first_element(
         &mut self,
-        node: &Rc<RefCell<Node<Key, ORDER>>>,
+        node: &Ptr<Lock<Node<Key, ORDER>>>,
         key: &Key,
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
         interelement_activation_threshold: f32,
         interelement_activation_exponent: i32
-    ) -> Rc<RefCell<Element<Key, ORDER>>> {
+    ) -> Ptr<Lock<Element<Key, ORDER>>> {
         let element_pointer = Element::<Key, ORDER>::new_custom(
             key, 
             1, 
@@ -1011,40 +1026,43 @@ where
         element_pointer
     }
 
-    fn split_root(&mut self) -> Rc<RefCell<Node<Key, ORDER>>> {
-        let new_root = Rc::new(RefCell::new(Node::new(false, None)));
+    fn split_root(&mut self) -> Ptr<Lock<Node<Key, ORDER>>> {
+        let new_root = Ptr::new(Lock::new(Node::new(false, None)));
         let old_root = self.root.clone();
         self.root = new_root;
-        old_root.borrow_mut().parent = Some(Rc::downgrade(&self.root));
+        old_root.borrow_mut().parent = Some(Ptr::downgrade(&self.root));
         self.root.borrow_mut().children[0] = Some(old_root);
         Node::split_child(&self.root, 0);
         self.root.clone()
     }
 
-    fn set_extrema(&mut self, element: &Rc<RefCell<Element<Key, ORDER>>>) {
-        let key = &element.borrow().key;
+    fn set_extrema(&mut self, element: &Ptr<Lock<Element<Key, ORDER>>>) {
         let key_min = &self.key_min;
         let key_max = &self.key_max;
+
         let mut should_update_weights = false;
-        if key_min.is_none() != key_max.is_none() {
-            panic!("inconsistent extremas: key_min.is_none() != key_max.is_none()")
-        } else if self.key_min.is_none() || self.key_max.is_none() {
-            self.key_min = Some(*dyn_clone::clone_box(key));
-            self.key_max = Some(*dyn_clone::clone_box(key));
-            self.element_min = Some(element.clone());
-            self.element_max = Some(element.clone());
-            should_update_weights = true;
-        } else {
-            if key.partial_compare(key_min.as_ref().unwrap()) == Some(Less) {
+        {
+            let key = &element.borrow().key;
+            if key_min.is_none() != key_max.is_none() {
+                panic!("inconsistent extremas: key_min.is_none() != key_max.is_none()")
+            } else if self.key_min.is_none() || self.key_max.is_none() {
                 self.key_min = Some(*dyn_clone::clone_box(key));
-                self.element_min = Some(element.clone());
-                should_update_weights = true;
-            }
-            if key.partial_compare(key_max.as_ref().unwrap()) == Some(Greater) {
                 self.key_max = Some(*dyn_clone::clone_box(key));
+                self.element_min = Some(element.clone());
                 self.element_max = Some(element.clone());
                 should_update_weights = true;
-            }   
+            } else {
+                if key.partial_compare(key_min.as_ref().unwrap()) == Some(Less) {
+                    self.key_min = Some(*dyn_clone::clone_box(key));
+                    self.element_min = Some(element.clone());
+                    should_update_weights = true;
+                }
+                if key.partial_compare(key_max.as_ref().unwrap()) == Some(Greater) {
+                    self.key_max = Some(*dyn_clone::clone_box(key));
+                    self.element_max = Some(element.clone());
+                    should_update_weights = true;
+                }   
+            }
         }
 
         if should_update_weights {
@@ -1054,36 +1072,25 @@ where
     }
 
     fn update_elements_weights(&mut self, range: f32) {
-        let mut element = match &self.element_min {
+        let mut prev_element_ptr = match &self.element_min {
             Some(e) => e.clone(),
             None => return
         };
-        loop {
-            match &element.borrow().prev {
-                Some(e) => {
-                    let element_ptr = element.as_ptr();
-                    let prev_element = e.0.upgrade().unwrap().clone();
-                    let prev_element_ptr = prev_element.as_ptr();
-                    let weight = element.borrow().weight(&*prev_element.borrow(), range);
-                    unsafe { (&mut *element_ptr).prev = Some((Rc::downgrade(&prev_element), weight)) };
-                    unsafe { (&mut *prev_element_ptr).next = Some((Rc::downgrade(&element), weight)) };
-                },
-                None => {}
-            };
 
-            let next_element= match &element.borrow().next {
-                Some(e) => {
-                    let element_ptr = element.as_ptr();
-                    let next_element = e.0.upgrade().unwrap().clone();
-                    let next_element_ptr = next_element.as_ptr();
-                    let weight = element.borrow().weight(&*next_element.borrow(), range);
-                    unsafe { (&mut *element_ptr).next = Some((Rc::downgrade(&next_element), weight)) };
-                    unsafe { (&mut *next_element_ptr).prev = Some((Rc::downgrade(&element), weight)) };
-                    next_element
-                },
-                None => return
-            };
-            element = next_element;
+        loop {
+            let next_element_ptr;
+            {
+                let mut prev_element = prev_element_ptr.borrow_mut();
+                let next_element_tuple = prev_element.next.clone();
+                if next_element_tuple.is_none() { break }
+                next_element_ptr = next_element_tuple.unwrap().0.clone().upgrade().unwrap();
+                let mut next_element = next_element_ptr.borrow_mut();
+                let weight = prev_element.weight(&next_element, range);
+                prev_element.next.as_mut().unwrap().1 = weight;
+                next_element.prev.as_mut().unwrap().1 = weight;
+            }
+            prev_element_ptr = next_element_ptr;
+            
         }
     }
 
@@ -1153,6 +1160,94 @@ where
         ))
     }
 
+    pub fn activate_in(
+        &self, 
+        key: &Key, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool,
+        context: &mut ActivationContext
+    ) -> Result<f32> {
+        let element = match self.search(key) {
+            Some(e) => e,
+            None => {
+                let is_fuzzy_ok = match self.data_category() {
+                    DataCategory::Continuous | DataCategory::Discrete => true,
+                    DataCategory::Categorical => false
+                };
+                if !propagate_horizontal || !is_fuzzy_ok {
+                    anyhow::bail!("activating missing sensory neuron {} in context", key)
+                }
+                return Ok(self.activate_missing_in(key, signal, propagate_vertical, context))
+            }
+        };
+
+        let activation = element.borrow().activate_in(
+            signal, propagate_horizontal, propagate_vertical, context
+        );
+        Ok(activation)
+    }
+
+    /// Fuzzy activation in `context` of the neighbours of a missing `key` as if it was inserted,
+    /// leaving the graph unchanged
+    fn activate_missing_in(
+        &self, key: &Key, signal: f32, propagate_vertical: bool, context: &mut ActivationContext
+    ) -> f32 {
+        let next = self.upper_bound(key);
+        let prev = match &next {
+            Some(next) => next.borrow().prev.as_ref().map(|(prev, _)| prev.upgrade().unwrap()),
+            None => self.element_max.clone()
+        };
+
+        // inserting a key out of the extrema would reweight all the connections
+        let (key_min, key_max) = match (&self.key_min, &self.key_max) {
+            (Some(key_min), Some(key_max)) => (key_min, key_max),
+            _ => return 0.0f32
+        };
+        let respan = if key.partial_compare(key_min) == Some(Less) {
+            Some(key.distance(key_max) as f32)
+        } else if key.partial_compare(key_max) == Some(Greater) {
+            Some(key.distance(key_min) as f32)
+        } else {
+            None
+        };
+        let span = respan.unwrap_or_else(|| self.span());
+
+        let mut neurons_activation = Vec::new();
+        for (is_next, neighbour) in [(true, next), (false, prev)] {
+            let neighbour = match neighbour {
+                Some(neighbour) => neighbour,
+                None => continue
+            };
+            let (weight, threshold, exponent) = {
+                let element = neighbour.borrow();
+                (
+                    element.weight_to_key(key, span),
+                    element.interelement_activation_threshold,
+                    element.interelement_activation_exponent
+                )
+            };
+            neurons_activation.extend(Element::context_spread(
+                Some((Ptr::downgrade(&neighbour), weight)),
+                is_next,
+                signal,
+                threshold,
+                exponent,
+                respan,
+                context
+            ));
+        }
+
+        let mut max_activation = 0.0f32;
+        if propagate_vertical {
+            for (neuron, activation) in &neurons_activation {
+                max_activation = f32::max(max_activation, *activation);
+                neuron.borrow().activate_in(*activation, true, true, context);
+            }
+        }
+        max_activation
+    }
+
     pub fn activate_defining(&mut self, key: &Key, signal: f32) -> Result<f32> {
         let element = match self.search(key) {
             Some(e) => e,
@@ -1194,12 +1289,88 @@ where
         }
     }
 
-    pub fn levels(&self) -> Vec<Vec<Vec<Rc<RefCell<Element<Key, ORDER>>>>>> {
+    pub fn first(&self) -> Option<Ptr<Lock<Element<Key, ORDER>>>> { 
+        self.element_min.clone() 
+    }
+
+    pub fn last(&self) -> Option<Ptr<Lock<Element<Key, ORDER>>>> { 
+        self.element_max.clone() 
+    }
+
+    /// first element with key not less than `key`
+    pub fn lower_bound(&self, key: &Key) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
+        self.bound(key, true)
+    }
+
+    /// first element with key greater than `key`
+    pub fn upper_bound(&self, key: &Key) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
+        self.bound(key, false)
+    }
+
+    fn bound(&self, key: &Key, inclusive: bool) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
+        let mut result = None;
+        let mut node_ptr = self.root.clone();
+        loop {
+            let child_ptr = {
+                let node = node_ptr.borrow();
+                let mut index = 0;
+                while index < node.size {
+                    let current_key = node.keys[index].as_ref().unwrap();
+                    let is_bound = current_key.partial_compare(key) == Some(Greater)
+                        || (inclusive && current_key.equals(key));
+                    if is_bound { break }
+                    index += 1;
+                }
+                if index < node.size {
+                    result = node.elements[index].clone();
+                    let current_key = node.keys[index].as_ref().unwrap();
+                    if inclusive && current_key.equals(key) { return result }
+                }
+                if node.is_leaf { return result }
+                node.children[index].clone().unwrap()
+            };
+            node_ptr = child_ptr;
+        }
+    }
+
+    pub fn range<R: RangeBounds<Key>>(&self, range: R) 
+    -> Vec<Ptr<Lock<Element<Key, ORDER>>>> {
+        let mut element = match range.start_bound() {
+            Bound::Included(lo) => self.lower_bound(lo),
+            Bound::Excluded(lo) => self.upper_bound(lo),
+            Bound::Unbounded => self.first()
+        };
+
+        let mut ret = Vec::new();
+        while let Some(current) = element {
+            let is_in_range = {
+                let key = &current.borrow().key;
+                match range.end_bound() {
+                    Bound::Included(hi) => key.partial_compare(hi) != Some(Greater),
+                    Bound::Excluded(hi) => key.partial_compare(hi) == Some(Less),
+                    Bound::Unbounded => true
+                }
+            };
+            if !is_in_range { break }
+            element = current.borrow().next.as_ref().and_then(|next| next.0.upgrade());
+            ret.push(current);
+        }
+        ret
+    }
+
+    pub fn iter(&self) -> ASAGraphIntoIterator<'_, Key, ORDER> { self.into_iter() }
+
+    pub fn iter_rev(&self) -> ASAGraphRevIterator<'_, Key, ORDER> {
+        ASAGraphRevIterator { graph: self, index: self.element_max.clone() }
+    }
+
+    pub fn levels(&self) 
+    -> Vec<Vec<Vec<Ptr<Lock<Element<Key, ORDER>>>>>> {
         let mut ret = Vec::new();
 
         let mut height = 0;
         let mut node = self.root.clone();
-        let mut queue: Vec<Vec<Rc<RefCell<Node<Key, ORDER>>>>> = vec![vec![]];
+        let mut queue: Vec<Vec<Ptr<Lock<Node<Key, ORDER>>>>> = vec![vec![]];
         queue[0].push(node.clone());
 
         loop {
@@ -1235,7 +1406,7 @@ where
 }
 
 impl<'a, Key, const ORDER: usize> Clone for ASAGraph<Key, ORDER> 
-where Key: SensorData, [(); ORDER + 1]: {
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
     fn clone(&self) -> Self {
         ASAGraph {
             id: self.id,
@@ -1255,8 +1426,8 @@ where Key: SensorData, [(); ORDER + 1]: {
 }
 
 impl<'a, Key, const ORDER: usize> IntoIterator for &'a ASAGraph<Key, ORDER> 
-where Key: SensorData, [(); ORDER + 1]: {
-    type Item = Rc<RefCell<Element<Key, ORDER>>>;
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
+    type Item = Ptr<Lock<Element<Key, ORDER>>>;
     type IntoIter = ASAGraphIntoIterator<'a, Key, ORDER>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -1271,15 +1442,15 @@ where Key: SensorData, [(); ORDER + 1]: {
 }
 
 pub struct ASAGraphIntoIterator<'a, Key, const ORDER: usize = 25>
-where Key: SensorData, [(); ORDER + 1]: {
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
     graph: &'a ASAGraph<Key, ORDER>,
-    index: Option<Rc<RefCell<Element<Key, ORDER>>>>
+    index: Option<Ptr<Lock<Element<Key, ORDER>>>>
 }
 
 impl<'a, Key, const ORDER: usize> Iterator for ASAGraphIntoIterator<'a, Key, ORDER> 
-where Key: SensorData, [(); ORDER + 1]: {
-    type Item = Rc<RefCell<Element<Key, ORDER>>>;
-    fn next(&mut self) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
+    type Item = Ptr<Lock<Element<Key, ORDER>>>;
+    fn next(&mut self) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
         let next_option;
         let result = match self.index.clone() {
             Some(element) => {
@@ -1302,12 +1473,28 @@ where Key: SensorData, [(); ORDER + 1]: {
     }
 }
 
+pub struct ASAGraphRevIterator<'a, Key, const ORDER: usize = 25>
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
+    graph: &'a ASAGraph<Key, ORDER>,
+    index: Option<Ptr<Lock<Element<Key, ORDER>>>>
+}
+
+impl<'a, Key, const ORDER: usize> Iterator for ASAGraphRevIterator<'a, Key, ORDER> 
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
+    type Item = Ptr<Lock<Element<Key, ORDER>>>;
+    fn next(&mut self) -> Option<Ptr<Lock<Element<Key, ORDER>>>> {
+        let element = self.index.take()?;
+        self.index = element.borrow().prev.as_ref().and_then(|prev| prev.0.upgrade());
+        Some(element)
+    }
+}
+
 impl<Key, const ORDER: usize> Display for ASAGraph<Key, ORDER> 
-where Key: SensorData, [(); ORDER + 1]: {
+where Key: SensorData + ThreadSafety, [(); ORDER + 1]: {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         let mut height = 0;
         let mut node = self.root.clone();
-        let mut queue: Vec<Vec<Rc<RefCell<Node<Key, ORDER>>>>> = vec![vec![]];
+        let mut queue: Vec<Vec<Ptr<Lock<Node<Key, ORDER>>>>> = vec![vec![]];
         queue[0].push(node.clone());
 
         loop {
@@ -1339,312 +1526,378 @@ where Key: SensorData, [(); ORDER + 1]: {
     }
 }
 
-#[cfg(test)]
-pub mod tests {
-    use std::{ time::Instant };
-    
-    use rand::{
-        Rng, 
-        seq::SliceRandom,
-        rngs::StdRng,
-        SeedableRng
-    };
-
-    use super::ASAGraph;
-
-    #[test]
-    fn create_empty_graph() {
-        ASAGraph::<i32, 3>::new(1);
-    }
+generic_tests! {
+    #[cfg(test)]
+    pub mod tests {
+        use std::{ time::Instant };
 
-    #[test]
-    fn create_100_elements_graph() {
-        let mut rng = rand::thread_rng();
+        use rand::{
+            Rng, 
+            seq::SliceRandom,
+            rngs::StdRng,
+            SeedableRng
+        };
 
-        let start = Instant::now();
+        use witchnet_common::{ data::DataTypeValue, neuron::ActivationContext };
 
-        let mut graph = Box::new(ASAGraph::<i32, 3>::new(1));
+        use super::{
+            ASAGraph,
+            super::common::neuron::Neuron
+        };
 
-        let n = 1_000;
-        for _ in 0..n {
-            let number: i32 = rng.gen();
-            graph.insert(&number);
+        #[test]
+        fn create_empty_graph() {
+            ASAGraph::<i32, 3>::new(1);
         }
 
-        let duration = start.elapsed();
+        #[test]
+        fn create_1000_elements_graph() {
+            let mut rng = rand::thread_rng();
 
-        println!("Time elapsed for ASAGraph insertion of {} elements is is: {:?}", n, duration);
-    }
+            let start = Instant::now();
 
-    #[test]
-    fn print_graph() {
-        let mut rng = rand::thread_rng();
+            let graph = ASAGraph::<i32, 3>::new_ptr(1);
+
+            let n = 1000;
+            for _ in (0..n).rev() {
+                let random_number: i32 = rng.gen_range(0..10000);
+                graph.borrow_mut().insert(&random_number);
+            }
 
-        let mut graph = ASAGraph::<i32, 5>::new(1);
+            let duration = start.elapsed();
 
-        for _ in 0..50 {
-            let number: i32 = rng.gen_range(1..=20);
-            graph.insert(&number);
+            println!("Time elapsed for ASAGraph insertion of {} elements is is: {:?}", n, duration);
         }
 
-        graph.print_graph();
-    }
+        #[test]
+        fn print_graph() {
+            let mut rng = rand::thread_rng();
 
-    #[test]
-    fn insert_3_degree() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
+            let mut graph = ASAGraph::<i32, 5>::new(1);
 
-        for i in 1..=250 {
-            graph.insert(&i);
-        }
+            for _ in 0..50 {
+                let number: i32 = rng.gen_range(1..=20);
+                graph.insert(&number);
+            }
 
-        for i in (150..=500).rev() {
-            graph.insert(&i);
+            graph.print_graph();
         }
 
-        assert_eq!(graph.count_elements_unique(), 500);
-        assert_eq!(graph.count_elements_agg(), 601);
+        #[test]
+        fn insert_3_degree() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
 
-        let root_first_key = graph.root.borrow().elements[0].as_ref().unwrap().borrow().key;
-        assert_eq!(root_first_key, 128);
-        assert_eq!(graph.key_min.unwrap(), 1);
-        assert_eq!(graph.element_min.as_ref().unwrap().borrow().key, 1);
-        assert_eq!(graph.key_max.unwrap(), 500);
-        assert_eq!(graph.element_max.as_ref().unwrap().borrow().key, 500);
+            for i in 1..=250 {
+                graph.insert(&i);
+            }
 
-        graph.print_graph();
-    }
+            for i in (150..=500).rev() {
+                graph.insert(&i);
+            }
 
-    #[test]
-    fn insert_25_degree() {
-        let mut graph = ASAGraph::<i32, 25>::new(1);
+            assert_eq!(graph.count_elements_unique(), 500);
+            assert_eq!(graph.count_elements_agg(), 601);
 
-        for i in 1..=250 {
-            graph.insert(&i);
-        }
+            let root_first_key = graph.root.borrow().elements[0].as_ref().unwrap().borrow().key;
+            assert_eq!(root_first_key, 128);
+            assert_eq!(graph.key_min.unwrap(), 1);
+            assert_eq!(graph.element_min.as_ref().unwrap().borrow().key, 1);
+            assert_eq!(graph.key_max.unwrap(), 500);
+            assert_eq!(graph.element_max.as_ref().unwrap().borrow().key, 500);
 
-        for i in (150..=500).rev() {
-            graph.insert(&i);
+            graph.print_graph();
         }
 
-        assert_eq!(graph.count_elements_unique(), 500);
-        assert_eq!(graph.count_elements_agg(), 601);
+        #[test]
+        fn insert_25_degree() {
+            let mut graph = ASAGraph::<i32, 25>::new(1);
 
-        let root_first_key = graph.root.borrow().elements[0].as_ref().unwrap().borrow().key;
-        assert_eq!(root_first_key, 169);
-        assert_eq!(graph.key_min.unwrap(), 1);
-        assert_eq!(graph.element_min.as_ref().unwrap().borrow().key, 1);
-        assert_eq!(graph.key_max.unwrap(), 500);
-        assert_eq!(graph.element_max.as_ref().unwrap().borrow().key, 500);
+            for i in 1..=250 {
+                graph.insert(&i);
+            }
 
-        graph.print_graph();
-    }
+            for i in (150..=500).rev() {
+                graph.insert(&i);
+            }
 
-    #[test]
-    fn search() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
+            assert_eq!(graph.count_elements_unique(), 500);
+            assert_eq!(graph.count_elements_agg(), 601);
 
-        let n = 100;
-        for i in 0..n {
-            graph.insert(&i);
+            let root_first_key = graph.root.borrow().elements[0].as_ref().unwrap().borrow().key;
+            assert_eq!(root_first_key, 169);
+            assert_eq!(graph.key_min.unwrap(), 1);
+            assert_eq!(graph.element_min.as_ref().unwrap().borrow().key, 1);
+            assert_eq!(graph.key_max.unwrap(), 500);
+            assert_eq!(graph.element_max.as_ref().unwrap().borrow().key, 500);
+
+            graph.print_graph();
         }
 
-        for i in 0..n {
-            let result = graph.search(&i);
+        #[test]
+        fn fuzzy_search() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+            for i in [1, 2, 3, 5, 6, 7] {
+                graph.insert(&i);
+            }
+            assert_eq!(graph.count_elements_unique(), 6);
+            let result = graph.fuzzy_search(&4, 0.8, false);
+            assert_eq!(graph.count_elements_unique(), 6);
+            assert!(result.is_some());
+            graph.fuzzy_search(&4, 0.8, true);
+            assert_eq!(graph.count_elements_unique(), 6);
+            assert_eq!(result.unwrap().0.borrow().key, 3);
+            assert!(graph.fuzzy_search(&10, 0.8, false).is_none());
+            assert_eq!(graph.count_elements_unique(), 6);
+            assert!(graph.fuzzy_search(&-2, 0.8, false).is_none());
+            assert_eq!(graph.count_elements_unique(), 6);
+            assert!(graph.fuzzy_search(&-2, 0.8, true).is_some());
+            assert_eq!(graph.count_elements_unique(), 7);
+
+            let mut graph = ASAGraph::<f64, 3>::new(1);
+            for i in [1.0, 2.0, 3.0, 5.0, 6.0, 7.0] {
+                graph.insert(&i);
+            }
+            let result = graph.fuzzy_search(&3.1, 0.95, false);
+            assert!(result.is_some());
+            assert_eq!(result.unwrap().0.borrow().key, 3.0);
+            assert!(graph.fuzzy_search(&3.5, 0.95, false).is_none());
+            assert!(graph.fuzzy_search(&3.5, 0.95, true).is_some());
+            assert!(graph.fuzzy_search(&-1.0, 0.95, false).is_none());
+
+            let mut graph = ASAGraph::<String, 3>::new(1);
+            for i in ["1".to_string(), "2".to_string()] {
+                graph.insert(&i);
+            }
+            let result = graph.fuzzy_search(&"1".to_string(), 0.8, false);
             assert!(result.is_some());
-            assert_eq!(result.unwrap().borrow().key, i);
+            assert_eq!(result.unwrap().0.borrow().key, "1".to_string());
+            assert!(graph.fuzzy_search(&"3".to_string(), 0.8, false).is_none());
+            assert!(graph.fuzzy_search(&"3".to_string(), 0.8, true).is_none());
         }
-        
-        assert!(graph.search(&101).is_none());
-        assert!(graph.search(&-1).is_none());
-    }
 
-    #[test]
-    fn fuzzy_search() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-        for i in [1, 2, 3, 5, 6, 7] {
-            graph.insert(&i);
-        }
-        assert_eq!(graph.count_elements_unique(), 6);
-        let result = graph.fuzzy_search(&4, 0.8, false);
-        assert_eq!(graph.count_elements_unique(), 6);
-        assert!(result.is_some());
-        graph.fuzzy_search(&4, 0.8, true);
-        assert_eq!(graph.count_elements_unique(), 6);
-        assert_eq!(result.unwrap().0.borrow().key, 3);
-        assert!(graph.fuzzy_search(&10, 0.8, false).is_none());
-        assert_eq!(graph.count_elements_unique(), 6);
-        assert!(graph.fuzzy_search(&-2, 0.8, false).is_none());
-        assert_eq!(graph.count_elements_unique(), 6);
-        assert!(graph.fuzzy_search(&-2, 0.8, true).is_some());
-        assert_eq!(graph.count_elements_unique(), 7);
-
-        let mut graph = ASAGraph::<f64, 3>::new(1);
-        for i in [1.0, 2.0, 3.0, 5.0, 6.0, 7.0] {
-            graph.insert(&i);
-        }
-        let result = graph.fuzzy_search(&3.1, 0.95, false);
-        assert!(result.is_some());
-        assert_eq!(result.unwrap().0.borrow().key, 3.0);
-        assert!(graph.fuzzy_search(&3.5, 0.95, false).is_none());
-        assert!(graph.fuzzy_search(&3.5, 0.95, true).is_some());
-        assert!(graph.fuzzy_search(&-1.0, 0.95, false).is_none());
-
-        let mut graph = ASAGraph::<String, 3>::new(1);
-        for i in ["1".to_string(), "2".to_string()] {
-            graph.insert(&i);
-        }
-        let result = graph.fuzzy_search(&"1".to_string(), 0.8, false);
-        assert!(result.is_some());
-        assert_eq!(result.unwrap().0.borrow().key, "1".to_string());
-        assert!(graph.fuzzy_search(&"3".to_string(), 0.8, false).is_none());
-        assert!(graph.fuzzy_search(&"3".to_string(), 0.8, true).is_none());
-    }
+        #[test]
+        fn search() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
 
-    #[test]
-    fn test_connections() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-    
-        let n = 50;
-        for i in 1..=n {
-            graph.insert(&i);
+            let n = 100;
+            for i in 0..n {
+                graph.insert(&i);
+            }
+
+            for i in 0..n {
+                let result = graph.search(&i);
+                assert!(result.is_some());
+                assert_eq!(result.unwrap().borrow().key, i);
+            }
+
+            assert!(graph.search(&101).is_none());
+            assert!(graph.search(&-1).is_none());
         }
 
-        let mut prev_element;
-        let mut current_element = graph.element_min.as_ref().unwrap().clone();
-        for i in 1..=n {
-            assert_eq!(current_element.borrow().key, i);
-            {
-                let prev = &current_element.borrow().prev;
-                let next = &current_element.borrow().next;
-                if i == 1 { 
-                    assert!(prev.is_none());
-                    assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, 2);
-                } else if i == n {
-                    assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, n - 1);
-                    assert!(next.is_none());
-                    break
-                } else {
-                    assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i - 1);
-                    assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i + 1);
+        #[test]
+        fn test_connections() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+
+            let n = 50;
+            for i in 1..=n {
+                graph.insert(&i);
+            }
+
+            let mut prev_element;
+            let mut current_element = graph.element_min.as_ref().unwrap().clone();
+            for i in 1..=n {
+                assert_eq!(current_element.borrow().key, i);
+                {
+                    let prev = &current_element.borrow().prev;
+                    let next = &current_element.borrow().next;
+                    if i == 1 { 
+                        assert!(prev.is_none());
+                        assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, 2);
+                    } else if i == n {
+                        assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, n - 1);
+                        assert!(next.is_none());
+                        break
+                    } else {
+                        assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i - 1);
+                        assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i + 1);
+                    }
                 }
+                prev_element = current_element.clone();
+                current_element = prev_element.borrow().next.as_ref().unwrap().0.upgrade().unwrap().clone();
             }
-            prev_element = current_element.clone();
-            current_element = prev_element.borrow().next.as_ref().unwrap().0.upgrade().unwrap().clone();
         }
-    }
 
-    #[test]
-    fn test_connections_rev() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-    
-        let n = 50;
-        for i in (1..=n).rev() {
-            graph.insert(&i);
-        }
+        #[test]
+        fn test_connections_rev() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
 
-        let mut prev_element;
-        let mut current_element = graph.element_min.as_ref().unwrap().clone();
-        for i in 1..=n {
-            assert_eq!(current_element.borrow().key, i);
-            {
-                let prev = &current_element.borrow().prev;
-                let next = &current_element.borrow().next;
-                if i == 1 { 
-                    assert!(prev.is_none());
-                    assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, 2);
-                } else if i == n {
-                    assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, n - 1);
-                    assert!(next.is_none());
-                    break
-                } else {
-                    assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i - 1);
-                    assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i + 1);
+            let n = 50;
+            for i in (1..=n).rev() {
+                graph.insert(&i);
+            }
+
+            let mut prev_element;
+            let mut current_element = graph.element_min.as_ref().unwrap().clone();
+            for i in 1..=n {
+                assert_eq!(current_element.borrow().key, i);
+                {
+                    let prev = &current_element.borrow().prev;
+                    let next = &current_element.borrow().next;
+                    if i == 1 { 
+                        assert!(prev.is_none());
+                        assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, 2);
+                    } else if i == n {
+                        assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, n - 1);
+                        assert!(next.is_none());
+                        break
+                    } else {
+                        assert_eq!(prev.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i - 1);
+                        assert_eq!(next.as_ref().unwrap().0.upgrade().unwrap().borrow().key, i + 1);
+                    }
                 }
+                prev_element = current_element.clone();
+                current_element = prev_element.borrow().next.as_ref().unwrap().0.upgrade().unwrap().clone();
             }
-            prev_element = current_element.clone();
-            current_element = prev_element.borrow().next.as_ref().unwrap().0.upgrade().unwrap().clone();
         }
-    }
 
-    #[test]
-    fn iterator_test() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-        let n = 50;
-        for i in (0..=n).rev() { graph.insert(&i); }
-        for (i, element) in graph.into_iter().enumerate() {
-            assert_eq!(element.borrow().key, i as i32);
+        #[test]
+        fn iterator_test() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+            let n = 50;
+            for i in (0..=n).rev() { graph.insert(&i); }
+            for (i, element) in graph.into_iter().enumerate() {
+                assert_eq!(element.borrow().key, i as i32);
+            }
+            assert_eq!(graph.key_min.unwrap(), 0i32);
         }
-        assert_eq!(graph.key_min.unwrap(), 0i32);
-    }
 
-    #[test]
-    fn new_from_vec() {
-        let vec = vec!["kot".to_string(), "pies".to_string()];
-        let graph = ASAGraph::<_, 25>::new_rc_from_vec(1, &vec[..]);
-        assert!(graph.borrow().search(&"kot".to_string()).is_some());
-        let graph = ASAGraph::<_, 3>::new_from_vec(1, &vec[..]);
-        assert!(graph.search(&"pies".to_string()).is_some());
-    }
+        #[test]
+        fn range_queries() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+            assert!(graph.first().is_none());
+            assert!(graph.lower_bound(&1).is_none());
+            assert!(graph.range(..).is_empty());
+
+            let n = 50;
+            for i in (0..n).rev() { graph.insert(&(i * 2)); }
+
+            assert_eq!(graph.first().unwrap().borrow().key, 0);
+            assert_eq!(graph.last().unwrap().borrow().key, 2 * (n - 1));
+            for key in -1..=(2 * n) {
+                let expected = (0..n).map(|i| i * 2).find(|x| *x >= key);
+                assert_eq!(graph.lower_bound(&key).map(|x| x.borrow().key), expected);
+                let expected = (0..n).map(|i| i * 2).find(|x| *x > key);
+                assert_eq!(graph.upper_bound(&key).map(|x| x.borrow().key), expected);
+            }
 
-    #[test]
-    fn ids() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-        for i in 1..=25 { graph.insert(&i); }
-        for i in 1..=25 { assert_eq!(i as u32, graph.search(&i).unwrap().borrow().id) }
-    }
+            let keys: Vec<i32> = graph.range(10..=20).iter().map(|x| x.borrow().key).collect();
+            assert_eq!(keys, vec![10, 12, 14, 16, 18, 20]);
+            let keys: Vec<i32> = graph.range(11..20).iter().map(|x| x.borrow().key).collect();
+            assert_eq!(keys, vec![12, 14, 16, 18]);
+            let above_90 = (std::ops::Bound::Excluded(90), std::ops::Bound::Unbounded);
+            let keys: Vec<i32> = graph.range(above_90).iter().map(|x| x.borrow().key).collect();
+            assert_eq!(keys, vec![92, 94, 96, 98]);
+            assert!(graph.range(31..32).is_empty());
+            assert_eq!(graph.range(..).len(), n as usize);
+
+            let keys: Vec<i32> = graph.iter_rev().map(|x| x.borrow().key).collect();
+            assert_eq!(keys, (0..n).rev().map(|i| i * 2).collect::<Vec<_>>());
+            let keys: Vec<i32> = graph.iter().map(|x| x.borrow().key).collect();
+            assert_eq!(keys, (0..n).map(|i| i * 2).collect::<Vec<_>>());
+        }
 
-    #[test]
-    fn display_graph() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-        for i in 1..=25 { graph.insert(&i); }
+        #[test]
+        fn new_from_vec() {
+            let vec = vec!["kot".to_string(), "pies".to_string()];
+            let graph = ASAGraph::<_, 25>::new_ptr_from_vec(1, &vec[..]);
+            assert!(graph.borrow().search(&"kot".to_string()).is_some());
+            let graph = ASAGraph::<_, 3>::new_from_vec(1, &vec[..]);
+            assert!(graph.search(&"pies".to_string()).is_some());
+        }
 
-        println!("{graph}");
-    }
+        #[test]
+        fn ids() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+            for i in 1..=25 { graph.insert(&i); }
+            for i in 1..=25 { assert_eq!(i as u32, graph.search(&i).unwrap().borrow().id) }
+        }
 
-    #[test]
-    fn levels() {
-        let mut graph = ASAGraph::<i32, 3>::new(1);
-        for i in 1..=25 { graph.insert(&i); }
-        let lvlvs: Vec<Vec<Vec<i32>>> = graph.levels().into_iter().map(
-            |v| v.into_iter().map(
-                |n| n.into_iter().map(
-                    |e| {
-                        e.borrow().key
-                    }
-                ).collect()
-            ).collect()
-        ).collect();
-        println!("{:?}", lvlvs);
-    }
+        #[test]
+        fn display_graph() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+            for i in 1..=25 { graph.insert(&i); }
 
-    #[test]
-    fn remove() {
-        let mut rng = StdRng::seed_from_u64(42);
+            println!("{graph}");
+        }
 
-        let mut graph = ASAGraph::<i32, 5>::new(1);
+        #[test]
+        fn levels() {
+            let mut graph = ASAGraph::<i32, 3>::new(1);
+            for i in 1..=25 { graph.insert(&i); }
+            let lvlvs: Vec<Vec<Vec<DataTypeValue>>> = graph.levels().into_iter().map(
+                |v| v.into_iter().map(
+                    |n| n.into_iter().map(
+                        |e| {
+                            e.borrow().value()
+                        }
+                    ).collect()
+                ).collect()
+            ).collect();
+            println!("{:?}", lvlvs);
+        }
 
-        let mut numbers = vec![];
-        for _ in 0..5150 {
-            let number: i32 = rng.gen_range(1..=2850);
-            numbers.push(number);
-            graph.insert(&number);
+        #[test]
+        fn activate_missing_in() {
+            for key in [3.0, 7.0, -2.0] {
+                let mut graph = ASAGraph::<f64, 3>::new(1);
+                let mut inserted = ASAGraph::<f64, 3>::new(1);
+                for key in [1.0, 2.0, 4.0, 5.0] { graph.insert(&key); inserted.insert(&key); }
+                inserted.insert(&key);
+
+                let mut context = ActivationContext::new();
+                graph.activate_in(&key, 1.0, true, true, &mut context).unwrap();
+                assert_eq!(graph.count_elements_unique(), 4);
+                let mut inserted_context = ActivationContext::new();
+                inserted.activate_in(&key, 1.0, true, true, &mut inserted_context).unwrap();
+
+                for element in &graph {
+                    let id = element.borrow().id();
+                    let activation = context.activation(&id, true);
+                    assert!(activation > 0.0);
+                    assert!((activation - inserted_context.activation(&id, true)).abs() < 1e-6);
+                }
+                assert!(graph.activate_in(&key, 1.0, false, true, &mut context).is_err());
+            }
         }
-        numbers.shuffle(&mut rng);
 
-        graph.print_graph();
+        #[test]
+        fn remove() {
+            let mut rng = StdRng::seed_from_u64(42);
 
-        print!("removing ");
-        for number in &numbers {
-            print!("{number} ");
-            graph.remove(number);
-            if !graph.test_graph(false) {
-                println!("removing {number} went wrong, returning");
-                return
+            let mut graph = ASAGraph::<i32, 5>::new(1);
+
+            let mut numbers = vec![];
+            for _ in 0..5150 {
+                let number: i32 = rng.gen_range(1..=2850);
+                numbers.push(number);
+                graph.insert(&number);
             }
-        }
-        println!();
+            numbers.shuffle(&mut rng);
+
+            graph.print_graph();
 
-        graph.print_graph();
+            print!("removing ");
+            for number in &numbers {
+                print!("{number} ");
+                graph.remove(number);
+                if !graph.test_graph(false) {
+                    println!("removing {number} went wrong, returning");
+                    return
+                }
+            }
+            println!();
+
+            graph.print_graph();
+        }
     }
-}
\ No newline at end of file
+}
@@ -4,6 +4,7 @@
 #![feature(box_into_inner)]
 
 #![allow(dead_code)] // TODO: remove after dev is done
+#![allow(clippy::duplicate_mod)] // `generic` sources are compiled once per pointer family
 
 pub mod simple;
 pub mod neural;
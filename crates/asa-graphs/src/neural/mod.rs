@@ -1,4 +1,57 @@
+//! `Rc<RefCell>` instantiation of the neural asa-graph sources in `generic`
+
+use std::{
+    rc::Rc,
+    cell::RefCell,
+    marker::PhantomData
+};
+
+use witchnet_common::{
+    synchronous as common,
+    sensor::SensorData,
+    data::{ DataDeductor, DataTypeValue }
+};
+
+use common::collective::defining::DefiningWeightingStrategy;
+
+/// Keeps the tests of the `generic` sources, they run once, in this family
+macro_rules! generic_tests { ($($tests:item)*) => { $($tests)* } }
+
+#[path = "../generic/graph.rs"]
 pub mod graph;
+#[path = "../generic/node.rs"]
 pub mod node;
+#[path = "../generic/element.rs"]
 pub mod element;
-pub mod sensor;
\ No newline at end of file
+#[path = "../generic/sensor.rs"]
+pub mod sensor;
+
+impl<Key, const ORDER: usize> graph::ASAGraph<Key, ORDER> 
+where 
+    Key: SensorData, 
+    [(); ORDER + 1]:, 
+    PhantomData<Key>: DataDeductor,
+    DataTypeValue: From<Key>
+{
+    pub fn new_rc(id: u32) -> Rc<RefCell<graph::ASAGraph<Key, ORDER>>> { Self::new_ptr(id) }
+
+    pub fn new_rc_from_vec(id: u32, data: &[Key]) -> Rc<RefCell<Self>> {
+        Self::new_ptr_from_vec(id, data)
+    }
+
+    pub fn new_rc_from_vec_custom(
+        id: u32,
+        data: &[Key],
+        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        interelement_activation_threshold: f32,
+        interelement_activation_exponent: i32
+    ) -> Rc<RefCell<Self>> {
+        Self::new_ptr_from_vec_custom(
+            id,
+            data,
+            weighting_strategy,
+            interelement_activation_threshold,
+            interelement_activation_exponent
+        )
+    }
+}
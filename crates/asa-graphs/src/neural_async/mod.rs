@@ -1,4 +1,56 @@
+//! `Arc<RwLock>` instantiation of the neural asa-graph sources in `generic`
+
+use std::{
+    sync::{ Arc, RwLock },
+    marker::PhantomData
+};
+
+use witchnet_common::{
+    asynchronous as common,
+    sensor::SensorData,
+    data::{ DataDeductor, DataTypeValue }
+};
+
+use common::collective::defining::DefiningWeightingStrategy;
+
+/// Drops the tests of the `generic` sources, they run once, in the `Rc<RefCell>` family
+macro_rules! generic_tests { ($($tests:item)*) => {} }
+
+#[path = "../generic/graph.rs"]
 pub mod graph;
+#[path = "../generic/node.rs"]
 pub mod node;
+#[path = "../generic/element.rs"]
 pub mod element;
-pub mod sensor;
\ No newline at end of file
+#[path = "../generic/sensor.rs"]
+pub mod sensor;
+
+impl<Key, const ORDER: usize> graph::ASAGraph<Key, ORDER> 
+where 
+    Key: SensorData + Sync + Send, 
+    [(); ORDER + 1]:, 
+    PhantomData<Key>: DataDeductor,
+    DataTypeValue: From<Key>
+{
+    pub fn new_arc(id: u32) -> Arc<RwLock<graph::ASAGraph<Key, ORDER>>> { Self::new_ptr(id) }
+
+    pub fn new_arc_from_vec(id: u32, data: &[Key]) -> Arc<RwLock<Self>> {
+        Self::new_ptr_from_vec(id, data)
+    }
+
+    pub fn new_arc_from_vec_custom(
+        id: u32,
+        data: &[Key],
+        weighting_strategy: Arc<dyn DefiningWeightingStrategy>,
+        interelement_activation_threshold: f32,
+        interelement_activation_exponent: i32
+    ) -> Arc<RwLock<Self>> {
+        Self::new_ptr_from_vec_custom(
+            id,
+            data,
+            weighting_strategy,
+            interelement_activation_threshold,
+            interelement_activation_exponent
+        )
+    }
+}
@@ -2,7 +2,8 @@ use std::{
     fmt::Display,
     rc::Rc,
     cell::RefCell,
-    io::{ self, Write }
+    io::{ self, Write },
+    ops::{ Bound, RangeBounds }
 };
 
 use witchnet_common::distances::Distance;
@@ -841,6 +842,79 @@ where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]:
         }
     }
 
+    pub fn first(&self) -> Option<Rc<RefCell<Element<Key, ORDER>>>> { 
+        self.element_min.clone() 
+    }
+
+    pub fn last(&self) -> Option<Rc<RefCell<Element<Key, ORDER>>>> { 
+        self.element_max.clone() 
+    }
+
+    /// first element with key not less than `key`
+    pub fn lower_bound(&self, key: &Key) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        self.bound(key, true)
+    }
+
+    /// first element with key greater than `key`
+    pub fn upper_bound(&self, key: &Key) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        self.bound(key, false)
+    }
+
+    fn bound(&self, key: &Key, inclusive: bool) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        let mut result = None;
+        let mut node_ptr = self.root.clone();
+        loop {
+            let child_ptr = {
+                let node = node_ptr.borrow();
+                let mut index = 0;
+                while index < node.size {
+                    let current_key = node.keys[index].as_ref().unwrap();
+                    if current_key > key || (inclusive && current_key == key) { break }
+                    index += 1;
+                }
+                if index < node.size {
+                    result = node.elements[index].clone();
+                    let current_key = node.keys[index].as_ref().unwrap();
+                    if inclusive && current_key == key { return result }
+                }
+                if node.is_leaf { return result }
+                node.children[index].clone().unwrap()
+            };
+            node_ptr = child_ptr;
+        }
+    }
+
+    pub fn range<R: RangeBounds<Key>>(&self, range: R) 
+    -> Vec<Rc<RefCell<Element<Key, ORDER>>>> {
+        let mut element = match range.start_bound() {
+            Bound::Included(lo) => self.lower_bound(lo),
+            Bound::Excluded(lo) => self.upper_bound(lo),
+            Bound::Unbounded => self.first()
+        };
+
+        let mut ret = Vec::new();
+        while let Some(current) = element {
+            let is_in_range = {
+                let key = &current.borrow().key;
+                match range.end_bound() {
+                    Bound::Included(hi) => key <= hi,
+                    Bound::Excluded(hi) => key < hi,
+                    Bound::Unbounded => true
+                }
+            };
+            if !is_in_range { break }
+            element = current.borrow().next.as_ref().and_then(|next| next.upgrade());
+            ret.push(current);
+        }
+        ret
+    }
+
+    pub fn iter(&self) -> ASAGraphIntoIterator<'_, Key, ORDER> { self.into_iter() }
+
+    pub fn iter_rev(&self) -> ASAGraphRevIterator<'_, Key, ORDER> {
+        ASAGraphRevIterator { graph: self, index: self.element_max.clone() }
+    }
+
     pub fn levels(&self) -> Vec<Vec<Vec<Rc<RefCell<Element<Key, ORDER>>>>>> {
         let mut ret = Vec::new();
 
@@ -881,6 +955,48 @@ where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]:
     }
 }
 
+impl<'a, Key, const ORDER: usize> IntoIterator for &'a ASAGraph<Key, ORDER> 
+where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]: {
+    type Item = Rc<RefCell<Element<Key, ORDER>>>;
+    type IntoIter = ASAGraphIntoIterator<'a, Key, ORDER>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ASAGraphIntoIterator { graph: self, index: self.element_min.clone() }
+    }
+}
+
+pub struct ASAGraphIntoIterator<'a, Key, const ORDER: usize = 25>
+where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]: {
+    graph: &'a ASAGraph<Key, ORDER>,
+    index: Option<Rc<RefCell<Element<Key, ORDER>>>>
+}
+
+impl<'a, Key, const ORDER: usize> Iterator for ASAGraphIntoIterator<'a, Key, ORDER> 
+where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]: {
+    type Item = Rc<RefCell<Element<Key, ORDER>>>;
+    fn next(&mut self) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        let element = self.index.take()?;
+        self.index = element.borrow().next.as_ref().and_then(|next| next.upgrade());
+        Some(element)
+    }
+}
+
+pub struct ASAGraphRevIterator<'a, Key, const ORDER: usize = 25>
+where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]: {
+    graph: &'a ASAGraph<Key, ORDER>,
+    index: Option<Rc<RefCell<Element<Key, ORDER>>>>
+}
+
+impl<'a, Key, const ORDER: usize> Iterator for ASAGraphRevIterator<'a, Key, ORDER> 
+where Key: Clone + Display + PartialOrd + PartialEq + Distance, [(); ORDER + 1]: {
+    type Item = Rc<RefCell<Element<Key, ORDER>>>;
+    fn next(&mut self) -> Option<Rc<RefCell<Element<Key, ORDER>>>> {
+        let element = self.index.take()?;
+        self.index = element.borrow().prev.as_ref().and_then(|prev| prev.upgrade());
+        Some(element)
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::time::Instant;
@@ -1001,6 +1117,41 @@ pub mod tests {
         assert!(graph.search(&-1).is_none());
     }
 
+    #[test]
+    fn range_queries() {
+        let mut graph = ASAGraph::<i32, 3>::new("test");
+        assert!(graph.first().is_none());
+        assert!(graph.lower_bound(&1).is_none());
+        assert!(graph.range(..).is_empty());
+
+        let n = 50;
+        for i in (0..n).rev() { graph.insert(&(i * 2)); }
+
+        assert_eq!(graph.first().unwrap().borrow().key, 0);
+        assert_eq!(graph.last().unwrap().borrow().key, 2 * (n - 1));
+        for key in -1..=(2 * n) {
+            let expected = (0..n).map(|i| i * 2).find(|x| *x >= key);
+            assert_eq!(graph.lower_bound(&key).map(|x| x.borrow().key), expected);
+            let expected = (0..n).map(|i| i * 2).find(|x| *x > key);
+            assert_eq!(graph.upper_bound(&key).map(|x| x.borrow().key), expected);
+        }
+
+        let keys: Vec<i32> = graph.range(10..=20).iter().map(|x| x.borrow().key).collect();
+        assert_eq!(keys, vec![10, 12, 14, 16, 18, 20]);
+        let keys: Vec<i32> = graph.range(11..20).iter().map(|x| x.borrow().key).collect();
+        assert_eq!(keys, vec![12, 14, 16, 18]);
+        let above_90 = (std::ops::Bound::Excluded(90), std::ops::Bound::Unbounded);
+        let keys: Vec<i32> = graph.range(above_90).iter().map(|x| x.borrow().key).collect();
+        assert_eq!(keys, vec![92, 94, 96, 98]);
+        assert!(graph.range(31..32).is_empty());
+        assert_eq!(graph.range(..).len(), n as usize);
+
+        let keys: Vec<i32> = graph.iter_rev().map(|x| x.borrow().key).collect();
+        assert_eq!(keys, (0..n).rev().map(|i| i * 2).collect::<Vec<_>>());
+        let keys: Vec<i32> = graph.iter().map(|x| x.borrow().key).collect();
+        assert_eq!(keys, (0..n).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_connections() {
         let mut graph = ASAGraph::<i32, 3>::new("test");
@@ -1,3 +0,0 @@
-pub mod prediction;
-pub mod recommendation;
-pub mod similarity;
\ No newline at end of file
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use rayon::prelude::*;
+
+use witchnet_common::{
+    data::DataTypeValue,
+    neuron::ActivationContext,
+    performance::{ SupervisedPerformance, DataProbability }
+};
+
+use super::{
+    magds::MAGDS,
+    algorithm::prediction::{ self, MissingValues }
+};
+
+/// Predicts the target for every query in parallel. The activations live in per-query
+/// contexts and query values missing in the sensors are activated fuzzily within them,
+/// so the magds is left unchanged.
+pub fn predict_batch(
+    magds: &MAGDS,
+    queries: &[Vec<(u32, DataTypeValue, f32)>],
+    target: u32,
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32,
+    missing: MissingValues
+) -> Vec<Option<DataProbability>> {
+    queries.par_iter()
+        .map(|features| prediction::predict_weighted_in(
+            magds, features, target, fuzzy, winners_limit, weight_ratio, missing,
+            &mut ActivationContext::new()
+        ))
+        .collect()
+}
+
+/// Parallel counterpart of `prediction::prediction_score_custom`.
+pub fn prediction_score_batch(
+    train: &MAGDS, 
+    test: &MAGDS, 
+    target: &str, 
+    fuzzy: bool,
+    weighted: bool,
+    winners_limit: usize,
+    weight_ratio: f32
+) -> Result<SupervisedPerformance> {
+    let (target_id, queries) = prediction::test_queries(train, test, target, weighted)?;
+    let (queries, references): (Vec<_>, Vec<_>) = queries.into_iter().unzip();
+
+    let results = predict_batch(
+        train, &queries, target_id, fuzzy, winners_limit, weight_ratio, MissingValues::Ignore
+    );
+
+    let mut predictions: Vec<DataTypeValue> = Vec::with_capacity(results.len());
+    let mut probabilities: Vec<f32> = Vec::with_capacity(results.len());
+    let mut predicted_references: Vec<DataTypeValue> = Vec::with_capacity(results.len());
+    for (result, reference) in results.into_iter().zip(references) {
+        if let Some(DataProbability(value, proba)) = result {
+            predicted_references.push(reference);
+            predictions.push(value);
+            probabilities.push(proba);
+        }
+    }
+
+    prediction::score(train, target_id, predicted_references, predictions, probabilities)
+}
+
+#[cfg(test)]
+mod tests {
+    use witchnet_common::sensor::SensorAsync;
+
+    use super::super::{
+        parser,
+        magds::MAGDS,
+        algorithm::prediction::{ self, MissingValues }
+    };
+
+    #[test]
+    fn predict_batch() {
+        let train_file = "data/iris_original_train.csv";
+        let test_file = "data/iris_original_test.csv";
+        let magds_train = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+        let magds_test = parser::magds_from_csv("iris_test", test_file, &vec![]).unwrap();
+
+        let (target_id, queries) = prediction::test_queries(
+            &magds_train, &magds_test, "variety", false
+        ).unwrap();
+        let queries: Vec<_> = queries.into_iter().map(|(features, _)| features).collect();
+        let weight_ratio = f32::ln(magds_train.neurons.len() as f32);
+
+        let elements = |magds: &MAGDS| -> Vec<(usize, usize)> {
+            let mut sensors = magds.sensors();
+            sensors.sort_by_key(|sensor| sensor.read().unwrap().id());
+            sensors.iter()
+                .map(|sensor| {
+                    let elements = sensor.read().unwrap().neurons();
+                    let counters = elements.iter().map(|e| e.read().unwrap().counter()).sum();
+                    (elements.len(), counters)
+                })
+                .collect()
+        };
+        let elements_before = elements(&magds_train);
+
+        let batch = super::predict_batch(
+            &magds_train, &queries, target_id, true, 12, weight_ratio, MissingValues::Ignore
+        );
+        assert_eq!(batch.len(), queries.len());
+        assert_eq!(elements(&magds_train), elements_before);
+        assert!(magds_train.neurons.iter().all(|x| x.read().unwrap().activation() == 0.0));
+
+        for (features, batch_result) in queries.iter().zip(batch) {
+            let result = prediction::predict_weighted(
+                &magds_train, features, target_id, true, 12, weight_ratio
+            );
+            let (result, batch_result) = (result.unwrap(), batch_result.unwrap());
+            assert_eq!(result.0, batch_result.0);
+            assert!((result.1 - batch_result.1).abs() < 1e-5);
+        }
+        assert_eq!(elements(&magds_train), elements_before);
+    }
+
+    #[test]
+    fn prediction_score_batch() {
+        let train_file = "data/iris_original_train.csv";
+        let test_file = "data/iris_original_test.csv";
+        let magds_train = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+        let magds_test = parser::magds_from_csv("iris_test", test_file, &vec![]).unwrap();
+        let weight_ratio = f32::ln(magds_train.neurons.len() as f32);
+
+        let batch = super::prediction_score_batch(
+            &magds_train, &magds_test, "variety", true, true, 12, weight_ratio
+        ).unwrap();
+        let sequential = prediction::prediction_score_custom(
+            &magds_train, &magds_test, "variety".into(), true, true, 12, weight_ratio
+        ).unwrap();
+        assert!(batch.accuracy().unwrap() > 0.90);
+        assert_eq!(batch.predictions(), sequential.predictions());
+    }
+}
@@ -1,5 +1,36 @@
+//! `Arc<RwLock>` instantiation of the magds sources in `generic`
+//!
+//! Each `generic` source is included once here and once in `synchronous`, with `Ptr`, `Lock`,
+//! `common` and `neural` resolving to this family, hence the allowed `clippy::duplicate_mod`
+#![allow(clippy::duplicate_mod)]
+
+use std::sync::{ Arc, RwLock };
+
+use witchnet_common::asynchronous as common;
+use asa_graphs::neural_async as neural;
+
+/// Drops the tests of the `generic` sources, they run once, in the `Rc<RefCell>` family
+macro_rules! generic_tests { ($($tests:item)*) => {} }
+
+#[path = "../generic/magds.rs"]
 pub mod magds;
+#[path = "../generic/sensor.rs"]
 pub mod sensor;
+#[path = "../generic/parser.rs"]
 pub mod parser;
+#[path = "../generic/snapshot.rs"]
+pub mod snapshot;
+#[path = "../generic/journal.rs"]
+pub mod journal;
+#[path = "../generic/algorithm/mod.rs"]
 pub mod algorithm;
-pub mod ffi;
\ No newline at end of file
+#[path = "../generic/query.rs"]
+pub mod query;
+#[path = "../generic/neuron/mod.rs"]
+pub mod neuron;
+pub mod ffi;
+pub mod batch;
+
+impl magds::MAGDS {
+    pub fn new_arc() -> Arc<RwLock<magds::MAGDS>> { Self::new_ptr() }
+}
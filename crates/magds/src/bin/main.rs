@@ -1,3 +1,482 @@
+use std::{
+    env,
+    fs,
+    process,
+    rc::Rc,
+    collections::HashMap
+};
+
+use anyhow::{ Result, Context };
+
+use polars::prelude::*;
+
+use serde_json::{ json, Value };
+
+use witchnet_common::{
+    data::{ DataType as MAGDSDataType, DataTypeValue },
+    sensor::Sensor,
+    neuron::ActivationContext,
+    performance::SupervisedPerformance,
+    polars as polars_common,
+    connection::collective::defining::{
+        DefiningWeightingStrategy, ConstantOneWeight, weighting_strategy_from_name
+    }
+};
+
+use magds::{
+    query::Literal,
+    synchronous::{
+        magds::MAGDS,
+        parser,
+        query,
+        algorithm::{
+            prediction::{ self, MissingValues },
+            similarity,
+            evaluation::{ self, EvaluationConfig, CrossValidation }
+        }
+    }
+};
+
+const USAGE: &str = "\
+usage: magds <command> [options]
+
+commands:
+    build <data.csv> <snapshot>         build magds from csv and save it as a snapshot
+    query <snapshot|data.csv> <query>   run a SELECT .. FROM .. WHERE .. query
+    predict <snapshot|data.csv>         predict --target from --features or --input csv rows
+    evaluate <train.csv>                score --target on --test csv, --k-fold or --holdout split
+    inspect <snapshot|data.csv>         print neuron groups and sensors statistics
+
+options:
+    --name <name>                       neuron group name, defaults to the file stem
+    --skip <a,b,..>                     csv columns to skip
+    --weighting-strategy <name>         ConstantOneWeight (default), ConstantZeroWeight,
+                                        OneOverOuts, OneOverOutsUpperHalf,
+                                        OneOverOutsUpperQuarter
+    --threshold <f32>                   interelement activation threshold, 0.00001 by default
+    --exponent <i32>                    interelement activation exponent, 1 by default
+    --target <feature>                  predicted feature
+    --features <a=1,b=x,..>             feature values to predict from
+    --input <data.csv>                  rows to predict from
+    --test <test.csv>                   test set for evaluate
+    --k-fold <k>                        k-fold cross-validation for evaluate
+    --holdout <ratio>                   holdout test ratio for evaluate
+    --seed <u64>                        split seed, 42 by default
+    --stratified                        stratified splits
+    --winners <n>                       winners limit, 12 by default
+    --weight-ratio <f32>                winners weight ratio, ln of records count by default
+    --no-fuzzy                          disable fuzzy activation of sortable features
+    --weighted                          weight features by target mutual information
+    --format <csv|json>                 output format, csv by default
+    --output <file>                     output file, stdout by default";
+
+const FLAGS: [&str; 3] = ["stratified", "no-fuzzy", "weighted"];
+
+struct Args {
+    command: String,
+    positional: Vec<String>,
+    options: HashMap<String, String>
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Args> {
+        let mut args = args.skip(1);
+        let command = args.next().context("missing command")?;
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if FLAGS.contains(&name) => {
+                    options.insert(name.to_string(), String::new());
+                }
+                Some(name) => {
+                    let value = args.next().context(format!("missing value for --{name}"))?;
+                    options.insert(name.to_string(), value);
+                }
+                None => positional.push(arg)
+            }
+        }
+        Ok(Args { command, positional, options })
+    }
+
+    fn positional(&self, index: usize, name: &str) -> Result<&str> {
+        self.positional.get(index).map(|x| x.as_str()).context(format!("missing {name} argument"))
+    }
+
+    fn option(&self, name: &str) -> Option<&str> { self.options.get(name).map(|x| x.as_str()) }
+
+    fn required(&self, name: &str) -> Result<&str> {
+        self.option(name).context(format!("missing --{name} option"))
+    }
+
+    fn parsed<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.option(name) {
+            Some(value) => {
+                value.parse::<T>().ok().context(format!("invalid --{name} value {value}"))
+            }
+            None => Ok(default)
+        }
+    }
+
+    fn flag(&self, name: &str) -> bool { self.options.contains_key(name) }
+
+    fn skip(&self) -> Vec<&str> {
+        self.option("skip").map(|x| x.split(',').collect()).unwrap_or_default()
+    }
+
+    fn weighting_strategy(&self) -> Result<Rc<dyn DefiningWeightingStrategy>> {
+        match self.option("weighting-strategy") {
+            Some(name) => weighting_strategy_from_name(name)
+                .context(format!("unknown weighting strategy {name}")),
+            None => Ok(Rc::new(ConstantOneWeight))
+        }
+    }
+
+    fn threshold(&self) -> Result<f32> { self.parsed("threshold", 0.00001f32) }
+
+    fn exponent(&self) -> Result<i32> { self.parsed("exponent", 1i32) }
+}
+
 fn main() {
-    println!("ahoy");
-}
\ No newline at end of file
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("warn"));
+
+    let result = Args::parse(env::args()).and_then(|args| match args.command.as_str() {
+        "build" => build(&args),
+        "query" => query(&args),
+        "predict" => predict(&args),
+        "evaluate" => evaluate(&args),
+        "inspect" => inspect(&args),
+        "help" | "--help" | "-h" => { println!("{USAGE}"); Ok(()) }
+        command => anyhow::bail!("unknown command {command}")
+    });
+
+    if let Err(e) = result {
+        eprintln!("error: {e:#}\n\n{USAGE}");
+        process::exit(1);
+    }
+}
+
+fn build(args: &Args) -> Result<()> {
+    let file_path = args.positional(0, "data.csv")?;
+    let snapshot_path = args.positional(1, "snapshot")?;
+    let magds = magds_from_csv(args, file_path)?;
+    magds.save(snapshot_path)?;
+    eprintln!(
+        "saved {} neurons and {} sensors to {snapshot_path}",
+        magds.neurons().len(),
+        magds.sensors().len()
+    );
+    Ok(())
+}
+
+fn query(args: &Args) -> Result<()> {
+    let magds = load(args, args.positional(0, "snapshot or data.csv")?)?;
+    let records = query::query(&magds, args.positional(1, "query")?)?;
+
+    let mut header = vec!["id", "activation"];
+    for record in &records {
+        for (name, _) in &record.values {
+            if !header.contains(&name.as_str()) { header.push(name) }
+        }
+    }
+    let records: Vec<Vec<Value>> = records.iter()
+        .map(|record| {
+            let mut fields = vec![json!(record.id.id), json!(record.activation)];
+            for name in &header[2..] {
+                fields.push(match record.values.iter().find(|(x, _)| x == name) {
+                    Some((_, value)) => value_to_json(value),
+                    None => Value::Null
+                });
+            }
+            fields
+        })
+        .collect();
+    write_table(args, &header, &records)
+}
+
+fn predict(args: &Args) -> Result<()> {
+    let magds = load(args, args.positional(0, "snapshot or data.csv")?)?;
+    let target = args.required("target")?;
+    let target_id = sensor_id(&magds, target)?;
+    let fuzzy = !args.flag("no-fuzzy");
+    let winners_limit = args.parsed("winners", 12usize)?;
+    let weight_ratio = args.parsed("weight-ratio", f32::ln(magds.neurons().len() as f32))?;
+
+    let rows: Vec<Vec<(String, String)>> = match (args.option("features"), args.option("input")) {
+        (Some(features), None) => vec![
+            features.split(',')
+                .map(|x| match x.split_once('=') {
+                    Some((name, value)) => Ok((name.trim().to_string(), value.trim().to_string())),
+                    None => anyhow::bail!("feature {x} should be given as name=value")
+                })
+                .collect::<Result<_>>()?
+        ],
+        (None, Some(input)) => {
+            let df = polars_common::csv_to_dataframe(input, &args.skip())?;
+            (0..df.height()).map(|i| row(&df, i)).collect()
+        }
+        _ => anyhow::bail!("either --features or --input is required")
+    };
+
+    let mut predictions = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut features: Vec<(u32, DataTypeValue, f32)> = Vec::new();
+        for (name, value) in row {
+            if name == target { continue }
+            let id = sensor_id(&magds, &name)?;
+            features.push((id, parse_value(&magds, id, &value)?, 1.0f32));
+        }
+        let prediction = prediction::predict_weighted_in(
+            &magds,
+            &features,
+            target_id,
+            fuzzy,
+            winners_limit,
+            weight_ratio,
+            MissingValues::Ignore,
+            &mut ActivationContext::new()
+        );
+        predictions.push(prediction);
+    }
+
+    let header = ["row", target, "probability"];
+    let records: Vec<Vec<Value>> = predictions.into_iter()
+        .enumerate()
+        .map(|(i, prediction)| match prediction {
+            Some(prediction) => vec![json!(i), value_to_json(&prediction.0), json!(prediction.1)],
+            None => vec![json!(i), Value::Null, Value::Null]
+        })
+        .collect();
+    write_table(args, &header, &records)
+}
+
+fn evaluate(args: &Args) -> Result<()> {
+    let file_path = args.positional(0, "train.csv")?;
+    let target = args.required("target")?;
+    let fuzzy = !args.flag("no-fuzzy");
+    let weighted = args.flag("weighted");
+    let winners_limit = args.parsed("winners", 12usize)?;
+
+    let config = || -> Result<EvaluationConfig> {
+        Ok(EvaluationConfig {
+            seed: args.parsed("seed", 42u64)?,
+            stratified: args.flag("stratified"),
+            fuzzy,
+            weighted,
+            winners_limit,
+            weight_ratio: args.option("weight-ratio")
+                .map(|_| args.parsed("weight-ratio", 0.0f32))
+                .transpose()?,
+            weighting_strategy: args.weighting_strategy()?,
+            interelement_activation_threshold: args.threshold()?,
+            interelement_activation_exponent: args.exponent()?
+        })
+    };
+
+    let header = ["metric", "mean", "std"];
+    let records = if let Some(test_path) = args.option("test") {
+        let train = magds_from_csv(args, file_path)?;
+        let test = magds_from_csv(args, test_path)?;
+        let weight_ratio = args.parsed("weight-ratio", f32::ln(train.neurons().len() as f32))?;
+        let performance = prediction::prediction_score_custom(
+            &train, &test, target.into(), fuzzy, weighted, winners_limit, weight_ratio
+        )?;
+        metrics(&performance)?.into_iter()
+            .map(|(metric, value)| vec![json!(metric), json!(value), json!(0.0)])
+            .collect()
+    } else {
+        let df = polars_common::csv_to_dataframe(file_path, &args.skip())?;
+        let cross_validation = match (args.option("k-fold"), args.option("holdout")) {
+            (Some(_), None) => {
+                let k = args.parsed("k-fold", 5usize)?;
+                evaluation::cross_validate(&df, target, k, &config()?)?
+            }
+            (None, Some(_)) => {
+                let ratio = args.parsed("holdout", 0.2f32)?;
+                let fold = evaluation::holdout(&df, target, ratio, &config()?)?;
+                CrossValidation { folds: vec![fold] }
+            }
+            _ => anyhow::bail!("one of --test, --k-fold or --holdout is required")
+        };
+        let mut records = Vec::new();
+        for (metric, _) in metrics(&cross_validation.folds[0])? {
+            let (mean, std) = cross_validation.summary(|x| {
+                metrics(x)?.into_iter()
+                    .find(|(name, _)| *name == metric)
+                    .map(|(_, value)| value)
+                    .context(format!("missing {metric}"))
+            })?;
+            records.push(vec![json!(metric), json!(mean), json!(std)]);
+        }
+        records
+    };
+    write_table(args, &header, &records)
+}
+
+fn inspect(args: &Args) -> Result<()> {
+    let magds = load(args, args.positional(0, "snapshot or data.csv")?)?;
+
+    for id in sorted(magds.neuron_group_ids()) {
+        let name = magds.neuron_group_name_from_id(id).unwrap_or_default();
+        let neurons = magds.neurons().iter().filter(|x| x.borrow().id().parent_id == id).count();
+        eprintln!("neuron group {id} {name}: {neurons} neurons");
+    }
+
+    let header = [
+        "id", "name", "data_type", "category", "elements", "nulls", "min", "max", "entropy"
+    ];
+    let mut records = Vec::new();
+    for sensor in magds.sensors() {
+        let sensor = sensor.borrow();
+        let id = sensor.id();
+        let values = sensor.values();
+        let is_sortable = sensor.data_category().is_sortable();
+        let extremum = |value: Option<&DataTypeValue>| match value {
+            Some(value) if is_sortable => value_to_json(value),
+            _ => Value::Null
+        };
+        let nulls = magds.sensor_null_element(id).map(|x| x.borrow().counter()).unwrap_or(0);
+        records.push(vec![
+            json!(id),
+            json!(magds.sensor_name(id).unwrap_or_default()),
+            json!(format!("{:?}", sensor.data_type())),
+            json!(format!("{:?}", sensor.data_category())),
+            json!(values.len()),
+            json!(nulls),
+            extremum(values.first()),
+            extremum(values.last()),
+            json!(similarity::entropy(&*sensor).ok())
+        ]);
+    }
+    records.sort_by_key(|x| x[0].as_u64());
+    write_table(args, &header, &records)
+}
+
+fn magds_from_csv(args: &Args, file_path: &str) -> Result<MAGDS> {
+    let name = match args.option("name") {
+        Some(name) => name.to_string(),
+        None => file_stem(file_path)
+    };
+    parser::magds_from_csv_custom(
+        &name,
+        file_path,
+        &args.skip(),
+        args.weighting_strategy()?,
+        args.threshold()?,
+        args.exponent()?
+    ).context(format!("error reading csv file {file_path}"))
+}
+
+fn load(args: &Args, file_path: &str) -> Result<MAGDS> {
+    if file_path.ends_with(".csv") {
+        magds_from_csv(args, file_path)
+    } else {
+        MAGDS::load(file_path)
+    }
+}
+
+fn file_stem(file_path: &str) -> String {
+    std::path::Path::new(file_path)
+        .file_stem()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or_else(|| "magds".to_string())
+}
+
+fn sensor_id(magds: &MAGDS, name: &str) -> Result<u32> {
+    magds.sensor_ids(name)
+        .and_then(|ids| ids.first().cloned())
+        .context(format!("unknown feature {name}"))
+}
+
+fn parse_value(magds: &MAGDS, sensor_id: u32, value: &str) -> Result<DataTypeValue> {
+    if value.is_empty() { return Ok(DataTypeValue::Null) }
+    let data_type = magds.sensor_data_type(sensor_id).unwrap_or(MAGDSDataType::Unknown);
+    Literal::String(value.to_string()).to_value(data_type)
+        .context(format!("cannot parse {value} as {:?}", data_type))
+}
+
+fn row(df: &DataFrame, i: usize) -> Vec<(String, String)> {
+    df.get_columns()
+        .iter()
+        .filter_map(|column| {
+            let value = match column.get(i) {
+                AnyValue::Null => return None,
+                AnyValue::Utf8(v) => v.to_string(),
+                v => v.to_string()
+            };
+            Some((column.name().to_string(), value))
+        })
+        .collect()
+}
+
+fn metrics(performance: &SupervisedPerformance) -> Result<Vec<(&'static str, f64)>> {
+    let mean_probability = performance.mean_probability()? as f64;
+    Ok(match performance {
+        SupervisedPerformance::Classification(_) => vec![
+            ("accuracy", performance.accuracy()?),
+            ("mean_probability", mean_probability)
+        ],
+        SupervisedPerformance::Regression(_) => vec![
+            ("rmse", performance.rmse()?),
+            ("nrmse", performance.nrmse()?),
+            ("mae", performance.mae()?),
+            ("mean_probability", mean_probability)
+        ]
+    })
+}
+
+fn value_to_json(value: &DataTypeValue) -> Value {
+    if value.is_null() || value.is_unknown() { return Value::Null }
+    let is_textual = matches!(
+        value, DataTypeValue::ArcStr(_) | DataTypeValue::String(_) | DataTypeValue::Bool(_)
+    );
+    match value.to_f64() {
+        Some(v) if !is_textual => json!(v),
+        _ => json!(value.to_string())
+    }
+}
+
+fn write_table(args: &Args, header: &[&str], records: &[Vec<Value>]) -> Result<()> {
+    let output = match args.option("format").unwrap_or("csv") {
+        "csv" => {
+            let mut output = header.join(",") + "\n";
+            for record in records {
+                let fields: Vec<String> = record.iter()
+                    .map(|field| match field {
+                        Value::Null => String::new(),
+                        Value::String(v) if v.contains([',', '"', '\n']) => {
+                            format!("\"{}\"", v.replace('"', "\"\""))
+                        }
+                        Value::String(v) => v.clone(),
+                        v => v.to_string()
+                    })
+                    .collect();
+                output += &(fields.join(",") + "\n");
+            }
+            output
+        }
+        "json" => {
+            let objects: Vec<Value> = records.iter()
+                .map(|record| {
+                    Value::Object(
+                        header.iter().map(|x| x.to_string()).zip(record.iter().cloned()).collect()
+                    )
+                })
+                .collect();
+            serde_json::to_string_pretty(&objects)? + "\n"
+        }
+        format => anyhow::bail!("unknown format {format}")
+    };
+
+    match args.option("output") {
+        Some(file_path) => fs::write(file_path, output)?,
+        None => print!("{output}")
+    }
+    Ok(())
+}
+
+fn sorted(mut ids: Vec<u32>) -> Vec<u32> {
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
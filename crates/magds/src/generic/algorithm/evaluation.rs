@@ -0,0 +1,284 @@
+use std::fmt::{ Debug, Formatter, Result as FmtResult };
+
+use anyhow::Result;
+
+use rand::{ SeedableRng, rngs::StdRng, seq::SliceRandom };
+
+use polars::prelude::*;
+
+use witchnet_common::{
+    connection::collective::defining::ConstantOneWeight,
+    performance::SupervisedPerformance
+};
+
+use super::{
+    prediction,
+    super::{
+        common::{ pointer::*, collective::defining::DefiningWeightingStrategy },
+        parser
+    }
+};
+
+#[derive(Clone)]
+pub struct EvaluationConfig {
+    pub seed: u64,
+    /// keeps the target distribution in every split, categorical targets are grouped by value,
+    /// numerical targets are dealt out in the sorted order
+    pub stratified: bool,
+    pub fuzzy: bool,
+    pub weighted: bool,
+    pub winners_limit: usize,
+    /// defaults to ln of the train records count
+    pub weight_ratio: Option<f32>,
+    pub weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
+    pub interelement_activation_threshold: f32,
+    pub interelement_activation_exponent: i32
+}
+
+impl Default for EvaluationConfig {
+    fn default() -> Self {
+        EvaluationConfig {
+            seed: 42,
+            stratified: false,
+            fuzzy: true,
+            weighted: false,
+            winners_limit: 12,
+            weight_ratio: None,
+            weighting_strategy: Ptr::new(ConstantOneWeight),
+            interelement_activation_threshold: 0.00001,
+            interelement_activation_exponent: 1
+        }
+    }
+}
+
+impl Debug for EvaluationConfig {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("EvaluationConfig")
+            .field("seed", &self.seed)
+            .field("stratified", &self.stratified)
+            .field("fuzzy", &self.fuzzy)
+            .field("weighted", &self.weighted)
+            .field("winners_limit", &self.winners_limit)
+            .field("weight_ratio", &self.weight_ratio)
+            .field("weighting_strategy", &self.weighting_strategy.name())
+            .field("interelement_activation_threshold", &self.interelement_activation_threshold)
+            .field("interelement_activation_exponent", &self.interelement_activation_exponent)
+            .finish()
+    }
+}
+
+pub struct CrossValidation {
+    pub folds: Vec<SupervisedPerformance>
+}
+
+impl CrossValidation {
+    /// mean and standard deviation of the metric across folds
+    pub fn summary(
+        &self, metric: impl Fn(&SupervisedPerformance) -> Result<f64>
+    ) -> Result<(f64, f64)> {
+        if self.folds.is_empty() { anyhow::bail!("no folds to summarize") }
+        let values = self.folds.iter().map(metric).collect::<Result<Vec<f64>>>()?;
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        Ok((mean, variance.sqrt()))
+    }
+
+    pub fn accuracy(&self) -> Result<(f64, f64)> { self.summary(|x| x.accuracy()) }
+
+    pub fn rmse(&self) -> Result<(f64, f64)> { self.summary(|x| x.rmse()) }
+
+    pub fn nrmse(&self) -> Result<(f64, f64)> { self.summary(|x| x.nrmse()) }
+
+    pub fn mae(&self) -> Result<(f64, f64)> { self.summary(|x| x.mae()) }
+
+    pub fn mean_probability(&self) -> Result<(f64, f64)> {
+        self.summary(|x| Ok(x.mean_probability()? as f64))
+    }
+}
+
+pub fn train_test_split(
+    df: &DataFrame, target: &str, test_ratio: f32, seed: u64, stratified: bool
+) -> Result<(DataFrame, DataFrame)> {
+    if !(0.0..1.0).contains(&test_ratio) { anyhow::bail!("test_ratio should be in [0, 1)") }
+
+    // every n / test_len-th row of the ordered indices goes to the test set
+    let n = df.height();
+    let test_len = f32::round(n as f32 * test_ratio) as usize;
+    let (mut train, mut test) = (Vec::new(), Vec::new());
+    for (position, index) in ordered_indices(df, target, seed, stratified)?.into_iter().enumerate() {
+        if (position + 1) * test_len / n > position * test_len / n {
+            test.push(index);
+        } else {
+            train.push(index);
+        }
+    }
+
+    Ok((take(df, train)?, take(df, test)?))
+}
+
+/// Test indices of each fold, every row is in exactly one of them.
+pub fn k_fold_indices(
+    df: &DataFrame, target: &str, k: usize, seed: u64, stratified: bool
+) -> Result<Vec<Vec<usize>>> {
+    if k < 2 || k > df.height() { anyhow::bail!("k should be in [2, {}]", df.height()) }
+
+    let mut folds = vec![Vec::new(); k];
+    for (position, index) in ordered_indices(df, target, seed, stratified)?.into_iter().enumerate() {
+        folds[position % k].push(index);
+    }
+    for fold in &mut folds { fold.sort_unstable(); }
+
+    Ok(folds)
+}
+
+pub fn holdout(
+    df: &DataFrame, target: &str, test_ratio: f32, config: &EvaluationConfig
+) -> Result<SupervisedPerformance> {
+    let (train, test) = train_test_split(df, target, test_ratio, config.seed, config.stratified)?;
+    score(&train, &test, target, config)
+}
+
+pub fn cross_validate(
+    df: &DataFrame, target: &str, k: usize, config: &EvaluationConfig
+) -> Result<CrossValidation> {
+    let folds = k_fold_indices(df, target, k, config.seed, config.stratified)?;
+
+    let mut performances = Vec::with_capacity(k);
+    for (i, test_indices) in folds.iter().enumerate() {
+        log::info!("cross validation fold {}/{k}", i + 1);
+        let train_indices: Vec<usize> = folds.iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .flat_map(|(_, fold)| fold.iter().cloned())
+            .collect();
+        let train = take(df, train_indices)?;
+        let test = take(df, test_indices.clone())?;
+        performances.push(score(&train, &test, target, config)?);
+    }
+
+    Ok(CrossValidation { folds: performances })
+}
+
+fn score(
+    train: &DataFrame, test: &DataFrame, target: &str, config: &EvaluationConfig
+) -> Result<SupervisedPerformance> {
+    let magds = parser::magds_from_df_custom(
+        "train",
+        train,
+        &[],
+        0,
+        false,
+        config.weighting_strategy.clone(),
+        config.interelement_activation_threshold,
+        config.interelement_activation_exponent
+    );
+    let weight_ratio = config.weight_ratio.unwrap_or(f32::ln(magds.neurons().len() as f32));
+    prediction::prediction_score_df_custom(
+        &magds, test, target, config.fuzzy, config.weighted, config.winners_limit, weight_ratio
+    )
+}
+
+/// Shuffled row indices, for stratification grouped by the target value
+/// or sorted by it for numerical targets, missing targets go last.
+fn ordered_indices(
+    df: &DataFrame, target: &str, seed: u64, stratified: bool
+) -> Result<Vec<usize>> {
+    let mut indices: Vec<usize> = (0..df.height()).collect();
+    indices.shuffle(&mut StdRng::seed_from_u64(seed));
+    if !stratified { return Ok(indices) }
+
+    let column = df.column(target)?;
+    if column.dtype().is_float() {
+        let values = column.cast(&DataType::Float64)?;
+        let values = values.f64()?;
+        indices.sort_by(|a, b| match (values.get(*a), values.get(*b)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal),
+            (a, b) => b.is_some().cmp(&a.is_some())
+        });
+        Ok(indices)
+    } else {
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for index in indices {
+            let key = column.get(index).to_string();
+            match groups.iter_mut().find(|(group, _)| *group == key) {
+                Some((_, group)) => group.push(index),
+                None => groups.push((key, vec![index]))
+            }
+        }
+        Ok(groups.into_iter().flat_map(|(_, group)| group).collect())
+    }
+}
+
+fn take(df: &DataFrame, indices: Vec<usize>) -> Result<DataFrame> {
+    let indices = IdxCa::from_vec("indices", indices.into_iter().map(|x| x as IdxSize).collect());
+    Ok(df.take(&indices)?)
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use witchnet_common::polars as polars_common;
+
+        use super::EvaluationConfig;
+
+        #[test]
+        fn train_test_split() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+
+            let (train, test) = super::train_test_split(&df, "variety", 0.2, 7, true).unwrap();
+            assert_eq!(train.height() + test.height(), df.height());
+            assert_eq!(test.height(), 30);
+            for variety in ["Setosa", "Versicolor", "Virginica"] {
+                let count = test.column("variety").unwrap()
+                    .utf8().unwrap()
+                    .into_iter()
+                    .filter(|x| *x == Some(variety))
+                    .count();
+                assert!((9..=10).contains(&count));
+            }
+
+            let (train_again, _) = super::train_test_split(&df, "variety", 0.2, 7, true).unwrap();
+            assert!(train.frame_equal_missing(&train_again));
+
+            let (_, test) = super::train_test_split(&df, "petal.length", 0.1, 7, true).unwrap();
+            assert_eq!(test.height(), 15);
+            assert!(super::train_test_split(&df, "variety", 1.0, 7, false).is_err());
+        }
+
+        #[test]
+        fn k_fold_indices() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+
+            let folds = super::k_fold_indices(&df, "variety", 5, 1, false).unwrap();
+            assert_eq!(folds.len(), 5);
+            let mut all: Vec<usize> = folds.iter().flatten().cloned().collect();
+            all.sort_unstable();
+            assert_eq!(all, (0..df.height()).collect::<Vec<usize>>());
+            assert!(folds.iter().all(|x| x.len() == 29 || x.len() == 30));
+            assert_ne!(folds, super::k_fold_indices(&df, "variety", 5, 2, false).unwrap());
+
+            assert!(super::k_fold_indices(&df, "variety", 1, 1, false).is_err());
+        }
+
+        #[test]
+        fn cross_validate() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+            let config = EvaluationConfig { stratified: true, ..Default::default() };
+
+            let classification = super::cross_validate(&df, "variety", 5, &config).unwrap();
+            assert_eq!(classification.folds.len(), 5);
+            let (mean, std) = classification.accuracy().unwrap();
+            println!("variety accuracy: {mean} +/- {std}");
+            assert!(mean > 0.85);
+            assert!(std >= 0.0);
+
+            let regression = super::cross_validate(&df, "petal.length", 3, &config).unwrap();
+            let (mean, std) = regression.rmse().unwrap();
+            println!("petal.length rmse: {mean} +/- {std}");
+            assert!(mean > 0.0 && mean < 1.0);
+
+            let holdout = super::holdout(&df, "variety", 0.3, &config).unwrap();
+            assert!(holdout.accuracy().unwrap() > 0.85);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod prediction;
+pub mod recommendation;
+pub mod similarity;
+pub mod evaluation;
+pub mod search;
@@ -0,0 +1,1107 @@
+use std::{
+    cmp::Ordering,
+    slice,
+    collections::HashMap,
+    sync::Arc,
+    str::FromStr
+};
+
+use witchnet_common::{
+    data::{ DataTypeValue, DataCategory, DataType },
+    neuron::{ NeuronID, ActivationContext },
+    polars::{ self as polars_common, DataVecOption },
+    performance::{ SupervisedPerformance, DataProbability },
+    temporal::{ Date, DateTime, Duration }
+};
+use polars::{
+    prelude::*,
+    export::num::ToPrimitive
+};
+
+use super::{
+    similarity,
+    super::{
+        common::{ pointer::*, neuron::Neuron, sensor::Sensor },
+        magds::MAGDS
+    }
+};
+
+/// How features with missing (`Null`) values take part in the activation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MissingValues {
+    /// missing query values are skipped and records with missing values are not affected
+    Ignore,
+    /// records missing an activated feature get a negative signal of the given ratio,
+    /// a missing query value activates the records missing the same feature
+    Penalize(f32),
+    /// missing query values are replaced with the most common (median) sensor value,
+    /// records missing an activated feature get the mean activation of the sensor
+    Impute
+}
+
+/// Why `predict_weighted_explained` predicted the value.
+#[derive(Debug, Clone)]
+pub struct PredictionExplanation {
+    /// neurons that voted, ordered by activation
+    pub winners: Vec<WinnerExplanation>,
+    /// `(sensor_id, share)` of the vote weighted winners activation, shares sum up to 1
+    pub feature_shares: Vec<(u32, f32)>
+}
+
+#[derive(Debug, Clone)]
+pub struct WinnerExplanation {
+    pub id: NeuronID,
+    pub activation: f32,
+    /// vote weight, decreasing with the winner position
+    pub weight: f32,
+    pub target: DataTypeValue,
+    pub contributions: Vec<ElementContribution>
+}
+
+/// Signal received by a winner from its sensor element.
+#[derive(Debug, Clone)]
+pub struct ElementContribution {
+    pub element: NeuronID,
+    pub value: DataTypeValue,
+    pub element_activation: f32,
+    pub signal: f32,
+    /// false for fuzzy neighbours of the query value
+    pub is_exact: bool
+}
+
+pub fn predict(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue)>,
+    target: u32
+) -> Option<DataProbability> {
+    let features: Vec<(u32, DataTypeValue, f32)> = features.into_iter()
+        .map(|(id, value)| (*id, value.clone(), 1.0f32))
+        .collect();
+    predict_weighted(magds, &features, target, true, 12, f32::ln(features.len() as f32))
+}
+
+pub fn predict_custom(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue)>,
+    target: u32,
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32
+) -> Option<DataProbability> {
+    let features: Vec<(u32, DataTypeValue, f32)> = features.into_iter()
+        .map(|(id, value)| (*id, value.clone(), 1.0f32))
+        .collect();
+    predict_weighted(magds, &features, target, fuzzy, winners_limit, weight_ratio)
+}
+
+pub fn predict_weighted(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue, f32)>,
+    target: u32,
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32
+) -> Option<DataProbability> {
+    predict_weighted_missing(
+        magds, features, target, fuzzy, winners_limit, weight_ratio, MissingValues::Ignore
+    )
+}
+
+pub fn predict_weighted_missing(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue, f32)>,
+    target: u32,
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32,
+    missing: MissingValues
+) -> Option<DataProbability> {
+    predict_weighted_in(
+        magds, 
+        features, 
+        target, 
+        fuzzy, 
+        winners_limit, 
+        weight_ratio, 
+        missing, 
+        &mut ActivationContext::new()
+    )
+}
+
+/// Same as `predict_weighted_missing` but the activations are accumulated in `context`,
+/// so several queries can share the magds at once.
+pub fn predict_weighted_in(
+    magds: &MAGDS, 
+    features: &[(u32, DataTypeValue, f32)],
+    target: u32,
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32,
+    missing: MissingValues,
+    context: &mut ActivationContext
+) -> Option<DataProbability> {
+    let max_activation_sum = activate_features_in(magds, features, fuzzy, missing, context);
+    let (prediction, _) = predict_activated(
+        magds, target, winners_limit, weight_ratio, max_activation_sum, context
+    )?;
+    Some(prediction)
+}
+
+/// Same as `predict_weighted_missing` but also explains which winners voted and how much
+/// each feature contributed to them.
+pub fn predict_weighted_explained(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue, f32)>,
+    target: u32,
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32,
+    missing: MissingValues
+) -> Option<(DataProbability, PredictionExplanation)> {
+    // activation is additive, so activating features one by one gives per feature signals
+    let mut context = ActivationContext::new();
+    let mut max_activation_sum = 0.0f32;
+    let mut signals: HashMap<NeuronID, Vec<(u32, f32)>> = HashMap::new();
+    let mut previous: HashMap<NeuronID, f32> = HashMap::new();
+    for feature in features {
+        max_activation_sum += activate_features_in(
+            magds, slice::from_ref(feature), fuzzy, missing, &mut context
+        );
+        for (id, activation) in context.neurons() {
+            let previous = previous.entry(id.clone()).or_default();
+            let signal = activation - *previous;
+            if signal != 0.0f32 {
+                signals.entry(id.clone()).or_default().push((feature.0, signal));
+            }
+            *previous = activation;
+        }
+    }
+
+    let (prediction, winners) = predict_activated(
+        magds, target, winners_limit, weight_ratio, max_activation_sum, &context
+    )?;
+
+    let mut feature_signals: HashMap<u32, f32> = HashMap::new();
+    let mut total_signal = 0.0f32;
+    let mut winner_explanations = Vec::with_capacity(winners.len());
+    for (neuron, activation, weight, target_value) in winners {
+        let neuron = neuron.borrow();
+        let mut contributions = Vec::new();
+        for (sensor_id, signal) in signals.remove(&neuron.id()).unwrap_or_default() {
+            *feature_signals.entry(sensor_id).or_default() += weight * signal;
+            total_signal += weight * signal;
+
+            let element = neuron.explain().iter().find(|x| x.borrow().id().parent_id == sensor_id);
+            let (element, value, element_activation) = match element {
+                Some(element) => {
+                    let element = element.borrow();
+                    (element.id(), element.value(), element.activation_in(&context))
+                }
+                None => continue
+            };
+            let is_exact = features.iter().any(|x| x.0 == sensor_id && x.1 == value);
+            contributions.push(ElementContribution {
+                element, value, element_activation, signal, is_exact
+            });
+        }
+        winner_explanations.push(WinnerExplanation {
+            id: neuron.id(), activation, weight, target: target_value, contributions
+        });
+    }
+
+    let mut feature_shares: Vec<(u32, f32)> = features.iter()
+        .map(|x| x.0)
+        .filter_map(|id| Some((id, feature_signals.remove(&id)? / total_signal)))
+        .collect();
+    feature_shares.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    Some((prediction, PredictionExplanation { winners: winner_explanations, feature_shares }))
+}
+
+/// Predicts several targets from a single activation of the features,
+/// targets that cannot be predicted are missing from the result.
+pub fn predict_multi(
+    magds: &MAGDS, 
+    features: &[(u32, DataTypeValue)],
+    targets: &[u32]
+) -> HashMap<u32, DataProbability> {
+    let features: Vec<(u32, DataTypeValue, f32)> = features.iter()
+        .map(|(id, value)| (*id, value.clone(), 1.0f32))
+        .collect();
+    predict_multi_weighted(
+        magds,
+        &features,
+        targets,
+        true,
+        12,
+        f32::ln(features.len() as f32),
+        MissingValues::Ignore
+    )
+}
+
+pub fn predict_multi_weighted(
+    magds: &MAGDS, 
+    features: &[(u32, DataTypeValue, f32)],
+    targets: &[u32],
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32,
+    missing: MissingValues
+) -> HashMap<u32, DataProbability> {
+    let mut context = ActivationContext::new();
+    let max_activation_sum = activate_features_in(magds, features, fuzzy, missing, &mut context);
+    targets.iter()
+        .filter_map(|target| {
+            let (prediction, _) = predict_activated(
+                magds, *target, winners_limit, weight_ratio, max_activation_sum, &context
+            )?;
+            Some((*target, prediction))
+        })
+        .collect()
+}
+
+/// winner neuron, its activation, vote weight and target value
+type Winner = (Ptr<Lock<dyn Neuron>>, f32, f32, DataTypeValue);
+
+fn predict_activated(
+    magds: &MAGDS,
+    target: u32,
+    winners_limit: usize,
+    weight_ratio: f32,
+    max_activation_sum: f32,
+    context: &ActivationContext
+) -> Option<(DataProbability, Vec<Winner>)> {
+    if max_activation_sum == 0.0f32 {
+        log::warn!("no feature activated, prediction impossible");
+        return None
+    }
+
+    let neurons = &magds.neurons;
+
+    let neurons_len = neurons.len();
+    if neurons_len == 0 { return None }
+
+    // let winners_limit = usize::min(12usize, neurons_len);
+
+    let mut neurons_sorted: Vec<(f32, Ptr<Lock<dyn Neuron>>)> = neurons.into_iter()
+        .map(|neuron| (
+            neuron.borrow().activation_in(context), neuron.clone() as Ptr<Lock<dyn Neuron>>
+        ))
+        .collect();
+    neurons_sorted.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    // let neurons_sorted = &neurons_sorted[(neurons_len - winners_limit)..neurons_len];
+
+    let target_data_category = match magds.sensor(target) {
+        Some(s) => s.borrow().data_category(),
+        None => { log::error!("error getting sensor {target}"); return None }
+    };
+    let target_data_type = magds.sensor(target).unwrap().borrow().data_type();
+
+    // let weight_ratio = f32::ln(features.len() as f32);
+
+    match target_data_category {
+        DataCategory::Continuous => {
+            let mut targets_weighted: Vec<f64> = Vec::new();
+            let mut probas: Vec<f32> = Vec::new();
+            let mut weights = 0.0f32;
+            let mut current_weight = 1.0f32;
+            let mut winners_counter = 0;
+            let mut winners = Vec::new();
+            for (neuron_activation, neuron) in (neurons_sorted).into_iter().rev() {
+                let target_value = neuron.borrow().explain_one(target).filter(|x| !x.is_null());
+                if let Some(target_value) = target_value {
+                    targets_weighted.push(target_value.to_f64().unwrap() * current_weight as f64);
+                    weights += current_weight;
+                    probas.push((neuron_activation.to_f32().unwrap() / max_activation_sum) * current_weight);
+                    winners.push((neuron.clone(), neuron_activation, current_weight, target_value));
+                    current_weight /= weight_ratio;
+
+                    winners_counter += 1;
+                    if winners_counter >= winners_limit { break }
+                }
+            }
+            
+            if targets_weighted.is_empty() {
+                log::warn!("all winners has no target feature");
+                return None
+            }
+
+            let predicted_value_f64: f64 = targets_weighted.iter().sum::<f64>() / weights as f64;
+            let predicted_value: DataTypeValue = match target_data_type {
+                DataType::U8 => (predicted_value_f64 as u8).into(),
+                DataType::U16 => (predicted_value_f64 as u16).into(),
+                DataType::U32 => (predicted_value_f64 as u32).into(),
+                DataType::U64 => (predicted_value_f64 as u64).into(),
+                DataType::U128 => (predicted_value_f64 as u128).into(),
+                DataType::USize => (predicted_value_f64 as usize).into(),
+                DataType::I8 => (predicted_value_f64 as i8).into(),
+                DataType::I16 => (predicted_value_f64 as i16).into(),
+                DataType::I32 => (predicted_value_f64 as i32).into(),
+                DataType::I64 => (predicted_value_f64 as i64).into(),
+                DataType::I128 => (predicted_value_f64 as i128).into(),
+                DataType::ISize => (predicted_value_f64 as isize).into(),
+                DataType::F32 => (predicted_value_f64 as f32).into(),
+                DataType::F64 => (predicted_value_f64 as f64).into(),
+                DataType::Date => Date(predicted_value_f64.round() as i32).into(),
+                DataType::DateTime => DateTime(predicted_value_f64.round() as i64).into(),
+                DataType::Duration => Duration(predicted_value_f64.round() as i64).into(),
+                _ => { log::error!("classified as numerical data so shouldn't be here"); return None }
+            };
+            let proba: f32 = probas.iter().sum::<f32>() / weights;
+            Some((DataProbability(predicted_value, proba), winners))
+        }
+        DataCategory::Categorical | DataCategory::Discrete => {
+            let mut values: HashMap<String, f32> = HashMap::new();
+            let mut probas: Vec<f32> = Vec::new();
+            let mut weights = 0.0f32;
+            let mut current_weight = 1.0f32;
+            let mut winners_counter = 0;
+            let mut winners = Vec::new();
+            for (neuron_activation, neuron) in (neurons_sorted).into_iter().rev() {
+                let target_value = neuron.borrow().explain_one(target).filter(|x| !x.is_null());
+                if let Some(target_value) = target_value {
+                    winners.push((neuron.clone(), neuron_activation, current_weight, target_value.clone()));
+                    let target_value = target_value.to_string();
+                    if values.contains_key(&target_value) {
+                        let current_value = values.get_mut(&target_value).unwrap();
+                        *current_value += current_weight;
+                    } else {
+                        values.insert(target_value, current_weight);
+                    }
+                    weights += current_weight;
+                    probas.push((neuron_activation.to_f32().unwrap() / max_activation_sum) * current_weight);
+                    current_weight /= weight_ratio;
+
+                    winners_counter += 1;
+                    if winners_counter >= winners_limit { break }
+                }
+            }
+
+            if values.is_empty() {
+                log::warn!("all winners has no target feature");
+                return None
+            }
+
+            let mut values_sorted: Vec<(String, f32)> = values.into_iter()
+                .map(|(name, value)| (name, value))
+                .collect();
+            values_sorted.sort_unstable_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+            let predicted_value_str = values_sorted.into_iter().next_back()?.0;
+            let predicted_value: DataTypeValue = match target_data_type {
+                DataType::Bool => bool::from_str(&predicted_value_str).ok()?.into(),
+                DataType::ArcStr => Arc::<str>::from(predicted_value_str).into(),
+                DataType::String => predicted_value_str.to_string().into(),
+                _ => { log::error!("classified as not numerical data so shouldn't be here"); return None }
+            };
+            let proba: f32 = probas.iter().sum::<f32>() / weights;
+            Some((DataProbability(predicted_value, proba), winners))
+        }
+    }
+}
+
+/// Inserts query values missing in the continuous and discrete sensors,
+/// so the fuzzy activation can spread from them to their neighbours.
+pub fn insert_missing_values(magds: &mut MAGDS, features: &[(u32, DataTypeValue, f32)]) {
+    for (id, value, _weight) in features {
+        if value.is_null() || magds.sensor_search(*id, value).is_some() { continue }
+        match magds.sensor_data_category(*id) {
+            Some(DataCategory::Continuous) | Some(DataCategory::Discrete) => {
+                log::info!("cannot find sensor {id} value {:?}, inserting", value);
+                if magds.sensor_insert(*id, value).is_none() {
+                    log::warn!("cannot insert {:?} to {id}", value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Activates the features in `context` without changing the magds, query values missing
+/// in the sensors are skipped, or activate their neighbours in the continuous and discrete
+/// sensors if `fuzzy`.
+pub fn activate_features_in(
+    magds: &MAGDS,
+    features: &[(u32, DataTypeValue, f32)],
+    fuzzy: bool,
+    missing: MissingValues,
+    context: &mut ActivationContext
+) -> f32 {
+    let mut max_activation_sum = 0.0f32;
+
+    for (id, value, weight) in features {
+        let value = &if value.is_null() {
+            match missing {
+                MissingValues::Ignore => continue,
+                MissingValues::Penalize(_) => {
+                    if let Some(null_element) = magds.sensor_null_element(*id) {
+                        max_activation_sum += null_element.borrow().activate_in(
+                            *weight, false, true, context
+                        );
+                    }
+                    continue
+                }
+                MissingValues::Impute => match impute(magds, *id) {
+                    Some(value) => value,
+                    None => {
+                        log::warn!("cannot impute missing value for sensor {id}, skipping");
+                        continue
+                    }
+                }
+            }
+        } else {
+            value.clone()
+        };
+
+        log::debug!(
+            "sensor {}: {}", 
+            magds.sensor_name(*id).unwrap(), 
+            magds.sensor(*id).unwrap().borrow()
+        );
+        // tokens of a multi-value feature share its weight, so objects score by set overlap
+        let tokens = magds.sensor_tokens(*id, value);
+        let signal = *weight / tokens.len() as f32;
+        let mut found = false;
+        for token in &tokens {
+            let activation = match magds.sensor_search(*id, token) {
+                Some(sensor) => Ok(sensor.borrow().activate_in(signal, fuzzy, true, context)),
+                None if fuzzy => {
+                    magds.sensor(*id).unwrap().borrow().activate_in(token, signal, true, true, context)
+                }
+                None => Err(anyhow::anyhow!("missing value"))
+            };
+            let activation = match activation {
+                Ok(activation) => activation,
+                Err(_) => {
+                    log::warn!("cannot find sensor {id} for value {:?}, skipping", token);
+                    continue
+                }
+            };
+            max_activation_sum += activation;
+            found = true;
+        }
+        if !found { continue }
+
+        let null_element = match magds.sensor_null_element(*id) {
+            Some(null_element) => null_element,
+            None => continue
+        };
+        match missing {
+            MissingValues::Ignore => {}
+            MissingValues::Penalize(ratio) => {
+                null_element.borrow().activate_in(-ratio * weight, false, true, context);
+            }
+            MissingValues::Impute => {
+                let signal = mean_activation(magds, *id, context);
+                null_element.borrow().activate_in(signal, false, true, context);
+            }
+        }
+    }
+
+    max_activation_sum
+}
+
+fn impute(magds: &MAGDS, sensor_id: u32) -> Option<DataTypeValue> {
+    let sensor = magds.sensor(sensor_id)?.borrow();
+    let elements = sensor.neurons();
+    match sensor.data_category() {
+        DataCategory::Categorical => elements.iter()
+            .max_by_key(|element| element.borrow().counter())
+            .map(|element| element.borrow().value()),
+        DataCategory::Continuous | DataCategory::Discrete => {
+            let half = elements.iter()
+                .map(|element| element.borrow().counter())
+                .sum::<usize>() / 2;
+            let mut counter = 0;
+            for element in &elements {
+                counter += element.borrow().counter();
+                if counter > half { return Some(element.borrow().value()) }
+            }
+            None
+        }
+    }
+}
+
+fn mean_activation(magds: &MAGDS, sensor_id: u32, context: &ActivationContext) -> f32 {
+    let sensor = match magds.sensor(sensor_id) {
+        Some(sensor) => sensor.borrow(),
+        None => return 0.0f32
+    };
+    let (mut activation_sum, mut counter_sum) = (0.0f32, 0usize);
+    for element in sensor.neurons() {
+        let element = element.borrow();
+        activation_sum += element.activation_in(context) * element.counter() as f32;
+        counter_sum += element.counter();
+    }
+    if counter_sum == 0 { 0.0f32 } else { activation_sum / counter_sum as f32 }
+}
+
+pub fn prediction_score(
+    train: &MAGDS, 
+    test: &MAGDS, 
+    target: Ptr<str>
+) -> anyhow::Result<SupervisedPerformance> {
+    prediction_score_custom(
+        train, test, target, true, false, 12, f32::ln(train.neurons.len() as f32)
+    )
+}
+
+pub fn prediction_score_custom(
+    train: &MAGDS, 
+    test: &MAGDS, 
+    target: Ptr<str>, 
+    fuzzy: bool,
+    weighted: bool,
+    winners_limit: usize,
+    weight_ratio: f32
+) -> anyhow::Result<SupervisedPerformance> {
+    let (target_id, queries) = test_queries(train, test, &target, weighted)?;
+    let y_len = queries.len();
+    let mut references: Vec<DataTypeValue> = Vec::with_capacity(y_len);
+    let mut predictions: Vec<DataTypeValue> = Vec::with_capacity(y_len);
+    let mut probabilities: Vec<f32> = Vec::with_capacity(y_len);
+
+    for (i, (features, test_reference_value)) in queries.into_iter().enumerate() {
+        if i % 100 == 0 { log::info!("prediction iteration: {i}"); }
+
+        let data_proba = match predict_weighted(
+            train, &features, target_id, fuzzy, winners_limit, weight_ratio
+        ) {
+            Some(dp) => dp,
+            None => continue
+        };
+        let (winner_value, winner_proba) = (data_proba.0, data_proba.1);
+        log::debug!("winner_value {:?}, test_reference_value {:?}", winner_value, test_reference_value);
+
+        references.push(test_reference_value);
+        predictions.push(winner_value);
+        probabilities.push(winner_proba);
+    }
+
+    score(train, target_id, references, predictions, probabilities)
+}
+
+/// Target sensor id in `train` and `(features, reference value)` of every `test` record
+/// having the target value, feature ids point to the `train` sensors.
+pub(crate) fn test_queries(
+    train: &MAGDS, 
+    test: &MAGDS, 
+    target: &str, 
+    weighted: bool
+) -> anyhow::Result<(u32, Vec<(Vec<(u32, DataTypeValue, f32)>, DataTypeValue)>)> {
+    let n_features = test.sensors.len();
+    let target_id = *train.sensor_ids(target).unwrap().first().unwrap();
+
+    let mut similarities: HashMap<u32, f64> = HashMap::new();
+    if weighted {
+        similarities = similarity::features_target_weights(train, target_id)?;
+    }
+
+    let mut queries = Vec::with_capacity(test.neurons.len());
+    for neuron in &test.neurons {
+        let mut features: Vec<(u32, DataTypeValue, f32)> = Vec::with_capacity(n_features);
+        let neuron_borrowed = neuron.borrow();
+        let sensors = neuron_borrowed.explain();
+        let mut test_reference_value = DataTypeValue::Unknown;
+        let mut should_skip = true;
+
+        for sensor in sensors {
+            let sensor_borrowed = sensor.borrow();
+            let sensor_id = sensor_borrowed.id();
+            let feature_id = sensor_id.parent_id;
+            let feature_name = test.sensor_name(feature_id).unwrap();
+            let feature_value = sensor_borrowed.value();
+            let feature_id_train = *train.sensor_ids(feature_name).unwrap().first().unwrap();
+            
+            if *feature_name == *target {
+                if !feature_value.is_null() {
+                    test_reference_value = feature_value;
+                    should_skip = false;
+                }
+            } else {
+                let weight = if weighted { similarities[&feature_id] as f32 } else { 1.0f32 };
+                features.push((feature_id_train, feature_value, weight));
+            }
+        }
+
+        if should_skip { continue }
+        if test_reference_value.is_unknown() { 
+            anyhow::bail!("test_reference_value shouldn't be unknown");
+        }
+        queries.push((features, test_reference_value));
+    }
+
+    Ok((target_id, queries))
+}
+
+pub(crate) fn score(
+    train: &MAGDS,
+    target_id: u32,
+    references: Vec<DataTypeValue>,
+    predictions: Vec<DataTypeValue>,
+    probabilities: Vec<f32>
+) -> anyhow::Result<SupervisedPerformance> {
+    let target_data_category = match train.sensor(target_id) {
+        Some(s) => s.borrow().data_category(),
+        None => anyhow::bail!("error getting sensor {target_id}")
+    };
+    match target_data_category {
+        DataCategory::Continuous => {
+            SupervisedPerformance::regression(references, predictions, probabilities)
+        }
+        DataCategory::Categorical | DataCategory::Discrete => {
+            SupervisedPerformance::classification(references, predictions, probabilities)
+        }
+    }
+}
+
+/// Scores `predict_multi_weighted` on the test records, every target is predicted from
+/// the remaining features of the record, so features aren't weighted per target.
+pub fn prediction_score_multi(
+    train: &MAGDS, 
+    test: &MAGDS, 
+    targets: &[Ptr<str>]
+) -> anyhow::Result<HashMap<Ptr<str>, SupervisedPerformance>> {
+    prediction_score_multi_custom(
+        train, test, targets, true, 12, f32::ln(train.neurons.len() as f32)
+    )
+}
+
+pub fn prediction_score_multi_custom(
+    train: &MAGDS, 
+    test: &MAGDS, 
+    targets: &[Ptr<str>], 
+    fuzzy: bool,
+    winners_limit: usize,
+    weight_ratio: f32
+) -> anyhow::Result<HashMap<Ptr<str>, SupervisedPerformance>> {
+    let y_len = test.neurons.len();
+    let n_features = test.sensors.len();
+
+    let mut target_ids: Vec<u32> = Vec::with_capacity(targets.len());
+    for target in targets {
+        match train.sensor_ids(target).and_then(|ids| ids.first()) {
+            Some(id) => target_ids.push(*id),
+            None => anyhow::bail!("error getting target sensor {target}")
+        }
+    }
+    let mut results: Vec<(Vec<DataTypeValue>, Vec<DataTypeValue>, Vec<f32>)> = targets.iter()
+        .map(|_| (Vec::with_capacity(y_len), Vec::with_capacity(y_len), Vec::with_capacity(y_len)))
+        .collect();
+
+    for (i, neuron) in test.neurons.iter().enumerate() {
+        if i % 100 == 0 { log::info!("prediction iteration: {i}"); }
+
+        let mut features: Vec<(u32, DataTypeValue, f32)> = Vec::with_capacity(n_features);
+        let mut reference_values: Vec<DataTypeValue> = vec![DataTypeValue::Null; targets.len()];
+        for sensor in neuron.borrow().explain() {
+            let sensor_borrowed = sensor.borrow();
+            let feature_name = test.sensor_name(sensor_borrowed.id().parent_id).unwrap();
+            let feature_value = sensor_borrowed.value();
+
+            match targets.iter().position(|target| **target == *feature_name) {
+                Some(index) => reference_values[index] = feature_value,
+                None => {
+                    let feature_id_train = *train.sensor_ids(feature_name).unwrap().first().unwrap();
+                    features.push((feature_id_train, feature_value, 1.0f32));
+                }
+            }
+        }
+
+        if reference_values.iter().all(|x| x.is_null()) { continue }
+
+        let predictions = predict_multi_weighted(
+            train, &features, &target_ids, fuzzy, winners_limit, weight_ratio, MissingValues::Ignore
+        );
+
+        for (index, reference_value) in reference_values.into_iter().enumerate() {
+            if reference_value.is_null() { continue }
+            if let Some(data_proba) = predictions.get(&target_ids[index]) {
+                let (references, predictions, probabilities) = &mut results[index];
+                references.push(reference_value);
+                predictions.push(data_proba.0.clone());
+                probabilities.push(data_proba.1);
+            }
+        }
+    }
+
+    let mut performances = HashMap::new();
+    for ((target, target_id), (references, predictions, probabilities)) in targets.iter()
+        .zip(target_ids)
+        .zip(results) {
+        let target_data_category = match train.sensor(target_id) {
+            Some(s) => s.borrow().data_category(),
+            None => anyhow::bail!("error getting sensor {target}")
+        };
+        let performance = match target_data_category {
+            DataCategory::Continuous => {
+                SupervisedPerformance::regression(references, predictions, probabilities)?
+            }
+            DataCategory::Categorical | DataCategory::Discrete => {
+                SupervisedPerformance::classification(references, predictions, probabilities)?
+            }
+        };
+        performances.insert(target.clone(), performance);
+    }
+
+    Ok(performances)
+}
+
+pub fn prediction_score_df(
+    train: &MAGDS, 
+    test: &DataFrame, 
+    target: &str
+) -> anyhow::Result<SupervisedPerformance> {
+    prediction_score_df_custom(
+        train, test, target, true, false, 12, f32::ln(train.neurons.len() as f32)
+    )
+}
+
+pub fn prediction_score_df_custom(
+    train: &MAGDS, 
+    test: &DataFrame, 
+    target: &str, 
+    fuzzy: bool,
+    weighted: bool,
+    winners_limit: usize,
+    weight_ratio: f32
+) -> anyhow::Result<SupervisedPerformance> {
+    let y_len = test.height();
+    let n_features = test.width();
+    let mut references: Vec<DataTypeValue> = Vec::with_capacity(y_len);
+    let mut predictions: Vec<DataTypeValue> = Vec::with_capacity(y_len);
+    let mut probabilities: Vec<f32> = Vec::with_capacity(y_len);
+
+    let target_id = *train.sensor_ids(target).unwrap().first().unwrap();
+
+    let mut feature_columns: HashMap<u32, DataVecOption> = HashMap::new();
+    let mut target_column: Option<DataVecOption> = None;
+    for column in test.get_columns() {
+        let column_name = column.name();
+        let datavec = match polars_common::series_to_datavec(column) {
+            Ok(v) => v,
+            Err(e) => { 
+                log::error!("error convering {column_name} to datavec, error: {e}");
+                continue
+            }
+        };
+        if column_name == target {
+            target_column = Some(datavec);
+        } else {
+            let feature_id_train = *train.sensor_ids(column_name).unwrap().first().unwrap();
+            feature_columns.insert(feature_id_train, datavec);
+        }
+    }
+    let target_column = target_column.unwrap();
+
+    let mut similarities: HashMap<u32, f64> = HashMap::new();
+    if weighted {
+        similarities = similarity::features_target_weights(train, target_id)?;
+    }
+
+    for i in 0..y_len {
+        if i % 1000 == 0 { log::info!("prediction iteration: {i}"); }
+        
+        if let Some(reference_value) = target_column.get(i) {
+            let mut features: Vec<(u32, DataTypeValue, f32)> = Vec::with_capacity(n_features);
+            for feature_id in feature_columns.keys() {
+                let weight = if weighted { similarities[feature_id] as f32 } else { 1.0f32 };
+                if let Some(feature_raw) = feature_columns[feature_id].get(i) {
+                    for feature in  feature_raw.to_vec() {
+                        features.push((*feature_id, feature, weight));
+                    }
+                }
+            }
+
+            let data_proba = match predict_weighted(
+                train, &features, target_id, fuzzy, winners_limit, weight_ratio
+            ) {
+                Some(dp) => dp,
+                None => continue
+            };
+
+            let (winner_value, winner_proba) = (data_proba.0, data_proba.1);
+            log::debug!("winner_value {:?}, reference_value {:?}", winner_value, reference_value);
+
+            references.push(reference_value);
+            predictions.push(winner_value);
+            probabilities.push(winner_proba);
+        } else {
+            log::warn!("{target} is missing for row {i}, skipping");
+            continue
+        }
+    }
+
+    let target_data_category = match train.sensor(target_id) {
+        Some(s) => s.borrow().data_category(),
+        None => anyhow::bail!("error getting sensor {target}")
+    };
+    match target_data_category {
+        DataCategory::Continuous => {
+            SupervisedPerformance::regression(references, predictions, probabilities)
+        }
+        DataCategory::Categorical | DataCategory::Discrete => {
+            SupervisedPerformance::classification(references, predictions, probabilities)
+        }
+    }
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use std::{ fs::File, sync::Arc };
+
+        use polars::prelude::*;
+
+        use test_log::test;
+
+        use witchnet_common::{
+            data::DataTypeValue, neuron::ActivationContext, performance::SupervisedPerformance
+        };
+
+        use super::super::{
+            prediction::{ self, MissingValues },
+            super::{ common::{ pointer::*, sensor::Sensor }, magds::MAGDS, parser }
+        };
+
+        #[test]
+        fn prediction_score() {
+            let train_file = "data/iris_original_train.csv";
+            let test_file = "data/iris_original_test.csv";
+
+            let magds_train = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+            let magds_test = parser::magds_from_csv("iris_test", test_file, &vec![]).unwrap();
+
+            let performance = prediction::prediction_score(
+                &magds_train, &magds_test, "variety".into()
+            ).unwrap();
+            let accuracy = performance.accuracy().unwrap();
+            let proba = performance.mean_probability().unwrap();
+            println!("accuracy: {accuracy} proba: {proba}");
+            assert!(accuracy > 0.90);
+            assert!(proba > 0.0);
+
+            let train_len = magds_train.neurons.len() as f32;
+            let performance = prediction::prediction_score_custom(
+                &magds_train,
+                &magds_test,
+                "variety".into(),
+                true,
+                true,
+                12,
+                f32::ln(train_len)
+            ).unwrap();
+            let accuracy = performance.accuracy().unwrap();
+            let proba = performance.mean_probability().unwrap();
+            println!("accuracy: {accuracy} proba: {proba}");
+            assert!(accuracy > 0.90);
+            assert!(proba > 0.0);
+        }
+
+        #[test]
+        fn prediction_score_df() {
+            let train_file = "data/iris_original_train.csv";
+            let test_file = "data/iris_original_test.csv";
+
+            let magds_train = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+            let test: DataFrame = CsvReader::new(File::open(test_file).unwrap())
+                .infer_schema(None)
+                .has_header(true)
+                .finish()
+                .unwrap();
+
+            let performance = prediction::prediction_score_df(
+                &magds_train, &test, "variety".into()
+            ).unwrap();
+            println!("performance.predictions() {:?}", performance.predictions());
+            println!("performance.references() {:?}", performance.references());
+            println!("performance.probabilities() {:?}", performance.probabilities());
+            let accuracy = performance.accuracy().unwrap();
+            let proba = performance.mean_probability().unwrap();
+            println!("accuracy: {accuracy} proba: {proba}");
+            assert!(accuracy > 0.90);
+            assert!(proba > 0.0);
+
+            let train_len = magds_train.neurons.len() as f32;
+            let performance = prediction::prediction_score_df_custom(
+                &magds_train,
+                &test,
+                "variety".into(),
+                true,
+                true,
+                12,
+                f32::ln(train_len)
+            ).unwrap();
+            let accuracy = performance.accuracy().unwrap();
+            let proba = performance.mean_probability().unwrap();
+            println!("accuracy: {accuracy} proba: {proba}");
+            assert!(accuracy > 0.80);
+            assert!(proba > 0.0);
+        }
+
+        #[test]
+        fn predict_weighted_empty() {
+            let train_file = "data/iris_original_train.csv";
+            let magds_train = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+            let train_len = magds_train.neurons.len() as f32;
+            let data_proba = prediction::predict_weighted(
+                &magds_train, 
+                &vec![], 
+                1u32, 
+                true,
+                12,
+                f32::ln(train_len)
+            );
+            assert!(data_proba.is_none());
+        }
+
+        #[test]
+        fn predict_explained() {
+            let train_file = "data/iris_original_train.csv";
+            let magds = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+            let weight_ratio = f32::ln(magds.neurons.len() as f32);
+            let variety_id = *magds.sensor_ids("variety").unwrap().first().unwrap();
+            let petal_length_id = *magds.sensor_ids("petal.length").unwrap().first().unwrap();
+            let petal_width_id = *magds.sensor_ids("petal.width").unwrap().first().unwrap();
+            let sepal_length_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let petal_width_len = magds.sensor(petal_width_id).unwrap().borrow().neurons().len();
+
+            let features = vec![
+                (petal_length_id, 5.7f64.into(), 1.0f32),
+                (petal_width_id, 2.15f64.into(), 1.0f32),
+                (sepal_length_id, 6.7f64.into(), 1.0f32)
+            ];
+
+            for target in [variety_id, petal_length_id] {
+                let features: Vec<(u32, DataTypeValue, f32)> = features.iter()
+                    .filter(|x| x.0 != target)
+                    .cloned()
+                    .collect();
+
+                let expected = prediction::predict_weighted(
+                    &magds, &features, target, true, 12, weight_ratio
+                ).unwrap();
+
+                let (predicted, explanation) = prediction::predict_weighted_explained(
+                    &magds, &features, target, true, 12, weight_ratio, MissingValues::Ignore
+                ).unwrap();
+
+                assert_eq!(predicted.0, expected.0);
+                assert!((predicted.1 - expected.1).abs() < 1e-4);
+
+                assert_eq!(explanation.winners.len(), 12);
+                for pair in explanation.winners.windows(2) {
+                    assert!(pair[0].activation >= pair[1].activation);
+                    assert!(pair[0].weight > pair[1].weight);
+                }
+                for winner in &explanation.winners {
+                    let signal: f32 = winner.contributions.iter().map(|x| x.signal).sum();
+                    assert!((signal - winner.activation).abs() < 1e-4);
+                    for contribution in &winner.contributions {
+                        assert_ne!(contribution.element.parent_id, target);
+                        assert!(contribution.element_activation > 0.0);
+                    }
+                }
+                assert!(explanation.winners.iter()
+                    .flat_map(|x| &x.contributions)
+                    .any(|x| !x.is_exact));
+
+                let shares: f32 = explanation.feature_shares.iter().map(|x| x.1).sum();
+                assert!((shares - 1.0).abs() < 1e-4);
+                assert!(explanation.feature_shares.iter().all(|x| x.0 != target));
+            }
+            assert_eq!(
+                magds.sensor(petal_width_id).unwrap().borrow().neurons().len(), petal_width_len
+            );
+        }
+
+        #[test]
+        fn predict_multi() {
+            let train_file = "data/iris_original_train.csv";
+            let test_file = "data/iris_original_test.csv";
+
+            let magds_train = parser::magds_from_csv("iris_train", train_file, &vec![]).unwrap();
+            let magds_test = parser::magds_from_csv("iris_test", test_file, &vec![]).unwrap();
+
+            let variety_id = *magds_train.sensor_ids("variety").unwrap().first().unwrap();
+            let petal_length_id = *magds_train.sensor_ids("petal.length").unwrap().first().unwrap();
+            let petal_width_id = *magds_train.sensor_ids("petal.width").unwrap().first().unwrap();
+            let sepal_length_id = *magds_train.sensor_ids("sepal.length").unwrap().first().unwrap();
+
+            let features = vec![(petal_width_id, 2.15f64.into()), (sepal_length_id, 6.7f64.into())];
+            let predictions = prediction::predict_multi(
+                &magds_train, &features, &[variety_id, petal_length_id]
+            );
+            assert_eq!(predictions.len(), 2);
+            for target in [variety_id, petal_length_id] {
+                let expected = prediction::predict(&magds_train, &features, target).unwrap();
+                assert_eq!(predictions[&target].0, expected.0);
+            }
+
+            let targets: Vec<Ptr<str>> = vec!["variety".into(), "petal.length".into()];
+            let performances = prediction::prediction_score_multi(
+                &magds_train, &magds_test, &targets
+            ).unwrap();
+            assert_eq!(performances.len(), 2);
+            match &performances[&targets[0]] {
+                SupervisedPerformance::Classification(_) => {}
+                _ => panic!("variety should be classified")
+            }
+            match &performances[&targets[1]] {
+                SupervisedPerformance::Regression(_) => {}
+                _ => panic!("petal.length should be regressed")
+            }
+            let accuracy = performances[&targets[0]].accuracy().unwrap();
+            println!("multi target variety accuracy: {accuracy}");
+            assert!(accuracy > 0.9);
+        }
+
+        #[test]
+        fn predict_missing() {
+            let file = "data/missing.csv";
+            let magds = parser::magds_from_csv_nullable("missing", file, &vec![]).unwrap();
+            let a_id = *magds.sensor_ids("a").unwrap().first().unwrap();
+            let b_id = *magds.sensor_ids("b").unwrap().first().unwrap();
+            let target_id = *magds.sensor_ids("target").unwrap().first().unwrap();
+            let group_id = *magds.neuron_group_ids_from_name("missing").unwrap().first().unwrap();
+            let activate = |magds: &MAGDS, features: &[(u32, DataTypeValue, f32)], missing| {
+                let mut context = ActivationContext::new();
+                prediction::activate_features_in(magds, features, true, missing, &mut context);
+                context
+            };
+            let activation = |magds: &MAGDS, context: &ActivationContext, id: u32| {
+                magds.neuron(id, group_id).unwrap().borrow().activation_in(context)
+            };
+
+            let features: Vec<(u32, DataTypeValue, f32)> = vec![
+                (a_id, 1.0_f64.into(), 1.0), (b_id, Arc::<str>::from("x").into(), 1.0)
+            ];
+            let context = activate(&magds, &features, MissingValues::Ignore);
+            assert_eq!(activation(&magds, &context, 5), 0.0);
+
+            let context = activate(&magds, &features, MissingValues::Penalize(0.5));
+            assert!(activation(&magds, &context, 5) < 0.0);
+            assert!(activation(&magds, &context, 3) < activation(&magds, &context, 1));
+
+            let features: Vec<(u32, DataTypeValue, f32)> = vec![
+                (a_id, DataTypeValue::Null, 1.0), (b_id, Arc::<str>::from("y").into(), 1.0)
+            ];
+            let context = activate(&magds, &features, MissingValues::Ignore);
+            assert_eq!(activation(&magds, &context, 3), 0.0);
+
+            let context = activate(&magds, &features, MissingValues::Penalize(0.5));
+            assert!(activation(&magds, &context, 3) > 0.0);
+            assert!(activation(&magds, &context, 5) > activation(&magds, &context, 2));
+
+            let context = activate(&magds, &features, MissingValues::Impute);
+            assert!(activation(&magds, &context, 3) > 0.0);
+            assert!(activation(&magds, &context, 2) > activation(&magds, &context, 5));
+            assert!(magds.neurons().iter().all(|x| x.borrow().activation() == 0.0));
+
+            let prediction = prediction::predict_weighted_missing(
+                &magds, &features, target_id, true, 1, 2.0, MissingValues::Impute
+            ).unwrap();
+            assert_eq!(prediction.0, Arc::<str>::from("q").into());
+        }
+    }
+}
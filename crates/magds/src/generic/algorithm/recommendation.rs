@@ -0,0 +1,235 @@
+use witchnet_common::{ data::DataTypeValue, neuron::ActivationContext };
+
+use super::{
+    prediction::{ MissingValues, activate_features_in },
+    super::{
+        common::{ pointer::*, neuron::Neuron },
+        magds::MAGDS
+    }
+};
+
+#[derive(Debug, Clone)]
+pub enum DataValueFilter {
+    Empty,
+    One(DataTypeValue),
+    Range((DataTypeValue, DataTypeValue)),
+    Many(Vec<DataTypeValue>)
+}
+
+pub fn recommend(
+    magds: &MAGDS, 
+    features: &[(u32, DataTypeValue)],
+    target: u32,
+    fuzzy: bool
+) -> Option<Vec<(DataTypeValue, f32)>> {
+    let features: Vec<(u32, DataTypeValue, f32)> = features.into_iter()
+        .map(|(id, value)| (*id, value.clone(), 1.0f32))
+        .collect();
+    recommend_weighted(magds, &features, &[], target, fuzzy)
+}
+
+pub fn recommend_filter(
+    magds: &MAGDS, 
+    features: &[(u32, DataTypeValue)],
+    filters: &[(u32, DataValueFilter)],
+    target: u32,
+    fuzzy: bool
+) -> Option<Vec<(DataTypeValue, f32)>> {
+    let features: Vec<(u32, DataTypeValue, f32)> = features.into_iter()
+        .map(|(id, value)| (*id, value.clone(), 1.0f32))
+        .collect();
+    recommend_weighted(magds, &features, filters, target, fuzzy)
+}
+
+pub fn recommend_weighted(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue, f32)>,
+    filters: &[(u32, DataValueFilter)],
+    target: u32,
+    fuzzy: bool
+) -> Option<Vec<(DataTypeValue, f32)>> {
+    recommend_weighted_missing(magds, features, filters, target, fuzzy, MissingValues::Ignore)
+}
+
+pub fn recommend_weighted_missing(
+    magds: &MAGDS, 
+    features: &Vec<(u32, DataTypeValue, f32)>,
+    filters: &[(u32, DataValueFilter)],
+    target: u32,
+    fuzzy: bool,
+    missing: MissingValues
+) -> Option<Vec<(DataTypeValue, f32)>> {
+    let mut context = ActivationContext::new();
+    let max_activation_sum = activate_features_in(magds, features, fuzzy, missing, &mut context);
+
+    let neurons = &magds.neurons;
+
+    let neurons_len = neurons.len();
+    if neurons_len == 0 { return None }
+
+    let mut values_sorted: Vec<(DataTypeValue, f32)> = neurons.into_iter()
+        .filter(|neuron| {
+            if filters.is_empty() { true } else { filter_neuron(neuron, filters) }
+        })
+        .map(|neuron| (
+            neuron.borrow().explain_one(target), 
+            if max_activation_sum == 0.0f32 {
+                0.0f32
+            } else {
+                neuron.borrow().activation_in(&context) / max_activation_sum
+            }
+        ))
+        .filter(|(target, _activation)| target.as_ref().map_or(false, |x| !x.is_null()))
+        .map(|(target, activation)| (target.unwrap(), activation))
+        .collect();
+    values_sorted.sort_unstable_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    log::debug!("values_sorted {:?}", values_sorted);
+
+    Some(values_sorted.into_iter().rev().collect())
+}
+
+/// Objects pass a filter if any of their values in the filtered sensor does,
+/// so multi-value sensors are filtered by the tokens they contain
+fn filter_neuron(
+    neuron: &Ptr<Lock<dyn Neuron>>, filters: &[(u32, DataValueFilter)]
+) -> bool {
+    let neuron = neuron.borrow();
+    for (filter_id, filter) in filters {
+        let neuron_values: Vec<DataTypeValue> = neuron.explain()
+            .iter()
+            .map(|x| x.borrow())
+            .filter(|x| x.id().parent_id == *filter_id)
+            .map(|x| x.value())
+            .collect();
+        if neuron_values.is_empty() { continue }
+        let is_ok = neuron_values.iter().any(|neuron_value| match filter {
+            DataValueFilter::Empty => true,
+            DataValueFilter::One(value) => neuron_value == value,
+            DataValueFilter::Range((min, max)) => neuron_value >= min && neuron_value <= max,
+            DataValueFilter::Many(values) => values.contains(neuron_value),
+        });
+        if !is_ok { return false }
+    }
+    true
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use std::sync::Arc;
+
+        use witchnet_common::{ data::DataTypeValue, tokenizer::Tokenizer };
+
+        use super::super::{
+            recommendation,
+            prediction,
+            super::parser
+        };
+
+        use super::*;
+
+        #[test]
+        fn recommend() {
+            let iris_file = "data/iris_original.csv";
+            let magds = parser::magds_from_csv("iris", iris_file, &vec![]).unwrap();
+            let variety_sensor_id = *magds.sensor_ids("variety").unwrap().first().unwrap();
+            let sepal_length_sensor_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let petal_length_sensor_id = *magds.sensor_ids("petal.length").unwrap().first().unwrap();
+            let petal_width_sensor_id = *magds.sensor_ids("petal.width").unwrap().first().unwrap();
+            let sepal_width_sensor_id = *magds.sensor_ids("sepal.width").unwrap().first().unwrap();
+
+            let features: Vec<(u32, DataTypeValue)> = vec![
+                (sepal_length_sensor_id, 5.8.into()),
+                (sepal_width_sensor_id, 3.8.into()),
+                (petal_length_sensor_id, 1.5.into()),
+                (petal_width_sensor_id, 2.0.into()),
+            ];
+
+            let recommendations = recommendation::recommend(
+                &magds, &features, variety_sensor_id, true
+            ).unwrap();
+
+            assert!(!recommendations.is_empty());
+            assert!(recommendations.first().unwrap().1 > 0f32);
+
+            println!("recommendations {:?}", recommendations);
+
+            let recommendations = recommendation::recommend(
+                &magds, &vec![], variety_sensor_id, true
+            ).unwrap();
+            assert!(!recommendations.is_empty());
+            assert!(recommendations.first().unwrap().1 == 0.0f32);
+            println!("empty recommendations {:?}", recommendations);
+
+            let setosa_filters = vec![
+                (variety_sensor_id, DataValueFilter::One(Arc::<str>::from("setosa").into()))
+            ];
+            let recommendations = recommendation::recommend_filter(
+                &magds, &features, &setosa_filters, variety_sensor_id, true
+            ).unwrap();
+            assert!(recommendations.len() == 50);
+            assert!(recommendations.first().unwrap().1 > 0f32);
+            println!("recommendations filtered by setosa {:?}", recommendations);
+
+            let empty_filters = vec![(variety_sensor_id, DataValueFilter::Empty)];
+            let recommendations = recommendation::recommend_filter(
+                &magds, &features, &empty_filters, variety_sensor_id, true
+            ).unwrap();
+            assert!(recommendations.len() == 150);
+            assert!(recommendations.first().unwrap().1 > 0f32);
+            println!("recommendations filtered by empty filter {:?}", recommendations);
+
+            let range_filters = vec![
+                (sepal_length_sensor_id, DataValueFilter::Range((5.0.into(), 10.0.into())))
+            ];
+            let recommendations = recommendation::recommend_filter(
+                &magds, &features, &range_filters, variety_sensor_id, true
+            ).unwrap();
+            assert!(recommendations.len() == 128);
+            assert!(recommendations.first().unwrap().1 > 0f32);
+            println!("recommendations filtered by range filter {:?}", recommendations);
+
+            let many_filters = vec![(
+                variety_sensor_id, DataValueFilter::Many(
+                    vec![Arc::<str>::from("setosa").into(), Arc::<str>::from("virginica").into()]
+                )
+            )];
+            let recommendations = recommendation::recommend_filter(
+                &magds, &features, &many_filters, variety_sensor_id, true
+            ).unwrap();
+            assert!(recommendations.len() == 100);
+            assert!(recommendations.first().unwrap().1 > 0f32);
+            println!("recommendations filtered by setosa and versicolor {:?}", recommendations);
+
+            let pred = prediction::predict(&magds, &features, variety_sensor_id);
+            println!("pred {:?}", pred);
+        }
+
+        #[test]
+        fn recommend_tokenized() {
+            let magds = parser::magds_from_csv_tokenized(
+                "cars", "data/equipment.csv", &vec![], &[("equipment", Tokenizer::list())]
+            ).unwrap();
+            let brand_id = *magds.sensor_ids("brand").unwrap().first().unwrap();
+            let equipment_id = *magds.sensor_ids("equipment").unwrap().first().unwrap();
+
+            let features = vec![(equipment_id, Arc::<str>::from("leather, sunroof").into())];
+            let recommendations = recommendation::recommend(
+                &magds, &features, brand_id, false
+            ).unwrap();
+            assert_eq!(recommendations[0], (Arc::<str>::from("audi").into(), 1.0));
+            assert_eq!(recommendations[1], (Arc::<str>::from("bmw").into(), 0.5));
+
+            let radio_filters = vec![
+                (equipment_id, DataValueFilter::One(Arc::<str>::from("radio").into()))
+            ];
+            let recommendations = recommendation::recommend_filter(
+                &magds, &features, &radio_filters, brand_id, false
+            ).unwrap();
+            let mut brands: Vec<String> = recommendations.iter().map(|x| x.0.to_string()).collect();
+            brands.sort();
+            assert_eq!(brands, vec!["fiat", "skoda"]);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use rand::{ SeedableRng, rngs::StdRng, seq::SliceRandom };
+
+use polars::prelude::DataFrame;
+
+use witchnet_common::performance::SupervisedPerformance;
+
+use super::{
+    evaluation::{ self, EvaluationConfig, CrossValidation },
+    super::common::{ pointer::*, collective::defining::DefiningWeightingStrategy }
+};
+
+/// Values tried for every prediction setting, the searched configurations
+/// are the cartesian product of them.
+#[derive(Clone)]
+pub struct SearchSpace {
+    pub winners_limit: Vec<usize>,
+    pub weight_ratio: Vec<Option<f32>>,
+    pub fuzzy: Vec<bool>,
+    pub weighted: Vec<bool>,
+    pub weighting_strategy: Vec<Ptr<dyn DefiningWeightingStrategy>>,
+    pub interelement_activation_threshold: Vec<f32>,
+    pub interelement_activation_exponent: Vec<i32>
+}
+
+impl Default for SearchSpace {
+    fn default() -> Self {
+        let config = EvaluationConfig::default();
+        SearchSpace {
+            winners_limit: vec![config.winners_limit],
+            weight_ratio: vec![config.weight_ratio],
+            fuzzy: vec![config.fuzzy],
+            weighted: vec![config.weighted],
+            weighting_strategy: vec![config.weighting_strategy],
+            interelement_activation_threshold: vec![config.interelement_activation_threshold],
+            interelement_activation_exponent: vec![config.interelement_activation_exponent]
+        }
+    }
+}
+
+impl SearchSpace {
+    pub fn len(&self) -> usize {
+        self.winners_limit.len()
+            * self.weight_ratio.len()
+            * self.fuzzy.len()
+            * self.weighted.len()
+            * self.weighting_strategy.len()
+            * self.interelement_activation_threshold.len()
+            * self.interelement_activation_exponent.len()
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    pub fn configs(&self, seed: u64, stratified: bool) -> Vec<EvaluationConfig> {
+        let mut configs = Vec::with_capacity(self.len());
+        for winners_limit in &self.winners_limit {
+            for weight_ratio in &self.weight_ratio {
+                for fuzzy in &self.fuzzy {
+                    for weighted in &self.weighted {
+                        for weighting_strategy in &self.weighting_strategy {
+                            for threshold in &self.interelement_activation_threshold {
+                                for exponent in &self.interelement_activation_exponent {
+                                    configs.push(EvaluationConfig {
+                                        seed,
+                                        stratified,
+                                        fuzzy: *fuzzy,
+                                        weighted: *weighted,
+                                        winners_limit: *winners_limit,
+                                        weight_ratio: *weight_ratio,
+                                        weighting_strategy: weighting_strategy.clone(),
+                                        interelement_activation_threshold: *threshold,
+                                        interelement_activation_exponent: *exponent
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        configs
+    }
+}
+
+/// Metric the configurations are ranked by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Objective {
+    Accuracy,
+    MeanProbability,
+    Rmse,
+    Nrmse,
+    Mae
+}
+
+impl Objective {
+    pub fn value(&self, performance: &SupervisedPerformance) -> Result<f64> {
+        match self {
+            Objective::Accuracy => performance.accuracy(),
+            Objective::MeanProbability => Ok(performance.mean_probability()? as f64),
+            Objective::Rmse => performance.rmse(),
+            Objective::Nrmse => performance.nrmse(),
+            Objective::Mae => performance.mae()
+        }
+    }
+
+    pub fn is_maximized(&self) -> bool {
+        matches!(self, Objective::Accuracy | Objective::MeanProbability)
+    }
+}
+
+pub struct SearchResult {
+    pub config: EvaluationConfig,
+    /// objective mean across folds
+    pub score: f64,
+    /// objective standard deviation across folds
+    pub std: f64,
+    pub cross_validation: CrossValidation
+}
+
+/// Cross-validates every configuration of the space, best first.
+pub fn grid_search(
+    df: &DataFrame,
+    target: &str,
+    k: usize,
+    space: &SearchSpace,
+    objective: Objective,
+    seed: u64,
+    stratified: bool
+) -> Result<Vec<SearchResult>> {
+    search(df, target, k, space.configs(seed, stratified), objective)
+}
+
+/// Cross-validates `iterations` configurations drawn from the space without repetition, best first.
+pub fn random_search(
+    df: &DataFrame,
+    target: &str,
+    k: usize,
+    space: &SearchSpace,
+    objective: Objective,
+    iterations: usize,
+    seed: u64,
+    stratified: bool
+) -> Result<Vec<SearchResult>> {
+    let mut configs = space.configs(seed, stratified);
+    configs.shuffle(&mut StdRng::seed_from_u64(seed));
+    configs.truncate(iterations);
+    search(df, target, k, configs, objective)
+}
+
+fn search(
+    df: &DataFrame,
+    target: &str,
+    k: usize,
+    configs: Vec<EvaluationConfig>,
+    objective: Objective
+) -> Result<Vec<SearchResult>> {
+    if configs.is_empty() { anyhow::bail!("search space is empty") }
+
+    let configs_len = configs.len();
+    let mut results = Vec::with_capacity(configs_len);
+    for (i, config) in configs.into_iter().enumerate() {
+        log::info!("search configuration {}/{configs_len}: {:?}", i + 1, config);
+        let cross_validation = evaluation::cross_validate(df, target, k, &config)?;
+        let (score, std) = cross_validation.summary(|x| objective.value(x))?;
+        results.push(SearchResult { config, score, std, cross_validation });
+    }
+
+    results.sort_by(|a, b| {
+        let ordering = a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal);
+        if objective.is_maximized() { ordering.reverse() } else { ordering }
+    });
+
+    Ok(results)
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use witchnet_common::{
+            polars as polars_common,
+            connection::collective::defining::{ ConstantOneWeight, OneOverOuts }
+        };
+
+        use super::{ SearchSpace, Objective, super::super::common::pointer::* };
+
+        #[test]
+        fn grid_search() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+            let space = SearchSpace {
+                winners_limit: vec![1, 12],
+                fuzzy: vec![false, true],
+                weighting_strategy: vec![Ptr::new(ConstantOneWeight), Ptr::new(OneOverOuts)],
+                ..Default::default()
+            };
+            assert_eq!(space.len(), 8);
+
+            let results = super::grid_search(
+                &df, "variety", 3, &space, Objective::Accuracy, 42, true
+            ).unwrap();
+            assert_eq!(results.len(), 8);
+            for pair in results.windows(2) { assert!(pair[0].score >= pair[1].score); }
+            for result in &results { assert_eq!(result.cross_validation.folds.len(), 3); }
+            println!("best configuration: {:?} {}", results[0].config, results[0].score);
+            assert!(results[0].score > 0.9);
+
+            let results = super::grid_search(
+                &df, "petal.length", 3, &space, Objective::Rmse, 42, false
+            ).unwrap();
+            for pair in results.windows(2) { assert!(pair[0].score <= pair[1].score); }
+        }
+
+        #[test]
+        fn random_search() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+            let space = SearchSpace {
+                winners_limit: vec![1, 5, 12, 24],
+                weight_ratio: vec![None, Some(1.0), Some(2.0)],
+                ..Default::default()
+            };
+
+            let results = super::random_search(
+                &df, "variety", 3, &space, Objective::Accuracy, 4, 7, true
+            ).unwrap();
+            assert_eq!(results.len(), 4);
+
+            let again = super::random_search(
+                &df, "variety", 3, &space, Objective::Accuracy, 4, 7, true
+            ).unwrap();
+            let drawn = |results: &[super::SearchResult]| {
+                let mut drawn: Vec<String> = results.iter()
+                    .map(|x| format!("{} {:?}", x.config.winners_limit, x.config.weight_ratio))
+                    .collect();
+                drawn.sort();
+                drawn
+            };
+            assert_eq!(drawn(&results), drawn(&again));
+
+            let empty = SearchSpace { fuzzy: vec![], ..Default::default() };
+            assert!(empty.is_empty());
+            assert!(super::grid_search(&df, "variety", 3, &empty, Objective::Accuracy, 7, true).is_err());
+        }
+    }
+}
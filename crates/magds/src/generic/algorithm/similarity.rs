@@ -0,0 +1,409 @@
+use std::{
+    cmp::Ordering,
+    collections::{ HashMap, HashSet }
+};
+
+use anyhow::{ Result, Context };
+
+use witchnet_common::{
+    data::DataTypeValue,
+    neuron::{ NeuronID, ActivationContext }
+};
+
+use super::super::common::pointer::*;
+
+use super::{
+    prediction::{ MissingValues, activate_features_in },
+    super::{
+        common::{ neuron::Neuron, sensor::Sensor },
+        magds::MAGDS
+    }
+};
+
+pub fn entropy<S: Sensor<DataTypeValue>>(sensor: &S) -> Result<f64> {
+    let mut entropy = 0.0;
+
+    let sensor_neurons: Vec<Ptr<Lock<dyn Neuron>>> = sensor.neurons();
+    let mut sensor_total_counter = 0f64;
+    for sensor_neuron in &sensor_neurons {
+        sensor_total_counter += sensor_neuron.borrow().counter() as f64;
+    }
+
+    for sensor_neuron in &sensor_neurons {
+        let px = sensor_neuron.borrow().counter() as f64 / sensor_total_counter;
+        entropy += px * f64::log2(px);
+    }
+    Ok(-1f64 * entropy)
+}
+
+pub fn mutual_information<S1: Sensor<DataTypeValue>, S2: Sensor<DataTypeValue>>(
+    s1: &S1, s2: &S2, normalized: bool
+) -> Result<f64> {
+    let mut mutual_information = 0.0;
+
+    let mut s1_total_counter = 0;
+    let s1_neurons = s1.neurons();
+    if s1_neurons.is_empty() { anyhow::bail!("s1 is empty") }
+    for sensor_neuron in s1_neurons {
+        s1_total_counter += sensor_neuron.borrow().counter();
+    }
+    
+    let mut s2_total_counter = 0;
+    let s2_neurons = s2.neurons();
+    if s2_neurons.is_empty() { anyhow::bail!("s2 is empty") }
+    for sensor_neuron in s2_neurons {
+        s2_total_counter += sensor_neuron.borrow().counter();
+    }
+    let total_counter = usize::max(s1_total_counter, s2_total_counter) as f64;
+
+    let mut s1_entropy = f64::NAN;
+    let mut s2_entropy = f64::NAN;
+    if normalized {
+        s1_entropy = entropy(s1)?;
+        s2_entropy = entropy(s2)?;
+    }
+
+    for s1_sensor_neuron in s1.neurons() {
+        let s1_sensor_neuron_borrowed = s1_sensor_neuron.borrow();
+        let s1_defining_neurons = s1_sensor_neuron_borrowed.defined_neurons();
+        
+        let s1_neurons_ids: HashSet<NeuronID> = HashSet::from_iter(
+            s1_defining_neurons.into_iter().map(|x| x.borrow().id())
+        );
+        
+        for s2_sensor_neuron in s2.neurons() {
+            let s2_sensor_neuron_borrowed = s2_sensor_neuron.borrow();
+            let s2_defining_neurons = s2_sensor_neuron_borrowed.defined_neurons();
+
+            let s2_neurons_ids: HashSet<NeuronID> = HashSet::from_iter(
+                s2_defining_neurons.into_iter().map(|x| x.borrow().id())
+            );
+            let coincidences = s1_neurons_ids.intersection(&s2_neurons_ids).count();
+            
+            if coincidences == 0 {
+                continue
+            } else {
+                let p_xy = coincidences as f64 / total_counter;
+                let p_x = s1_sensor_neuron.borrow().counter() as f64 / total_counter;
+                let p_y = s2_sensor_neuron.borrow().counter() as f64 / total_counter;
+                let mi = if normalized {
+                    2f64 * (p_xy * f64::log2(p_xy / (p_x * p_y))) / (s1_entropy * s2_entropy)
+                } else {
+                    p_xy * f64::log2(p_xy / (p_x * p_y))
+                };
+                mutual_information += mi;
+            }
+        }
+    }
+
+    for s1_sensor_neuron in s1.neurons() {
+        s1_sensor_neuron.borrow_mut().deactivate(false, true);
+    }
+    for s2_sensor_neuron in s2.neurons() {
+        s2_sensor_neuron.borrow_mut().deactivate(false, true);
+    }
+
+    Ok(mutual_information)
+}
+
+
+pub fn features_target_weights(magds: &MAGDS, target_id: u32) -> Result<HashMap<u32, f64>> {
+    let target_sensor = magds.sensor(target_id).context("error getting target sensor")?;
+    
+    let features_ids: Vec<u32> = magds.sensors.keys()
+        .map(|id| *id)
+        .filter(|id| *id != target_id)
+        .collect();
+
+    let mut ret = HashMap::new();
+    for id in features_ids {
+        let sensor = magds.sensor(id).context("error getting sensor id {id}")?;
+        let similarity = if sensor.borrow().data_category().is_sortable() {
+            1.0f64
+        } else {
+            mutual_information(
+                &*sensor.borrow(), &*target_sensor.borrow(), true
+            )?
+        };
+        ret.insert(id, similarity);
+    }
+
+    log::info!("features_target_weights for target {target_id} {:?}", ret);
+    Ok(ret)
+}
+
+/// Object similarity search input: an existing neuron or a partial record
+/// of `(sensor_id, value, weight)` features.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectQuery {
+    Neuron(NeuronID),
+    Features(Vec<(u32, DataTypeValue, f32)>)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearestOptions {
+    /// activate neighbouring sensor elements of sortable features
+    pub fuzzy: bool,
+    /// sensors not taken into account, e.g. the prediction target
+    pub skip_sensors: Vec<u32>,
+    /// restrict results to the given neuron group, for `ObjectQuery::Neuron`
+    /// the group of the query neuron is used by default
+    pub neuron_group: Option<u32>
+}
+
+impl Default for NearestOptions {
+    fn default() -> Self {
+        NearestOptions { fuzzy: true, skip_sensors: vec![], neuron_group: None }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearestObject {
+    pub id: NeuronID,
+    /// weighted mean of contributions in the range [0, 1]
+    pub similarity: f32,
+    /// `(sensor_id, contribution)` for each activated feature, a feature contributes
+    /// its weight for the exact match and less for its fuzzy neighbours
+    pub contributions: Vec<(u32, f32)>
+}
+
+pub fn nearest_objects(
+    magds: &MAGDS, query: &ObjectQuery, k: usize, options: &NearestOptions
+) -> Result<Vec<NearestObject>> {
+    let (features, query_id, neuron_group) = match query {
+        ObjectQuery::Neuron(id) => {
+            let neuron = magds.neuron_from_id(id).context(format!("neuron {id} doesn't exist"))?;
+            let features: Vec<(u32, DataTypeValue, f32)> = neuron.borrow()
+                .explain()
+                .iter()
+                .map(|x| { let x = x.borrow(); (x.id().parent_id, x.value(), 1.0f32) })
+                .collect();
+            (features, Some(id.clone()), Some(options.neuron_group.unwrap_or(id.parent_id)))
+        }
+        ObjectQuery::Features(features) => (features.clone(), None, options.neuron_group)
+    };
+
+    let mut contributions: HashMap<NeuronID, Vec<(u32, f32)>> = HashMap::new();
+    let mut weights_sum = 0.0f32;
+    let mut context = ActivationContext::new();
+    for (id, value, weight) in features {
+        if options.skip_sensors.contains(&id) || value.is_null() { continue }
+
+        context.clear();
+        activate_features_in(
+            magds, &[(id, value, 1.0f32)], options.fuzzy, MissingValues::Ignore, &mut context
+        );
+        let activations: Vec<(NeuronID, f32)> = context.neurons()
+            .map(|(neuron_id, activation)| (neuron_id.clone(), activation))
+            .filter(|(neuron_id, activation)| {
+                *activation > 0.0f32
+                    && neuron_group.map_or(true, |group| neuron_id.parent_id == group)
+            })
+            .collect();
+        let max_activation = activations.iter().fold(0.0f32, |acc, x| f32::max(acc, x.1));
+        if max_activation <= 0.0f32 { continue }
+
+        weights_sum += weight;
+        for (neuron_id, activation) in activations {
+            if Some(&neuron_id) == query_id.as_ref() { continue }
+            contributions.entry(neuron_id).or_default()
+                .push((id, weight * activation / max_activation));
+        }
+    }
+
+    let mut nearest: Vec<NearestObject> = contributions.into_iter()
+        .map(|(id, contributions)| {
+            let similarity = contributions.iter().map(|x| x.1).sum::<f32>() / weights_sum;
+            NearestObject { id, similarity, contributions }
+        })
+        .collect();
+    nearest.sort_by(|a, b| {
+        b.similarity.partial_cmp(&a.similarity).unwrap_or(Ordering::Equal)
+            .then_with(|| (a.id.parent_id, a.id.id).cmp(&(b.id.parent_id, b.id.id)))
+    });
+    nearest.truncate(k);
+
+    Ok(nearest)
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use witchnet_common::neuron::NeuronID;
+
+        use super::{
+            ObjectQuery,
+            NearestOptions,
+            super::super::parser
+        };
+
+        #[test]
+        fn features_target_weights() {
+            let magds = parser::magds_from_csv("iris", "data/iris.csv", &vec![]).unwrap();
+            let variety_sensor_id = *magds.sensor_ids("variety").unwrap().first().unwrap();
+            let weights = super::features_target_weights(&magds, variety_sensor_id).unwrap();
+            println!("{:?}", weights);
+            for (_id, weight) in weights.into_iter() { assert!(weight > 0f64); }
+        }
+
+        #[test]
+        fn entropy() {
+            let magds = parser::magds_from_csv("iris", "data/iris.csv", &vec![]).unwrap();
+
+            let variety_sensor_id = *magds.sensor_ids("variety").unwrap().first().unwrap();
+            let sepal_length_sensor_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let petal_length_sensor_id = *magds.sensor_ids("petal.length").unwrap().first().unwrap();
+
+            let variety_sensor = magds.sensor(variety_sensor_id).unwrap();
+            let sepal_length_sensor = magds.sensor(sepal_length_sensor_id).unwrap();
+            let petal_length_sensor = magds.sensor(petal_length_sensor_id).unwrap();
+
+            let variety_entropy = super::entropy(&*variety_sensor.borrow()).unwrap();
+            assert!(variety_entropy > 0f64);
+            println!("entropy variety_sensor {:?}", variety_entropy);
+
+            let sepal_length_entropy = super::entropy(&*sepal_length_sensor.borrow()).unwrap();
+            assert!(sepal_length_entropy > 0f64);
+            println!("entropy sepal_length_sensor {:?}", sepal_length_entropy);
+
+            let petal_length_entropy = super::entropy(&*petal_length_sensor.borrow()).unwrap();
+            assert!(petal_length_entropy > 0f64);
+            println!("entropy petal_length_sensor {:?}", petal_length_entropy);
+        }
+
+        #[test]
+        fn mutual_information() {
+            let magds = parser::magds_from_csv("iris", "data/iris.csv", &vec![]).unwrap();
+
+            let variety_sensor_id = *magds.sensor_ids("variety").unwrap().first().unwrap();
+            let sepal_length_sensor_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let petal_length_sensor_id = *magds.sensor_ids("petal.length").unwrap().first().unwrap();
+            let petal_width_sensor_id = *magds.sensor_ids("petal.width").unwrap().first().unwrap();
+            let sepal_width_sensor_id = *magds.sensor_ids("sepal.width").unwrap().first().unwrap();
+
+            let variety_sensor = magds.sensor(variety_sensor_id).unwrap();
+            let sepal_length_sensor = magds.sensor(sepal_length_sensor_id).unwrap();
+            let petal_length_sensor = magds.sensor(petal_length_sensor_id).unwrap();
+            let petal_width_sensor = magds.sensor(petal_width_sensor_id).unwrap();
+            let sepal_width_sensor = magds.sensor(sepal_width_sensor_id).unwrap();
+
+            // petal_width-petal_length
+            let mi = super::mutual_information(
+                &*petal_width_sensor.borrow(), &*petal_length_sensor.borrow(), false
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information petal_width-petal_length {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*petal_length_sensor.borrow(), &*petal_width_sensor.borrow(), false
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information petal_length-petal_width {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*petal_width_sensor.borrow(), &*petal_length_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information petal_width-petal_length weighted {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*petal_length_sensor.borrow(), &*petal_width_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information petal_length-petal_width weighted {:?}", mi);
+
+            // sepal_width-petal_length
+            let mi = super::mutual_information(
+                &*sepal_width_sensor.borrow(), &*petal_length_sensor.borrow(), false
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information sepal_width-petal_length {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*petal_length_sensor.borrow(), &*sepal_width_sensor.borrow(), false
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information petal_length-sepal_width {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*sepal_width_sensor.borrow(), &*petal_length_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information sepal_width-petal_length weighted {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*petal_length_sensor.borrow(), &*sepal_width_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information petal_length-sepal_width weighted {:?}", mi);
+
+            // variety
+            let mi = super::mutual_information(
+                &*variety_sensor.borrow(), &*petal_length_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information variety-petal_length {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*variety_sensor.borrow(), &*petal_width_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information variety-petal_width {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*variety_sensor.borrow(), &*sepal_length_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information variety-sepal_length {:?}", mi);
+
+            let mi = super::mutual_information(
+                &*variety_sensor.borrow(), &*sepal_width_sensor.borrow(), true
+            ).unwrap();
+            assert!(mi > 0f64);
+            println!("mutual_information variety-sepal_width {:?}", mi);
+        }
+
+        #[test]
+        fn nearest_objects() {
+            let magds = parser::magds_from_csv("iris", "data/iris.csv", &vec![]).unwrap();
+            let variety_id = *magds.sensor_ids("variety").unwrap().first().unwrap();
+            let petal_length_id = *magds.sensor_ids("petal.length").unwrap().first().unwrap();
+            let petal_width_id = *magds.sensor_ids("petal.width").unwrap().first().unwrap();
+
+            let query_neuron = magds.neurons().first().unwrap().clone();
+            let query_id = query_neuron.borrow().id();
+            let query_variety = query_neuron.borrow().explain_one(variety_id).unwrap();
+            let options = NearestOptions { skip_sensors: vec![variety_id], ..Default::default() };
+            let nearest = super::nearest_objects(
+                &magds, &ObjectQuery::Neuron(query_id.clone()), 5, &options
+            ).unwrap();
+            assert_eq!(nearest.len(), 5);
+            for object in &nearest {
+                assert_ne!(object.id, query_id);
+                assert!(object.similarity > 0.0 && object.similarity <= 1.0 + f32::EPSILON);
+                assert!(object.contributions.iter().all(|(id, _)| *id != variety_id));
+                let neuron = magds.neuron_from_id(&object.id).unwrap();
+                assert_eq!(neuron.borrow().explain_one(variety_id).unwrap(), query_variety);
+            }
+            for pair in nearest.windows(2) { assert!(pair[0].similarity >= pair[1].similarity); }
+            assert!(magds.neurons().iter().all(|x| x.borrow().activation() == 0.0));
+
+            let query = ObjectQuery::Features(vec![
+                (petal_length_id, 6.0f64.into(), 1.0), (petal_width_id, 2.2f64.into(), 1.0)
+            ]);
+            let nearest = super::nearest_objects(
+                &magds, &query, 3, &NearestOptions::default()
+            ).unwrap();
+            assert_eq!(nearest.len(), 3);
+            for object in &nearest {
+                assert_eq!(object.contributions.len(), 2);
+                let neuron = magds.neuron_from_id(&object.id).unwrap();
+                assert_eq!(neuron.borrow().explain_one(variety_id).unwrap().to_string(), "Virginica");
+            }
+
+            assert!(super::nearest_objects(
+                &magds, &ObjectQuery::Neuron(NeuronID::new(0, 0)), 3, &options
+            ).is_err());
+        }
+    }
+}
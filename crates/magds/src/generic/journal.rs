@@ -0,0 +1,491 @@
+use std::{
+    fs,
+    path::{ Path, PathBuf }
+};
+
+use anyhow::{ Result, Context };
+
+use witchnet_common::{
+    neuron::NeuronID,
+    connection::ConnectionKind,
+    data::{ DataType, DataTypeValue },
+    tokenizer::Tokenizer
+};
+
+use crate::{
+    snapshot::MAGDSSnapshot,
+    journal::{ self, Journal, JournalEntry }
+};
+
+use super::{
+    common::{ pointer::*, neuron::Neuron },
+    magds::MAGDS,
+    snapshot
+};
+
+/// MAGDS whose mutations are journaled on top of a snapshot, so they survive a crash.
+/// A mutation is durable once its method returns `Ok`, failed mutations aren't journaled.
+pub struct JournaledMAGDS {
+    magds: MAGDS,
+    snapshot_path: PathBuf,
+    journal_path: PathBuf,
+    journal: Journal,
+    compaction_threshold: usize
+}
+
+impl JournaledMAGDS {
+    /// Loads the snapshot and replays the journal on it, an empty magds is created
+    /// only if neither of them exists yet
+    pub fn open(snapshot_path: &str, journal_path: &str) -> Result<JournaledMAGDS> {
+        let snapshot_path = PathBuf::from(snapshot_path);
+        let journal_path = PathBuf::from(journal_path);
+        if !snapshot_path.is_file() {
+            if journal_path.exists() {
+                anyhow::bail!(
+                    "journal {} exists but its snapshot {} is missing",
+                    journal_path.display(), snapshot_path.display()
+                )
+            }
+            return JournaledMAGDS::create(MAGDS::new(), snapshot_path, journal_path)
+        }
+
+        let bytes = fs::read(&snapshot_path)
+            .context(format!("error reading {}", snapshot_path.display()))?;
+        let base = journal::checksum(&bytes);
+        let snapshot = MAGDSSnapshot::read(&mut bytes.as_slice())?;
+        let mut magds = snapshot::magds_from_snapshot(&snapshot)?;
+
+        if !journal_path.is_file() { Journal::write_empty(&journal_path, base)?; }
+        let (mut journal, journal_base, mut entries) = Journal::open(&journal_path)?;
+        if journal_base != base {
+            // compaction was interrupted after the new snapshot had been moved in place
+            let pending_path = journal::pending_path(&journal_path);
+            if !pending_path.is_file() || Journal::open(&pending_path)?.1 != base {
+                anyhow::bail!(
+                    "journal {} doesn't belong to snapshot {}",
+                    journal_path.display(), snapshot_path.display()
+                )
+            }
+            fs::rename(&pending_path, &journal_path)?;
+            (journal, _, entries) = Journal::open(&journal_path)?;
+        }
+
+        for (i, entry) in entries.iter().enumerate() {
+            apply(&mut magds, entry).context(format!("error replaying journal entry {i}"))?;
+        }
+        log::info!("replayed {} journal entries from {}", entries.len(), journal_path.display());
+
+        Ok(JournaledMAGDS { magds, snapshot_path, journal_path, journal, compaction_threshold: 0 })
+    }
+
+    /// Starts journaling `magds`, it's written out as the base snapshot first
+    pub fn create<P: AsRef<Path>>(
+        magds: MAGDS, snapshot_path: P, journal_path: P
+    ) -> Result<JournaledMAGDS> {
+        let snapshot_path = snapshot_path.as_ref().to_path_buf();
+        let journal_path = journal_path.as_ref().to_path_buf();
+        let base = write_snapshot(&magds, &snapshot_path)?;
+        Journal::write_empty(&journal_path, base)?;
+        let (journal, _base, _entries) = Journal::open(&journal_path)?;
+        Ok(JournaledMAGDS { magds, snapshot_path, journal_path, journal, compaction_threshold: 0 })
+    }
+
+    pub fn magds(&self) -> &MAGDS { &self.magds }
+
+    pub fn into_magds(self) -> MAGDS { self.magds }
+
+    /// Number of entries appended since the last compaction
+    pub fn journal_len(&self) -> usize { self.journal.len() }
+
+    /// Compacts automatically once the journal holds `entries` entries, 0 turns it off
+    pub fn set_compaction_threshold(&mut self, entries: usize) {
+        self.compaction_threshold = entries;
+    }
+
+    /// Folds the journal into a new snapshot and starts an empty journal on top of it
+    pub fn compact(&mut self) -> Result<()> {
+        let pending_snapshot = journal::pending_path(&self.snapshot_path);
+        let pending_journal = journal::pending_path(&self.journal_path);
+        let base = write_snapshot(&self.magds, &pending_snapshot)?;
+        Journal::write_empty(&pending_journal, base)?;
+        fs::rename(&pending_snapshot, &self.snapshot_path)?;
+        fs::rename(&pending_journal, &self.journal_path)?;
+        self.journal = Journal::open(&self.journal_path)?.0;
+        Ok(())
+    }
+
+    pub fn create_sensor(&mut self, name: &str, data_type: DataType) -> Result<u32> {
+        if data_type == DataType::Unknown {
+            anyhow::bail!("unknown data type sensor is not allowed")
+        }
+        let id = *self.magds.sensors.keys().max().unwrap_or(&0) + 1;
+        let entry = JournalEntry::CreateSensor { id, name: name.to_string(), data_type };
+        self.journaled(entry, |magds| Ok(magds.create_sensor(name, data_type).1))
+    }
+
+    pub fn create_tokenized_sensor(&mut self, name: &str, tokenizer: Tokenizer) -> Result<u32> {
+        let id = *self.magds.sensors.keys().max().unwrap_or(&0) + 1;
+        let entry = JournalEntry::CreateTokenizedSensor {
+            id, name: name.to_string(), tokenizer: tokenizer.clone()
+        };
+        self.journaled(entry, |magds| Ok(magds.create_tokenized_sensor(name, tokenizer).1))
+    }
+
+    pub fn sensor_insert(
+        &mut self, id: u32, item: &DataTypeValue
+    ) -> Result<Ptr<Lock<dyn Neuron>>> {
+        let entry = JournalEntry::SensorInsert { sensor_id: id, value: item.clone() };
+        self.journaled(entry, |magds| sensor_insert(magds, id, item))
+    }
+
+    pub fn add_neuron_group(&mut self, group_name: &str, group_id: Option<u32>) -> Result<u32> {
+        let id = group_id.unwrap_or_else(|| {
+            *self.magds.neuron_group_names.keys().max().unwrap_or(&0) + 1
+        });
+        let entry = JournalEntry::AddNeuronGroup { id, name: group_name.to_string() };
+        self.journaled(entry, |magds| Ok(magds.add_neuron_group(group_name, Some(id))))
+    }
+
+    pub fn add_neuron(&mut self, id: NeuronID) -> Result<Ptr<Lock<dyn Neuron>>> {
+        if self.magds.neuron_from_id(&id).is_some() {
+            anyhow::bail!("neuron {} already exists", id)
+        }
+        let entry = JournalEntry::AddNeuron { id: id.clone() };
+        self.journaled(entry, |magds| Ok(magds.create_neuron(id)))
+    }
+
+    /// Connects the element of sensor `sensor_id` storing `item` with the neuron `neuron_id`
+    pub fn connect_bilateral(
+        &mut self, sensor_id: u32, item: &DataTypeValue, neuron_id: &NeuronID
+    ) -> Result<()> {
+        let entry = JournalEntry::ConnectBilateral {
+            sensor_id, value: item.clone(), neuron_id: neuron_id.clone()
+        };
+        self.journaled(entry, |magds| connect_bilateral(magds, sensor_id, item, neuron_id))
+    }
+
+    pub fn insert_record(
+        &mut self, group_id: u32, features: &[(u32, DataTypeValue)]
+    ) -> Result<NeuronID> {
+        let entry = JournalEntry::InsertRecord { group_id, features: features.to_vec() };
+        self.journaled(entry, |magds| magds.insert_record(group_id, features))
+    }
+
+    pub fn update_record(&mut self, id: &NeuronID, changes: &[(u32, DataTypeValue)]) -> Result<()> {
+        let entry = JournalEntry::UpdateRecord { id: id.clone(), changes: changes.to_vec() };
+        self.journaled(entry, |magds| magds.update_record(id, changes))
+    }
+
+    pub fn remove_record(&mut self, id: &NeuronID) -> Result<()> {
+        let entry = JournalEntry::RemoveRecord { id: id.clone() };
+        self.journaled(entry, |magds| magds.remove_record(id))
+    }
+
+    pub fn connect_neurons(&mut self, parent: &NeuronID, child: &NeuronID) -> Result<()> {
+        let entry = JournalEntry::ConnectNeurons { parent: parent.clone(), child: child.clone() };
+        self.journaled(entry, |magds| magds.connect_neurons(parent, child))
+    }
+
+    pub fn add_relation(
+        &mut self,
+        group_id: u32,
+        sensor_id: u32,
+        referenced_group_id: u32,
+        referenced_sensor_id: u32
+    ) -> Result<usize> {
+        let entry = JournalEntry::AddRelation {
+            group_id, sensor_id, referenced_group_id, referenced_sensor_id
+        };
+        self.journaled(entry, |magds| {
+            magds.add_relation(group_id, sensor_id, referenced_group_id, referenced_sensor_id)
+        })
+    }
+
+    pub fn connect_lateral(
+        &mut self, from: &NeuronID, to: &NeuronID, kind: ConnectionKind, weight: f32
+    ) -> Result<()> {
+        let entry = JournalEntry::ConnectLateral { from: from.clone(), to: to.clone(), kind, weight };
+        self.journaled(entry, |magds| magds.connect_lateral(from, to, kind, weight))
+    }
+
+    pub fn connect_similar(&mut self, group_id: u32, min_similarity: f32) -> Result<usize> {
+        let entry = JournalEntry::ConnectSimilar { group_id, min_similarity };
+        self.journaled(entry, |magds| magds.connect_similar(group_id, min_similarity))
+    }
+
+    pub fn connect_competing(
+        &mut self, group_id: u32, target_id: u32, min_similarity: f32, weight: f32
+    ) -> Result<usize> {
+        let entry = JournalEntry::ConnectCompeting { group_id, target_id, min_similarity, weight };
+        self.journaled(entry, |magds| {
+            magds.connect_competing(group_id, target_id, min_similarity, weight)
+        })
+    }
+
+    /// Writes `entry` ahead of applying it with `mutation`, so the magds never holds
+    /// a state the journal doesn't, the entry is cut off again if the mutation fails
+    fn journaled<T>(
+        &mut self, entry: JournalEntry, mutation: impl FnOnce(&mut MAGDS) -> Result<T>
+    ) -> Result<T> {
+        let start = self.journal.append(&entry)?;
+        let value = match mutation(&mut self.magds) {
+            Ok(value) => value,
+            Err(error) => {
+                self.journal.remove_last(start)?;
+                return Err(error)
+            }
+        };
+        if self.compaction_threshold > 0 && self.journal.len() >= self.compaction_threshold {
+            self.compact()?;
+        }
+        Ok(value)
+    }
+}
+
+fn apply(magds: &mut MAGDS, entry: &JournalEntry) -> Result<()> {
+    match entry {
+        JournalEntry::CreateSensor { id, name, data_type } => {
+            let (_sensor, created_id) = magds.create_sensor(name, *data_type);
+            if created_id != *id {
+                anyhow::bail!("sensor {name} was created as {created_id} instead of {id}")
+            }
+        }
+        JournalEntry::SensorInsert { sensor_id, value } => {
+            sensor_insert(magds, *sensor_id, value)?;
+        }
+        JournalEntry::AddNeuronGroup { id, name } => { magds.add_neuron_group(name, Some(*id)); }
+        JournalEntry::AddNeuron { id } => { magds.create_neuron(id.clone()); }
+        JournalEntry::ConnectBilateral { sensor_id, value, neuron_id } => {
+            connect_bilateral(magds, *sensor_id, value, neuron_id)?;
+        }
+        JournalEntry::InsertRecord { group_id, features } => {
+            magds.insert_record(*group_id, features)?;
+        }
+        JournalEntry::UpdateRecord { id, changes } => magds.update_record(id, changes)?,
+        JournalEntry::RemoveRecord { id } => magds.remove_record(id)?,
+        JournalEntry::CreateTokenizedSensor { id, name, tokenizer } => {
+            let (_sensor, created_id) = magds.create_tokenized_sensor(name, tokenizer.clone());
+            if created_id != *id {
+                anyhow::bail!("sensor {name} was created as {created_id} instead of {id}")
+            }
+        }
+        JournalEntry::ConnectNeurons { parent, child } => magds.connect_neurons(parent, child)?,
+        JournalEntry::AddRelation {
+            group_id, sensor_id, referenced_group_id, referenced_sensor_id
+        } => {
+            magds.add_relation(*group_id, *sensor_id, *referenced_group_id, *referenced_sensor_id)?;
+        }
+        JournalEntry::ConnectLateral { from, to, kind, weight } => {
+            magds.connect_lateral(from, to, *kind, *weight)?
+        }
+        JournalEntry::ConnectSimilar { group_id, min_similarity } => {
+            magds.connect_similar(*group_id, *min_similarity)?;
+        }
+        JournalEntry::ConnectCompeting { group_id, target_id, min_similarity, weight } => {
+            magds.connect_competing(*group_id, *target_id, *min_similarity, *weight)?;
+        }
+    }
+    Ok(())
+}
+
+fn sensor_insert(
+    magds: &mut MAGDS, id: u32, item: &DataTypeValue
+) -> Result<Ptr<Lock<dyn Neuron>>> {
+    magds.check_record_values(&[(id, item.clone())])?;
+    magds.sensor_insert(id, item).ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", id))
+}
+
+fn connect_bilateral(
+    magds: &MAGDS, sensor_id: u32, item: &DataTypeValue, neuron_id: &NeuronID
+) -> Result<()> {
+    let element = magds.sensor_search(sensor_id, item)
+        .ok_or_else(|| anyhow::anyhow!("sensor {} has no element {}", sensor_id, item))?;
+    let neuron = magds.neuron_from_id(neuron_id)
+        .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", neuron_id))?;
+    let result = element.borrow_mut().connect_bilateral(neuron, false, ConnectionKind::Defining);
+    result
+}
+
+fn write_snapshot(magds: &MAGDS, path: &Path) -> Result<u32> {
+    let mut bytes: Vec<u8> = Vec::new();
+    snapshot::magds_to_snapshot(magds)?.write(&mut bytes)?;
+    journal::write_synced(path, &bytes)?;
+    Ok(journal::checksum(&bytes))
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use std::{ fs, sync::Arc };
+
+        use witchnet_common::{ data::{ DataType, DataTypeValue }, neuron::NeuronID };
+
+        use crate::journal::Journal;
+
+        use super::{
+            JournaledMAGDS,
+            super::{ magds::MAGDS, parser, snapshot }
+        };
+
+        /// fresh directory per test and process, so concurrent test runs keep their files apart
+        fn paths(name: &str) -> (String, String) {
+            let dir = std::env::temp_dir()
+                .join(format!("{}_{}", name.replace("::", "_"), std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            let snapshot_path = dir.join("magds.magds");
+            let journal_path = dir.join("magds.wal");
+            (snapshot_path.to_str().unwrap().to_string(), journal_path.to_str().unwrap().to_string())
+        }
+
+        fn assert_same(a: &MAGDS, b: &MAGDS) {
+            assert_eq!(
+                snapshot::magds_to_snapshot(a).unwrap(), snapshot::magds_to_snapshot(b).unwrap()
+            );
+        }
+
+        fn text(x: &str) -> DataTypeValue { Arc::<str>::from(x).into() }
+
+        #[test]
+        fn replay() {
+            let (snapshot_path, journal_path) = paths(&format!("magds_replay_{}", module_path!()));
+            let mut magds = JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap();
+
+            let brand_id = magds.create_sensor("brand", DataType::ArcStr).unwrap();
+            let price_id = magds.create_sensor("price", DataType::F64).unwrap();
+            let group_id = magds.add_neuron_group("cars", None).unwrap();
+            let neuron_id = NeuronID::new(1, group_id);
+            magds.add_neuron(neuron_id.clone()).unwrap();
+            magds.sensor_insert(brand_id, &text("audi")).unwrap();
+            magds.connect_bilateral(brand_id, &text("audi"), &neuron_id).unwrap();
+            assert!(magds.connect_bilateral(brand_id, &text("bmw"), &neuron_id).is_err());
+            assert!(magds.sensor_insert(price_id, &text("bmw")).is_err());
+            let id = magds.insert_record(
+                group_id, &[(brand_id, text("bmw")), (price_id, 10.0_f64.into())]
+            ).unwrap();
+            magds.insert_record(group_id, &[(brand_id, text("audi")), (price_id, 12.0_f64.into())])
+                .unwrap();
+            magds.update_record(&id, &[(price_id, 11.0_f64.into())]).unwrap();
+            magds.remove_record(&neuron_id).unwrap();
+            assert_eq!(magds.journal_len(), 10);
+
+            let live = magds.into_magds();
+            let replayed = JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap();
+            assert_eq!(replayed.journal_len(), 10);
+            assert_same(replayed.magds(), &live);
+            assert_eq!(replayed.magds().neurons().len(), 2);
+
+            // the journal must not be truncated by creating a new store over it
+            let journal = fs::read(&journal_path).unwrap();
+            fs::remove_file(&snapshot_path).unwrap();
+            assert!(JournaledMAGDS::open(&snapshot_path, &journal_path).is_err());
+            assert_eq!(fs::read(&journal_path).unwrap(), journal);
+            assert!(!std::path::Path::new(&snapshot_path).exists());
+            fs::remove_file(&journal_path).unwrap();
+        }
+
+        #[test]
+        fn torn_write() {
+            let (snapshot_path, journal_path) = paths(&format!("magds_torn_write_{}", module_path!()));
+            let base = parser::magds_from_csv_nullable("missing", "data/missing.csv", &vec![]).unwrap();
+            let a_id = *base.sensor_ids("a").unwrap().first().unwrap();
+            let b_id = *base.sensor_ids("b").unwrap().first().unwrap();
+            let group_id = *base.neuron_group_ids_from_name("missing").unwrap().first().unwrap();
+
+            let mut magds = JournaledMAGDS::create(base, &snapshot_path, &journal_path).unwrap();
+            magds.insert_record(group_id, &[(a_id, 7.0_f64.into()), (b_id, text("x"))]).unwrap();
+            let before_last = snapshot::magds_to_snapshot(magds.magds()).unwrap();
+            let journal_len = fs::metadata(&journal_path).unwrap().len();
+            magds.remove_record(&NeuronID::new(1, group_id)).unwrap();
+            drop(magds);
+
+            // a crash in the middle of the last append leaves a torn entry behind
+            let file = fs::OpenOptions::new().write(true).open(&journal_path).unwrap();
+            file.set_len(fs::metadata(&journal_path).unwrap().len() - 3).unwrap();
+            drop(file);
+
+            let mut magds = JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap();
+            assert_eq!(magds.journal_len(), 1);
+            assert_eq!(snapshot::magds_to_snapshot(magds.magds()).unwrap(), before_last);
+            assert_eq!(fs::metadata(&journal_path).unwrap().len(), journal_len);
+
+            magds.remove_record(&NeuronID::new(2, group_id)).unwrap();
+            let live = magds.into_magds();
+            let replayed = JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap();
+            assert_eq!(replayed.journal_len(), 2);
+            assert_same(replayed.magds(), &live);
+
+            for path in [&snapshot_path, &journal_path] { fs::remove_file(path).unwrap(); }
+        }
+
+        #[test]
+        fn compact() {
+            let (snapshot_path, journal_path) = paths(&format!("magds_compact_{}", module_path!()));
+            let base = parser::magds_from_csv_nullable("missing", "data/missing.csv", &vec![]).unwrap();
+            let a_id = *base.sensor_ids("a").unwrap().first().unwrap();
+            let group_id = *base.neuron_group_ids_from_name("missing").unwrap().first().unwrap();
+
+            let mut magds = JournaledMAGDS::create(base, &snapshot_path, &journal_path).unwrap();
+            magds.set_compaction_threshold(3);
+            for i in 0..4 {
+                magds.insert_record(group_id, &[(a_id, (10.0 + i as f64).into())]).unwrap();
+            }
+            assert_eq!(magds.journal_len(), 1);
+            let compacted_len = fs::metadata(&journal_path).unwrap().len();
+
+            magds.compact().unwrap();
+            assert_eq!(magds.journal_len(), 0);
+            assert!(fs::metadata(&journal_path).unwrap().len() < compacted_len);
+            let live = magds.into_magds();
+            assert_same(&MAGDS::load(&snapshot_path).unwrap(), &live);
+            assert_same(JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap().magds(), &live);
+
+            // the snapshot was moved in place but the fresh journal wasn't
+            let stale_journal = fs::read(&journal_path).unwrap();
+            let mut magds = JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap();
+            magds.insert_record(group_id, &[(a_id, 20.0_f64.into())]).unwrap();
+            magds.compact().unwrap();
+            let live = magds.into_magds();
+            let pending_journal = format!("{journal_path}.pending");
+            fs::rename(&journal_path, &pending_journal).unwrap();
+            fs::write(&journal_path, &stale_journal).unwrap();
+            assert_same(JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap().magds(), &live);
+            assert!(!std::path::Path::new(&pending_journal).exists());
+
+            fs::write(&journal_path, &stale_journal).unwrap();
+            assert!(JournaledMAGDS::open(&snapshot_path, &journal_path).is_err());
+
+            for path in [&snapshot_path, &journal_path] { fs::remove_file(path).unwrap(); }
+        }
+
+        #[test]
+        fn failed_append() {
+            let (snapshot_path, journal_path) = paths(&format!("magds_failed_append_{}", module_path!()));
+            let base = parser::magds_from_csv_nullable("missing", "data/missing.csv", &vec![]).unwrap();
+            let a_id = *base.sensor_ids("a").unwrap().first().unwrap();
+            let group_id = *base.neuron_group_ids_from_name("missing").unwrap().first().unwrap();
+
+            let mut magds = JournaledMAGDS::create(base, &snapshot_path, &journal_path).unwrap();
+            magds.insert_record(group_id, &[(a_id, 7.0_f64.into())]).unwrap();
+            let before = snapshot::magds_to_snapshot(magds.magds()).unwrap();
+            let journal = fs::read(&journal_path).unwrap();
+
+            magds.journal = Journal::open_read_only(std::path::Path::new(&journal_path)).unwrap();
+            assert!(magds.insert_record(group_id, &[(a_id, 8.0_f64.into())]).is_err());
+            assert!(magds.remove_record(&NeuronID::new(1, group_id)).is_err());
+            assert!(magds.create_sensor("c", DataType::F64).is_err());
+            assert_eq!(snapshot::magds_to_snapshot(magds.magds()).unwrap(), before);
+            assert_eq!(fs::read(&journal_path).unwrap(), journal);
+
+            // a mutation failing after its entry was written leaves no trace in the journal
+            let mut magds = JournaledMAGDS::open(&snapshot_path, &journal_path).unwrap();
+            assert!(magds.remove_record(&NeuronID::new(100, group_id)).is_err());
+            assert!(magds.connect_neurons(&NeuronID::new(1, group_id), &NeuronID::new(1, group_id))
+                .is_err());
+            assert_eq!(magds.journal_len(), 1);
+            assert_eq!(fs::read(&journal_path).unwrap(), journal);
+
+            for path in [&snapshot_path, &journal_path] { fs::remove_file(path).unwrap(); }
+        }
+    }
+}
@@ -0,0 +1,1449 @@
+use std::{
+    sync::Arc,
+    collections::{ HashMap, HashSet },
+    fmt::{ Display, Formatter, Result as FmtResult }
+};
+
+use anyhow::Result;
+
+use witchnet_common::{
+    neuron::{ NeuronID, ActivationContext },
+    data::{ DataType, DataTypeValue, DataCategory },
+    connection::{ ConnectionKind, collective::defining::ConstantOneWeight },
+    temporal::{ Date, DateTime, Duration },
+    tokenizer::Tokenizer
+};
+
+use crate::snapshot::MAGDSSnapshot;
+
+use super::{
+    common::{
+        pointer::*,
+        neuron::Neuron,
+        sensor::Sensor,
+        collective::defining::DefiningWeightingStrategy
+    },
+    neural::graph::ASAGraph,
+    neuron::{ simple_neuron::SimpleNeuron, null_element::NullElement },
+    sensor::SensorConatiner,
+    snapshot
+};
+
+/// Connection kinds spreading activation between object neurons of the same level
+pub(crate) const LATERAL_CONNECTIONS: [ConnectionKind; 3] = [
+    ConnectionKind::Similarity, ConnectionKind::Inhibitory, ConnectionKind::Sequential
+];
+
+#[derive(Debug, Clone)]
+pub struct MAGDS {
+    pub(crate) sensors: HashMap<u32, Ptr<Lock<SensorConatiner>>>,
+    pub(crate) sensor_names: HashMap<u32, Ptr<str>>,
+    pub(crate) sensor_ids: HashMap<Ptr<str>, Vec<u32>>,
+    pub(crate) neurons: Vec<Ptr<Lock<dyn Neuron>>>,
+    pub(crate) neuron_group_names: HashMap<u32, Ptr<str>>,
+    pub(crate) neuron_group_ids: HashMap<Ptr<str>, Vec<u32>>,
+    pub(crate) null_elements: HashMap<u32, Ptr<Lock<NullElement>>>,
+    pub(crate) tokenizers: HashMap<u32, Tokenizer>
+}
+
+impl MAGDS {
+    pub fn new() -> MAGDS {
+        MAGDS { 
+            sensors: HashMap::new(),
+            sensor_names: HashMap::new(),
+            sensor_ids: HashMap::new(),
+            neurons: Vec::new(),
+            neuron_group_names: HashMap::new(),
+            neuron_group_ids: HashMap::new(),
+            null_elements: HashMap::new(),
+            tokenizers: HashMap::new()
+        }
+    }
+    
+    pub fn new_ptr() -> Ptr<Lock<MAGDS>> {
+        Ptr::new(Lock::new(
+            MAGDS { 
+                sensors: HashMap::new(),
+                sensor_names: HashMap::new(),
+                sensor_ids: HashMap::new(),
+                neurons: Vec::new(),
+                neuron_group_names: HashMap::new(),
+                neuron_group_ids: HashMap::new(),
+                null_elements: HashMap::new(),
+                tokenizers: HashMap::new()
+            }
+        ))
+    }
+
+    pub fn sensors(&self) -> Vec<Ptr<Lock<SensorConatiner>>> { 
+        self.sensors.values().cloned().collect()
+    }
+
+    pub fn sensors_names(&self) -> Vec<Ptr<str>> { 
+        self.sensor_ids.keys().cloned().collect()
+    }
+
+    pub fn create_sensor(
+        &mut self, name: &str, data_type: DataType
+    ) -> (Ptr<Lock<SensorConatiner>>, u32) {
+        let name_rc: Ptr<str> = Ptr::from(name);
+        let new_id: u32 = *self.sensors.keys().max().unwrap_or(&0) + 1;
+
+        let sensor = match data_type {
+            DataType::Bool => SensorConatiner::Bool(ASAGraph::<bool>::new_box(new_id)),
+            DataType::U8 => SensorConatiner::U8(ASAGraph::<u8>::new_box(new_id)),
+            DataType::U16 => SensorConatiner::U16(ASAGraph::<u16>::new_box(new_id)),
+            DataType::U32 => SensorConatiner::U32(ASAGraph::<u32>::new_box(new_id)),
+            DataType::U64 => SensorConatiner::U64(ASAGraph::<u64>::new_box(new_id)),
+            DataType::U128 => SensorConatiner::U128(ASAGraph::<u128>::new_box(new_id)),
+            DataType::USize => SensorConatiner::USize(ASAGraph::<usize>::new_box(new_id)),
+            DataType::I8 => SensorConatiner::I8(ASAGraph::<i8>::new_box(new_id)),
+            DataType::I16 => SensorConatiner::I16(ASAGraph::<i16>::new_box(new_id)),
+            DataType::I32 => SensorConatiner::I32(ASAGraph::<i32>::new_box(new_id)),
+            DataType::I64 => SensorConatiner::I64(ASAGraph::<i64>::new_box(new_id)),
+            DataType::I128 => SensorConatiner::I128(ASAGraph::<i128>::new_box(new_id)),
+            DataType::ISize => SensorConatiner::ISize(ASAGraph::<isize>::new_box(new_id)),
+            DataType::F32 => SensorConatiner::F32(ASAGraph::<f32>::new_box(new_id)),
+            DataType::F64 => SensorConatiner::F64(ASAGraph::<f64>::new_box(new_id)),
+            DataType::ArcStr => SensorConatiner::ArcStr(ASAGraph::<Arc<str>>::new_box(new_id)),
+            DataType::String => SensorConatiner::String(ASAGraph::<String>::new_box(new_id)),
+            DataType::Date => SensorConatiner::Date(ASAGraph::<Date>::new_box(new_id)),
+            DataType::DateTime => SensorConatiner::DateTime(ASAGraph::<DateTime>::new_box(new_id)),
+            DataType::Duration => SensorConatiner::Duration(ASAGraph::<Duration>::new_box(new_id)),
+            DataType::Unknown => panic!("unknown data type sensor is not allowed")
+        };
+
+        let sensor_ptr = Ptr::new(Lock::new(sensor));
+        self.sensors.insert(new_id, sensor_ptr.clone());
+
+        self.sensor_names.insert(new_id, name_rc.clone());
+        match self.sensor_ids.get_mut(name) {
+            Some(v) => v.push(new_id),
+            None => { self.sensor_ids.insert(name_rc, vec![new_id]); }
+        }
+
+        (sensor_ptr, new_id)
+    }
+
+    /// Creates a text sensor whose values are split by `tokenizer`,
+    /// so one object is connected to an element for every token
+    pub fn create_tokenized_sensor(
+        &mut self, name: &str, tokenizer: Tokenizer
+    ) -> (Ptr<Lock<SensorConatiner>>, u32) {
+        let (sensor, id) = self.create_sensor(name, DataType::ArcStr);
+        self.tokenizers.insert(id, tokenizer);
+        (sensor, id)
+    }
+
+    pub fn add_sensor(
+        &mut self, name: &str, sensor: Ptr<Lock<SensorConatiner>>
+    ) -> (Ptr<Lock<SensorConatiner>>, u32) {
+        let new_id: u32 = *self.sensors.keys().max().unwrap_or(&0) + 1;
+        let name_rc: Ptr<str> = Ptr::from(name);
+
+        self.sensors.insert(new_id, sensor.clone());
+
+        self.sensor_names.insert(new_id, name_rc.clone());
+        match self.sensor_ids.get_mut(name) {
+            Some(v) => v.push(new_id),
+            None => { self.sensor_ids.insert(name_rc, vec![new_id]); }
+        }
+
+        (sensor, new_id)
+    }
+
+    pub fn sensor(&self, id: u32) -> Option<&Ptr<Lock<SensorConatiner>>> {
+        self.sensors.get(&id)
+    }
+
+    pub fn sensor_ids(&self, name: &str) -> Option<&[u32]> { 
+        match self.sensor_ids.get(name.into()) {
+            Some(id) => Some(id),
+            None => None
+        }
+    }
+
+    pub fn sensor_name(&self, id: u32) -> Option<&str> { 
+        match self.sensor_names.get(&id) {
+            Some(id) => Some(&id),
+            None => None
+        }
+    }
+
+    pub fn sensor_data_type(&self, id: u32) -> Option<DataType> { 
+        Some(self.sensors.get(&id)?.borrow().data_type())
+    }
+
+    pub fn sensor_data_category(&self, id: u32) -> Option<DataCategory> { 
+        Some(self.sensors.get(&id)?.borrow().data_category())
+    }
+
+    pub fn sensor_tokenizer(&self, id: u32) -> Option<&Tokenizer> { self.tokenizers.get(&id) }
+
+    /// Values `value` connects to in sensor `id`, the tokens of a text for tokenized sensors
+    pub fn sensor_tokens(&self, id: u32, value: &DataTypeValue) -> Vec<DataTypeValue> {
+        let (tokenizer, text) = match (self.tokenizers.get(&id), value) {
+            (Some(tokenizer), DataTypeValue::ArcStr(text)) => (tokenizer, text.as_ref()),
+            (Some(tokenizer), DataTypeValue::String(text)) => (tokenizer, text.as_str()),
+            _ => return vec![value.clone()]
+        };
+        let tokens: Vec<DataTypeValue> = tokenizer.tokenize(text)
+            .into_iter()
+            .map(|token| DataTypeValue::ArcStr(Arc::from(token)))
+            .collect();
+        if tokens.is_empty() { vec![DataTypeValue::Null] } else { tokens }
+    }
+
+    pub fn sensor_insert(
+        &mut self, id: u32, item: &DataTypeValue
+    ) -> Option<Ptr<Lock<dyn Neuron>>> {
+        if item.is_null() {
+            let null_element = self.sensor_insert_null(id, Ptr::new(ConstantOneWeight))?;
+            return Some(null_element as Ptr<Lock<dyn Neuron>>)
+        }
+        Some(self.sensors.get_mut(&id)?.borrow_mut().insert(item))
+    }
+    
+    pub fn sensor_search(
+        &self, id: u32, item: &DataTypeValue
+    ) -> Option<Ptr<Lock<dyn Neuron>>> { 
+        if item.is_null() { return self.sensor_null_element(id) }
+        self.sensors.get(&id)?.borrow().search(item) 
+    }
+
+    pub fn sensor_insert_null(
+        &mut self, id: u32, weighting_strategy: Ptr<dyn DefiningWeightingStrategy>
+    ) -> Option<Ptr<Lock<NullElement>>> {
+        let data_type = self.sensor_data_type(id)?;
+        match self.null_elements.get(&id) {
+            Some(null_element) => {
+                null_element.borrow_mut().counter += 1;
+                Some(null_element.clone())
+            }
+            None => {
+                let null_element = NullElement::new(id, data_type, weighting_strategy);
+                self.null_elements.insert(id, null_element.clone());
+                Some(null_element)
+            }
+        }
+    }
+
+    pub fn sensor_null_element(&self, id: u32) -> Option<Ptr<Lock<dyn Neuron>>> {
+        Some(self.null_elements.get(&id)?.clone() as Ptr<Lock<dyn Neuron>>)
+    }
+
+    pub fn sensor_activate(
+        &mut self, 
+        id: u32, 
+        item: &DataTypeValue,
+        signal: f32,
+        propagate_horizontal: bool, 
+        propagate_vertical: bool
+    ) -> Result<f32> {
+        if item.is_null() {
+            return match self.null_elements.get(&id) {
+                Some(null_element) => Ok(null_element.borrow_mut().activate(
+                    signal, propagate_horizontal, propagate_vertical
+                )),
+                None => anyhow::bail!("sensor {} doesn't have null element", id)
+            }
+        }
+        self.sensors
+            .get_mut(&id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", id))?
+            .borrow_mut()
+            .activate(item, signal, propagate_horizontal, propagate_vertical)
+    }
+
+    /// `sensor_activate` accumulating in `context`, the magds is only read
+    pub fn sensor_activate_in(
+        &self, 
+        id: u32, 
+        item: &DataTypeValue,
+        signal: f32,
+        propagate_horizontal: bool, 
+        propagate_vertical: bool,
+        context: &mut ActivationContext
+    ) -> Result<f32> {
+        if item.is_null() {
+            return match self.null_elements.get(&id) {
+                Some(null_element) => Ok(null_element.borrow().activate_in(
+                    signal, propagate_horizontal, propagate_vertical, context
+                )),
+                None => anyhow::bail!("sensor {} doesn't have null element", id)
+            }
+        }
+        self.sensors
+            .get(&id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", id))?
+            .borrow()
+            .activate_in(item, signal, propagate_horizontal, propagate_vertical, context)
+    }
+
+    pub fn sensor_deactivate(
+        &mut self, 
+        id: u32, 
+        item: &DataTypeValue,
+        propagate_horizontal: bool, 
+        propagate_vertical: bool
+    ) -> Result<()> {
+        if item.is_null() {
+            return match self.null_elements.get(&id) {
+                Some(null_element) => {
+                    null_element.borrow_mut().deactivate(propagate_horizontal, propagate_vertical);
+                    Ok(())
+                }
+                None => anyhow::bail!("sensor {} doesn't have null element", id)
+            }
+        }
+        self.sensors
+            .get_mut(&id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", id))?
+            .borrow_mut()
+            .deactivate(item, propagate_horizontal, propagate_vertical)
+    }
+
+    pub fn deactivate_whole_sensor(&mut self, id: u32) -> Result<()> {
+        self.sensors
+            .get_mut(&id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", id))?
+            .borrow_mut()
+            .deactivate_sensor();
+        if let Some(null_element) = self.null_elements.get(&id) {
+            null_element.borrow_mut().deactivate(false, false);
+        }
+        Ok(())
+    }
+
+    pub fn neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] { &self.neurons }
+    
+    pub fn create_neuron(
+        &mut self, id: NeuronID
+    ) -> Ptr<Lock<dyn Neuron>> {
+        let neuron = SimpleNeuron::new(id) as Ptr<Lock<dyn Neuron>>;
+        self.neurons.push(neuron.clone());
+        neuron
+    }
+    
+    pub fn add_neuron(
+        &mut self, neuron: Ptr<Lock<dyn Neuron>>
+    ) {
+        self.neurons.push(neuron.clone());
+    }
+
+    pub fn neuron_from_id(&self, id: &NeuronID) -> Option<Ptr<Lock<dyn Neuron>>> {
+        for neuron in &self.neurons {
+            if neuron.borrow().id() == *id { return Some(neuron.clone()) }
+        }
+        None
+    }
+
+    pub fn neuron_group_names(&self) -> Vec<Ptr<str>> { 
+        self.neuron_group_ids.keys().cloned().collect()
+    }
+    
+    pub fn neuron_group_ids(&self) -> Vec<u32> { 
+        self.neuron_group_ids.values().cloned().flatten().collect()
+    }
+
+    pub fn neuron(&self, id: u32, parent_id: u32) -> Option<Ptr<Lock<dyn Neuron>>> {
+        let neuron_id = NeuronID::new(id, parent_id);
+        for neuron in &self.neurons {
+            if neuron.borrow().id() == neuron_id { return Some(neuron.clone()) }
+        }
+        None
+    }
+
+    /// Adds an object neuron to neuron group `group_id` defined by `features`
+    pub fn insert_record(
+        &mut self, group_id: u32, features: &[(u32, DataTypeValue)]
+    ) -> Result<NeuronID> {
+        if !self.neuron_group_names.contains_key(&group_id) {
+            anyhow::bail!("neuron group {} doesn't exist", group_id)
+        }
+        self.check_record_values(features)?;
+
+        let id = self.neurons.iter()
+            .map(|x| x.borrow().id())
+            .filter(|x| x.parent_id == group_id)
+            .map(|x| x.id)
+            .max()
+            .unwrap_or(0) + 1;
+        let neuron_id = NeuronID::new(id, group_id);
+        let neuron = self.create_neuron(neuron_id.clone());
+        for (sensor_id, value) in features {
+            for token in self.sensor_tokens(*sensor_id, value) {
+                self.connect_record_value(&neuron, *sensor_id, &token)?;
+            }
+        }
+
+        Ok(neuron_id)
+    }
+
+    /// Replaces the values of the object neuron `id` for every sensor in `changes`
+    pub fn update_record(&mut self, id: &NeuronID, changes: &[(u32, DataTypeValue)]) -> Result<()> {
+        let neuron = self.neuron_from_id(id)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", id))?;
+        self.check_record_values(changes)?;
+
+        for (sensor_id, value) in changes {
+            let elements: Vec<Ptr<Lock<dyn Neuron>>> = neuron.borrow()
+                .explain()
+                .iter()
+                .filter(|x| x.borrow().id().parent_id == *sensor_id)
+                .cloned()
+                .collect();
+            let tokens = self.sensor_tokens(*sensor_id, value);
+            if elements.len() == tokens.len()
+                && elements.iter().all(|x| tokens.contains(&x.borrow().value())) { continue }
+
+            for element in elements { self.disconnect_record_element(&neuron, &element)?; }
+            for token in tokens { self.connect_record_value(&neuron, *sensor_id, &token)?; }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the object neuron `id`, sensor elements left without objects are removed too
+    pub fn remove_record(&mut self, id: &NeuronID) -> Result<()> {
+        let index = self.neurons.iter()
+            .position(|x| x.borrow().id() == *id)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", id))?;
+        let neuron = self.neurons.remove(index);
+
+        let elements = neuron.borrow().explain().to_vec();
+        for element in elements { self.disconnect_record_element(&neuron, &element)?; }
+
+        let defining: Vec<Ptr<Lock<dyn Neuron>>> = self.neurons.iter()
+            .filter(|x| x.borrow().defined_neurons().iter().any(|x| x.borrow().id() == *id))
+            .cloned()
+            .collect();
+        for parent in defining {
+            let mut parent = parent.borrow_mut();
+            parent.disconnect_bilateral(neuron.clone(), false, ConnectionKind::Defining)?;
+        }
+        let defined = neuron.borrow().defined_neurons().to_vec();
+        for child in defined {
+            neuron.borrow_mut().disconnect_bilateral(child, false, ConnectionKind::Defining)?;
+        }
+
+        for kind in LATERAL_CONNECTIONS {
+            for other in &self.neurons {
+                let is_connected = other.borrow()
+                    .weighted_connections(kind)
+                    .iter()
+                    .any(|(x, _weight)| Ptr::ptr_eq(x, &neuron));
+                if is_connected { other.borrow_mut().disconnect_from(neuron.clone(), false, kind)?; }
+            }
+            let connected = neuron.borrow().weighted_connections(kind);
+            for (other, _weight) in connected {
+                neuron.borrow_mut().disconnect_from(other, false, kind)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Makes the object neuron `parent` define `child`, so activation flows from it to `child`
+    pub fn connect_neurons(&mut self, parent: &NeuronID, child: &NeuronID) -> Result<()> {
+        let parent_neuron = self.neuron_from_id(parent)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", parent))?;
+        let child_neuron = self.neuron_from_id(child)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", child))?;
+        connect_neurons(&parent_neuron, &child_neuron)
+    }
+
+    pub fn disconnect_neurons(&mut self, parent: &NeuronID, child: &NeuronID) -> Result<()> {
+        let parent_neuron = self.neuron_from_id(parent)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", parent))?;
+        let child_neuron = self.neuron_from_id(child)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", child))?;
+        let result = parent_neuron.borrow_mut().disconnect_bilateral(
+            child_neuron, false, ConnectionKind::Defining
+        );
+        result
+    }
+
+    /// Connects the object neurons `from` and `to` with a weighted similarity or inhibitory
+    /// connection in both directions, or with a one-way sequential connection.
+    /// Lateral connections can't lead to a neuron defining their source, as it is still
+    /// being activated when the lateral signal arrives
+    pub fn connect_lateral(
+        &mut self, from: &NeuronID, to: &NeuronID, kind: ConnectionKind, weight: f32
+    ) -> Result<()> {
+        let from_neuron = self.neuron_from_id(from)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", from))?;
+        let to_neuron = self.neuron_from_id(to)
+            .ok_or_else(|| anyhow::anyhow!("neuron {} doesn't exist", to))?;
+        let mut from_neuron = from_neuron.borrow_mut();
+        match kind {
+            ConnectionKind::Sequential => {
+                from_neuron.connect_to_weighted(to_neuron, false, kind, weight)
+            }
+            _ => from_neuron.connect_bilateral_weighted(to_neuron, false, kind, weight)
+        }
+    }
+
+    /// Connects objects of group `group_id` whose share of common sensor elements (jaccard index)
+    /// is at least `min_similarity` with similarity connections weighted by that share,
+    /// returns the number of connected pairs
+    pub fn connect_similar(&mut self, group_id: u32, min_similarity: f32) -> Result<usize> {
+        if !self.neuron_group_names.contains_key(&group_id) {
+            anyhow::bail!("neuron group {} doesn't exist", group_id)
+        }
+        let (neurons, overlaps) = self.overlaps(group_id, None);
+        let mut pairs = 0;
+        for (i, j, similarity) in overlaps {
+            if similarity < min_similarity { continue }
+            neurons[i].borrow_mut().connect_bilateral_weighted(
+                neurons[j].clone(), false, ConnectionKind::Similarity, similarity
+            )?;
+            pairs += 1;
+        }
+        Ok(pairs)
+    }
+
+    /// Connects similar objects of group `group_id` that disagree on the `target_id` value with
+    /// inhibitory connections of `weight`, so they suppress each other as competing winners.
+    /// The similarity is computed without the target, returns the number of connected pairs
+    pub fn connect_competing(
+        &mut self, group_id: u32, target_id: u32, min_similarity: f32, weight: f32
+    ) -> Result<usize> {
+        if !self.neuron_group_names.contains_key(&group_id) {
+            anyhow::bail!("neuron group {} doesn't exist", group_id)
+        }
+        if !self.sensors.contains_key(&target_id) {
+            anyhow::bail!("sensor {} doesn't exists", target_id)
+        }
+        let (neurons, overlaps) = self.overlaps(group_id, Some(target_id));
+        let targets: Vec<Option<DataTypeValue>> = neurons.iter()
+            .map(|x| x.borrow().explain_one(target_id).filter(|x| !x.is_null()))
+            .collect();
+        let mut pairs = 0;
+        for (i, j, similarity) in overlaps {
+            let is_competing = match (&targets[i], &targets[j]) {
+                (Some(target_i), Some(target_j)) => target_i != target_j,
+                _ => false
+            };
+            if !is_competing || similarity < min_similarity { continue }
+            neurons[i].borrow_mut().connect_bilateral_weighted(
+                neurons[j].clone(), false, ConnectionKind::Inhibitory, weight
+            )?;
+            pairs += 1;
+        }
+        Ok(pairs)
+    }
+
+    /// Objects of group `group_id` and the jaccard index of sensor elements of every pair of them
+    /// sharing any element, elements of `skip_sensor` and null elements are left out
+    fn overlaps(
+        &self, group_id: u32, skip_sensor: Option<u32>
+    ) -> (Vec<Ptr<Lock<dyn Neuron>>>, Vec<(usize, usize, f32)>) {
+        let neurons: Vec<Ptr<Lock<dyn Neuron>>> = self.neurons.iter()
+            .filter(|x| x.borrow().id().parent_id == group_id)
+            .cloned()
+            .collect();
+        let indices: HashMap<NeuronID, usize> = neurons.iter()
+            .enumerate()
+            .map(|(i, x)| (x.borrow().id(), i))
+            .collect();
+        let elements: Vec<Vec<Ptr<Lock<dyn Neuron>>>> = neurons.iter()
+            .map(|neuron| neuron.borrow()
+                .explain()
+                .iter()
+                .filter(|x| {
+                    let x = x.borrow();
+                    Some(x.id().parent_id) != skip_sensor && !x.value().is_null()
+                })
+                .cloned()
+                .collect()
+            )
+            .collect();
+
+        let mut overlaps = Vec::new();
+        for (i, neuron_elements) in elements.iter().enumerate() {
+            let mut shared: HashMap<usize, usize> = HashMap::new();
+            for element in neuron_elements {
+                for other in element.borrow().defined_neurons() {
+                    match indices.get(&other.borrow().id()) {
+                        Some(j) if *j > i => *shared.entry(*j).or_default() += 1,
+                        _ => {}
+                    }
+                }
+            }
+            let mut shared: Vec<(usize, usize)> = shared.into_iter().collect();
+            shared.sort_unstable();
+            for (j, shared) in shared {
+                let union = neuron_elements.len() + elements[j].len() - shared;
+                overlaps.push((i, j, shared as f32 / union as f32));
+            }
+        }
+        (neurons, overlaps)
+    }
+
+    /// Links every object of group `group_id` with the objects of `referenced_group_id`
+    /// whose `referenced_sensor_id` value equals its `sensor_id` value, like a foreign key.
+    /// Referenced objects define the referencing ones, returns the number of new links
+    pub fn add_relation(
+        &mut self,
+        group_id: u32,
+        sensor_id: u32,
+        referenced_group_id: u32,
+        referenced_sensor_id: u32
+    ) -> Result<usize> {
+        for id in [group_id, referenced_group_id] {
+            if !self.neuron_group_names.contains_key(&id) {
+                anyhow::bail!("neuron group {} doesn't exist", id)
+            }
+        }
+        let data_type = self.sensor_data_type(sensor_id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", sensor_id))?;
+        let referenced_data_type = self.sensor_data_type(referenced_sensor_id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", referenced_sensor_id))?;
+        if data_type != referenced_data_type {
+            anyhow::bail!(
+                "{:?} sensor {} can't reference {:?} sensor {}",
+                data_type, sensor_id, referenced_data_type, referenced_sensor_id
+            )
+        }
+
+        let neurons: Vec<Ptr<Lock<dyn Neuron>>> = self.neurons.iter()
+            .filter(|x| x.borrow().id().parent_id == group_id)
+            .cloned()
+            .collect();
+        let mut links = 0;
+        for neuron in neurons {
+            let keys: Vec<DataTypeValue> = neuron.borrow()
+                .explain()
+                .iter()
+                .map(|x| x.borrow())
+                .filter(|x| x.id().parent_id == sensor_id && !x.value().is_null())
+                .map(|x| x.value())
+                .collect();
+            for key in keys {
+                let element = match self.sensor_search(referenced_sensor_id, &key) {
+                    Some(element) => element,
+                    None => {
+                        log::warn!("no object referenced by {} with {key}", neuron.borrow());
+                        continue
+                    }
+                };
+                let referenced: Vec<Ptr<Lock<dyn Neuron>>> = element.borrow()
+                    .defined_neurons()
+                    .iter()
+                    .filter(|x| x.borrow().id().parent_id == referenced_group_id)
+                    .cloned()
+                    .collect();
+                let neuron_id = neuron.borrow().id();
+                for parent in referenced {
+                    let is_linked = parent.borrow()
+                        .defined_neurons()
+                        .iter()
+                        .any(|x| x.borrow().id() == neuron_id);
+                    if is_linked { continue }
+                    connect_neurons(&parent, &neuron)?;
+                    links += 1;
+                }
+            }
+        }
+
+        Ok(links)
+    }
+
+    /// Adds `{name}.weekday`, `{name}.month` and for datetimes `{name}.hour` sensors derived from
+    /// the temporal sensor `id` and connected to the same objects, returns the new sensors ids
+    pub fn add_derived_temporal_sensors(&mut self, id: u32) -> Result<Vec<u32>> {
+        let sensor = self.sensor(id)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", id))?
+            .clone();
+        let data_type = sensor.borrow().data_type();
+        let parts: &[&str] = match data_type {
+            DataType::Date => &["weekday", "month"],
+            DataType::DateTime => &["weekday", "month", "hour"],
+            _ => anyhow::bail!("{:?} sensor {} has no derived sensors", data_type, id)
+        };
+        let name = self.sensor_name(id).unwrap_or_default().to_string();
+        let sensor_ids: Vec<u32> = parts.iter()
+            .map(|part| self.create_sensor(&format!("{name}.{part}"), DataType::U8).1)
+            .collect();
+
+        let values = sensor.borrow().values();
+        for value in values {
+            let element = match sensor.borrow().search(&value) {
+                Some(element) => element,
+                None => continue
+            };
+            let neurons = element.borrow().defined_neurons().to_vec();
+            for (sensor_id, part) in sensor_ids.iter().zip(temporal_parts(&value)) {
+                for neuron in &neurons { self.connect_record_value(neuron, *sensor_id, &part)?; }
+            }
+        }
+        if let Some(null_element) = self.sensor_null_element(id) {
+            let neurons = null_element.borrow().defined_neurons().to_vec();
+            for sensor_id in &sensor_ids {
+                for neuron in &neurons {
+                    self.connect_record_value(neuron, *sensor_id, &DataTypeValue::Null)?;
+                }
+            }
+        }
+
+        Ok(sensor_ids)
+    }
+
+    pub(crate) fn check_record_values(&self, features: &[(u32, DataTypeValue)]) -> Result<()> {
+        for (sensor_id, value) in features {
+            let data_type = self.sensor_data_type(*sensor_id)
+                .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", sensor_id))?;
+            if !value.is_null() && DataType::from(value) != data_type {
+                anyhow::bail!("value {} doesn't match {:?} sensor {}", value, data_type, sensor_id)
+            }
+        }
+        Ok(())
+    }
+
+    fn connect_record_value(
+        &mut self, neuron: &Ptr<Lock<dyn Neuron>>, sensor_id: u32, value: &DataTypeValue
+    ) -> Result<()> {
+        let element = self.sensor_insert(sensor_id, value)
+            .ok_or_else(|| anyhow::anyhow!("sensor {} doesn't exists", sensor_id))?;
+        let result = element.borrow_mut().connect_bilateral(
+            neuron.clone(), false, ConnectionKind::Defining
+        );
+        result
+    }
+
+    fn disconnect_record_element(
+        &mut self, neuron: &Ptr<Lock<dyn Neuron>>, element: &Ptr<Lock<dyn Neuron>>
+    ) -> Result<()> {
+        element.borrow_mut().disconnect_bilateral(neuron.clone(), false, ConnectionKind::Defining)?;
+
+        let (element_id, value) = {
+            let element = element.borrow();
+            (element.id(), element.value())
+        };
+        // elements shared with other records stay, only their counter goes down
+        if element.borrow().counter() > 1 {
+            element.borrow_mut().decrement_counter();
+            return Ok(())
+        }
+        if value.is_null() {
+            self.null_elements.remove(&element_id.parent_id);
+        } else if let Some(sensor) = self.sensors.get(&element_id.parent_id) {
+            sensor.borrow_mut().remove(&value);
+        }
+
+        Ok(())
+    }
+
+    pub fn deactivate(&mut self) {
+        for sensor in &mut self.sensors.values() { sensor.borrow_mut().deactivate_sensor(); }
+        for neuron in &mut self.neurons { neuron.borrow_mut().deactivate(false, false); }
+        for null_element in self.null_elements.values() {
+            null_element.borrow_mut().deactivate(false, false);
+        }
+    }
+
+    pub fn add_neuron_group(&mut self, group_name: &str, group_id: Option<u32>) -> u32 {
+        let new_id: u32 = if let Some(id) = group_id { id } else {
+            *self.neuron_group_names.keys().max().unwrap_or(&0) + 1
+        };
+        let group_name_rc: Ptr<str> = group_name.into();
+        self.neuron_group_names.insert(new_id, group_name_rc.clone());
+        match self.neuron_group_ids.get_mut(&group_name_rc) {
+            Some(v) => v.push(new_id),
+            None => { self.neuron_group_ids.insert(group_name_rc, vec![new_id]); }
+        }
+        new_id
+    }
+
+    pub fn neuron_group_ids_from_name(&self, name: &str) -> Option<&[u32]> { 
+        match self.neuron_group_ids.get(name.into()) {
+            Some(id) => Some(id),
+            None => None
+        }
+    }
+
+    pub fn neuron_group_name_from_id(&self, id: u32) -> Option<&str> { 
+        match self.neuron_group_names.get(&id) {
+            Some(id) => Some(&id),
+            None => None
+        }
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<()> {
+        snapshot::magds_to_snapshot(self)?.save(file_path)
+    }
+
+    pub fn load(file_path: &str) -> Result<MAGDS> {
+        snapshot::magds_from_snapshot(&MAGDSSnapshot::load(file_path)?)
+    }
+}
+
+impl Display for MAGDS {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "========== sensors ==========")?;
+        let mut number = 1;
+        for (id, sensor) in &self.sensors {
+            let name = self.sensor_name(*id).unwrap();
+            writeln!(f, "{number}: {name}({id})")?;
+            writeln!(f, "{}", sensor.borrow())?;
+            if let Some(null_element) = self.null_elements.get(id) {
+                writeln!(f, "{}", null_element.borrow())?;
+            }
+            number += 1;
+        }
+
+        let mut number = 1;
+        writeln!(f, "========== neurons ==========")?;
+        for neuron in &self.neurons {
+            let neuron_id = neuron.borrow().id().parent_id;
+            let parent_name = self.neuron_group_name_from_id(neuron_id).unwrap();
+            writeln!(f, "{number}: {parent_name}({neuron_id})")?;
+            writeln!(f, "{}", neuron.borrow())?;
+            number += 1;
+        }
+
+        writeln!(f, "========== ======= ==========")?;
+        Ok(())
+    }
+}
+
+/// Weekday, month and hour of a temporal value, in the order of `add_derived_temporal_sensors`
+fn temporal_parts(value: &DataTypeValue) -> Vec<DataTypeValue> {
+    let parts = match value {
+        DataTypeValue::Date(v) => vec![Some(v.weekday()), v.month()],
+        DataTypeValue::DateTime(v) => vec![Some(v.weekday()), v.month(), Some(v.hour())],
+        _ => vec![]
+    };
+    parts.into_iter().map(|x| x.map_or(DataTypeValue::Null, DataTypeValue::U8)).collect()
+}
+
+fn connect_neurons(parent: &Ptr<Lock<dyn Neuron>>, child: &Ptr<Lock<dyn Neuron>>) -> Result<()> {
+    let (parent_id, child_id) = (parent.borrow().id(), child.borrow().id());
+    if parent_id == child_id || defines(child, &parent_id) {
+        anyhow::bail!("connecting {} to {} would create a cycle", parent_id, child_id)
+    }
+    if signals_back(parent, child) {
+        anyhow::bail!(
+            "connecting {} to {} would let a lateral connection reach a defining neuron",
+            parent_id, child_id
+        )
+    }
+    let result = parent.borrow_mut().connect_bilateral(
+        child.clone(), false, ConnectionKind::Defining
+    );
+    result
+}
+
+/// Whether activation of `neuron` reaches the neuron `id` through defined neurons
+fn defines(neuron: &Ptr<Lock<dyn Neuron>>, id: &NeuronID) -> bool {
+    let mut visited: HashSet<NeuronID> = HashSet::new();
+    let mut stack = neuron.borrow().defined_neurons().to_vec();
+    while let Some(neuron) = stack.pop() {
+        let neuron = neuron.borrow();
+        let neuron_id = neuron.id();
+        if neuron_id == *id { return true }
+        if visited.insert(neuron_id) { stack.extend(neuron.defined_neurons().iter().cloned()); }
+    }
+    false
+}
+
+/// Whether `child` or a neuron it defines is laterally connected to `parent`
+/// or to a neuron defining it
+fn signals_back(parent: &Ptr<Lock<dyn Neuron>>, child: &Ptr<Lock<dyn Neuron>>) -> bool {
+    let parent_id = parent.borrow().id();
+    let mut visited: HashSet<NeuronID> = HashSet::new();
+    let mut stack = vec![child.clone()];
+    while let Some(neuron) = stack.pop() {
+        let neuron = neuron.borrow();
+        if !visited.insert(neuron.id()) { continue }
+        for kind in LATERAL_CONNECTIONS {
+            for (lateral, _weight) in neuron.weighted_connections(kind) {
+                if lateral.borrow().id() == parent_id || defines(&lateral, &parent_id) { return true }
+            }
+        }
+        stack.extend(neuron.defined_neurons().iter().filter(|x| !x.borrow().is_sensor()).cloned());
+    }
+    false
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use std::sync::Arc;
+
+        use witchnet_common::{
+            neuron::{ NeuronID, ActivationContext },
+            data::{ DataType, DataTypeValue },
+            polars as polars_common,
+            connection::{ ConnectionKind, collective::defining::{ ConstantOneWeight, OneOverOuts } },
+            temporal::{ Date, DateTime },
+            tokenizer::Tokenizer
+        };
+
+        use super::{
+            MAGDS,
+            super::{
+                common::{ pointer::*, sensor::Sensor },
+                neural::graph::ASAGraph,
+                neuron::simple_neuron::SimpleNeuron,
+                algorithm::prediction,
+                parser
+            }
+        };
+
+        #[test]
+        fn create_magds() {
+            let mut magds = MAGDS::new();
+
+            let mut sensor_1 = ASAGraph::<i32>::new_box(1) as Box<dyn Sensor<i32>>;
+            for i in 1..=9 { sensor_1.insert(&i); }
+
+            let mut sensor_2 = ASAGraph::<String, 3>::new_box(2) as Box<dyn Sensor<String>>;
+            for i in 1..=9 { sensor_2.insert(&i.to_string()); }
+
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2 = SimpleNeuron::new(
+                NeuronID { id: 2, parent_id }
+            );
+
+            magds.add_neuron_group("1", Some(parent_id));
+            println!("{:?}", magds.neuron_group_ids);
+            assert_eq!(magds.neuron_group_ids_from_name("1").unwrap().first().unwrap(), &1);
+            assert_eq!(magds.neuron_group_name_from_id(1).unwrap(), "1");
+
+            magds.add_sensor("test_1".into(), Ptr::new(Lock::new(sensor_1.into())));
+            magds.add_sensor("test_2".into(), Ptr::new(Lock::new(sensor_2.into())));
+            assert_eq!(magds.sensor_names[&1], Ptr::from("test_1"));
+            assert_eq!(magds.sensor_names[&2], Ptr::from("test_2"));
+            assert_eq!(magds.sensor_name(1).unwrap(), "test_1");
+            assert_eq!(magds.sensor_name(2).unwrap(), "test_2");
+
+            magds.add_neuron(neuron_1);
+            magds.add_neuron(neuron_2);
+
+            let sensor_1_from_magds = magds.sensor(1).unwrap();
+            sensor_1_from_magds.borrow_mut().insert(&10.into());
+            sensor_1_from_magds.borrow_mut().insert(&11.into());
+            sensor_1_from_magds.borrow_mut().insert(&12.into());
+            assert!(sensor_1_from_magds.borrow().as_i32().is_some());
+
+            let mut sum = 0;
+            for i in 1..=12 {
+                let el = sensor_1_from_magds.borrow().search(&(i as i32).into());
+                assert!(el.is_some());
+                let neuron_id = NeuronID { id: i, parent_id: 1 };
+                let el = el.unwrap();
+                assert_eq!(el.borrow().id(), neuron_id);
+                let counter = el.borrow().counter();
+                sum += counter;
+                assert_eq!(counter, 1);
+            }
+            assert_eq!(sum, 12);
+
+            let sensor_2_from_magds = magds.sensor(2).unwrap();
+            sensor_2_from_magds.borrow_mut().insert(&10.to_string().into());
+            sensor_2_from_magds.borrow_mut().insert(&11.to_string().into());
+            sensor_2_from_magds.borrow_mut().insert(&12.to_string().into());
+
+            let mut sum = 0;
+            for i in 1..=12 {
+                let el = sensor_2_from_magds.borrow().search(&(i.to_string()).into());
+                assert!(el.is_some());
+                let neuron_id = NeuronID { id: i, parent_id: 2 };
+                let el = el.unwrap();
+                println!("i {i} el_id {} ket {}", el.borrow().id(), el.borrow().value());
+                assert_eq!(el.borrow().id(), neuron_id);
+                let counter = el.borrow().counter();
+                sum += counter;
+                assert_eq!(counter, 1);
+            }
+            assert_eq!(sum, 12);
+
+            let neuron_1_id = NeuronID::new(1, 1);
+            let neuron_1_from_magds = magds.neuron_from_id(&neuron_1_id).unwrap();
+            assert_eq!(neuron_1_from_magds.borrow().id(), neuron_1_id);
+            let neuron_1_from_magds = magds.neuron(1, 1).unwrap();
+            assert_eq!(neuron_1_from_magds.borrow().id(), neuron_1_id);
+
+            let (_rcstr_test_sensor, rcstr_test_id) = 
+                magds.create_sensor("rcstr_test", DataType::ArcStr);
+            let text: Arc<str> = Arc::from("test");
+            let sensor_element = magds.sensor_insert(rcstr_test_id, &text.into());
+            assert!(sensor_element.is_some())
+        }
+
+        #[test]
+        fn magds_activation() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+            let magds = parser::magds_from_df_custom(
+                "iris", &df, &vec![], 0, false, Ptr::new(ConstantOneWeight), 0.98, 2
+            );
+            println!("{magds}");
+
+            let sepal_length_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let sl43 = magds.sensor_search(sepal_length_id, &4.3_f64.into()).unwrap();
+            let sl57 = magds.sensor_search(sepal_length_id, &5.7_f64.into()).unwrap();
+            let sl58 = magds.sensor_search(sepal_length_id, &5.8_f64.into()).unwrap();
+            let sl59 = magds.sensor_search(sepal_length_id, &5.9_f64.into()).unwrap();
+            let sl79 = magds.sensor_search(sepal_length_id, &7.9_f64.into()).unwrap();
+            let neuron_15 = magds.neuron(15, 1).unwrap();
+            let neuron_16 = magds.neuron(16, 1).unwrap();
+
+            sl58.borrow_mut().activate(1.0, false, false);
+            assert_eq!(sl57.borrow().activation(), 0.0_f32);
+            assert_eq!(sl58.borrow().activation(), 1.0_f32);
+            assert_eq!(sl59.borrow().activation(), 0.0_f32);
+            assert_eq!(neuron_15.borrow().activation(), 0.0_f32);
+
+            sl58.borrow_mut().deactivate(false, false);
+            assert_eq!(sl58.borrow().activation(), 0.0_f32);
+            assert_eq!(neuron_15.borrow().activation(), 0.0_f32);
+
+            sl58.borrow_mut().activate(1.0, false, true);
+            assert_eq!(sl57.borrow().activation(), 0.0_f32);
+            assert_eq!(sl58.borrow().activation(), 1.0_f32);
+            assert_eq!(sl59.borrow().activation(), 0.0_f32);
+            // assert_eq!(neuron_15.borrow().activation(), 1.0_f32 / 7_f32);
+            assert_eq!(neuron_15.borrow().activation(), 1.0_f32 / 1_f32);
+            assert_eq!(neuron_16.borrow().activation(), 0.0_f32);
+
+            sl58.borrow_mut().deactivate(false, false);
+            assert_eq!(sl58.borrow().activation(), 0.0_f32);
+            assert_eq!(neuron_15.borrow().activation(), 1.0_f32 / 1_f32);
+            // assert_eq!(neuron_15.borrow().activation(), 1.0_f32 / 7_f32);
+
+            sl58.borrow_mut().deactivate(false, true);
+            assert_eq!(sl58.borrow().activation(), 0.0_f32);
+            assert_eq!(neuron_15.borrow().activation(), 0.0_f32);
+
+            sl58.borrow_mut().activate(1.0, true, true);
+            assert_eq!(sl43.borrow().activation(), 0.0_f32);
+            assert_eq!(sl57.borrow().activation(), 0.945216_f32);
+            assert_eq!(sl58.borrow().activation(), 1.0_f32);
+            assert_eq!(sl59.borrow().activation(), 0.945216_f32);
+            assert_eq!(sl79.borrow().activation(), 0.0_f32);
+            // assert_eq!(neuron_15.borrow().activation(), 1.0_f32 / 7_f32);
+            assert_eq!(neuron_15.borrow().activation(), 1.0_f32 / 1_f32);
+            // assert_eq!(neuron_16.borrow().activation(), 0.945216_f32 / 8_f32);
+            assert_eq!(neuron_16.borrow().activation(), 0.945216_f32 / 1_f32);
+
+            sl58.borrow_mut().deactivate(true, true);
+            assert_eq!(sl43.borrow().activation(), 0.0_f32);
+            assert_eq!(sl57.borrow().activation(), 0.0_f32);
+            assert_eq!(sl58.borrow().activation(), 0.0_f32);
+            assert_eq!(sl59.borrow().activation(), 0.0_f32);
+            assert_eq!(sl79.borrow().activation(), 0.0_f32);
+            assert_eq!(neuron_15.borrow().activation(), 0.0_f32);
+            assert_eq!(neuron_16.borrow().activation(), 0.0_f32);
+        }
+
+        #[test]
+        fn magds_activation_context() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+            let mut magds = parser::magds_from_df_custom(
+                "iris", &df, &vec![], 0, false, Ptr::new(ConstantOneWeight), 0.98, 2
+            );
+
+            let sepal_length_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let sl57 = magds.sensor_search(sepal_length_id, &5.7_f64.into()).unwrap();
+            let sl58 = magds.sensor_search(sepal_length_id, &5.8_f64.into()).unwrap();
+            let neuron_15 = magds.neuron(15, 1).unwrap();
+            let neuron_16 = magds.neuron(16, 1).unwrap();
+
+            let mut context_58 = ActivationContext::new();
+            let mut context_57 = ActivationContext::new();
+            magds.sensor_activate_in(
+                sepal_length_id, &5.8_f64.into(), 1.0, true, true, &mut context_58
+            ).unwrap();
+            magds.sensor_activate_in(
+                sepal_length_id, &5.7_f64.into(), 1.0, false, true, &mut context_57
+            ).unwrap();
+            assert!(magds.sensor_activate_in(
+                sepal_length_id, &100.0_f64.into(), 1.0, false, true, &mut context_57
+            ).is_err());
+
+            assert_eq!(sl58.borrow().activation_in(&context_58), 1.0_f32);
+            assert_eq!(sl57.borrow().activation_in(&context_58), 0.945216_f32);
+            assert_eq!(neuron_16.borrow().activation_in(&context_58), 0.945216_f32);
+            assert_eq!(sl58.borrow().activation_in(&context_57), 0.0_f32);
+            assert_eq!(sl57.borrow().activation_in(&context_57), 1.0_f32);
+            assert_eq!(neuron_15.borrow().activation_in(&context_57), 0.0_f32);
+
+            assert_eq!(sl58.borrow().activation(), 0.0_f32);
+            assert!(magds.neurons().iter().all(|x| x.borrow().activation() == 0.0));
+
+            magds.sensor_activate(sepal_length_id, &5.8_f64.into(), 1.0, true, true).unwrap();
+            for neuron in magds.neurons() {
+                let neuron = neuron.borrow();
+                assert_eq!(neuron.activation(), neuron.activation_in(&context_58));
+            }
+            magds.deactivate();
+        }
+
+        #[test]
+        fn records() {
+            let mut magds = parser::magds_from_csv_nullable("missing", "data/missing.csv", &vec![]).unwrap();
+            let a_id = *magds.sensor_ids("a").unwrap().first().unwrap();
+            let b_id = *magds.sensor_ids("b").unwrap().first().unwrap();
+            let target_id = *magds.sensor_ids("target").unwrap().first().unwrap();
+            let group_id = *magds.neuron_group_ids_from_name("missing").unwrap().first().unwrap();
+            let text = |x: &str| -> DataTypeValue { Arc::<str>::from(x).into() };
+
+            let id = magds.insert_record(
+                group_id, &[(a_id, 5.0_f64.into()), (b_id, text("x")), (target_id, text("s"))]
+            ).unwrap();
+            assert_eq!(id, NeuronID::new(6, group_id));
+            assert_eq!(magds.neurons().len(), 6);
+            let x = magds.sensor_search(b_id, &text("x")).unwrap();
+            assert_eq!(x.borrow().counter(), 3);
+            assert_eq!(x.borrow().defined_neurons().len(), 3);
+            assert!(magds.insert_record(group_id, &[(a_id, text("x"))]).is_err());
+            assert!(magds.insert_record(group_id + 1, &[]).is_err());
+            assert_eq!(magds.neurons().len(), 6);
+
+            magds.update_record(&id, &[(a_id, DataTypeValue::Null), (b_id, text("z"))]).unwrap();
+            assert!(magds.sensor_search(a_id, &5.0_f64.into()).is_none());
+            assert_eq!(magds.sensor_null_element(a_id).unwrap().borrow().counter(), 3);
+            assert_eq!(x.borrow().counter(), 2);
+            assert_eq!(x.borrow().defined_neurons().len(), 2);
+            let neuron = magds.neuron_from_id(&id).unwrap();
+            assert_eq!(neuron.borrow().explain().len(), 3);
+            assert_eq!(neuron.borrow().explain_one(b_id), Some(text("z")));
+            assert_eq!(neuron.borrow().explain_one(a_id), Some(DataTypeValue::Null));
+
+            magds.remove_record(&id).unwrap();
+            assert!(magds.neuron_from_id(&id).is_none());
+            assert!(magds.sensor_search(b_id, &text("z")).is_none());
+            assert!(magds.sensor_search(target_id, &text("s")).is_none());
+            assert_eq!(magds.sensor_null_element(a_id).unwrap().borrow().counter(), 2);
+            assert!(magds.remove_record(&id).is_err());
+
+            for i in 1..=5 { magds.remove_record(&NeuronID::new(i, group_id)).unwrap(); }
+            assert!(magds.neurons().is_empty());
+            assert!(magds.sensor(b_id).unwrap().borrow().values().is_empty());
+            assert!(magds.sensor_null_element(a_id).is_none());
+        }
+
+        #[test]
+        fn temporal_sensors() {
+            let mut magds = parser::magds_from_csv_nullable("cars", "data/temporal.csv", &vec![]).unwrap();
+            let listed_id = *magds.sensor_ids("listed").unwrap().first().unwrap();
+            let sold_id = *magds.sensor_ids("sold").unwrap().first().unwrap();
+            let price_id = *magds.sensor_ids("price").unwrap().first().unwrap();
+            assert_eq!(magds.sensor_data_type(listed_id), Some(DataType::Date));
+            assert_eq!(magds.sensor_data_type(sold_id), Some(DataType::DateTime));
+
+            let october_3: DataTypeValue = Date::from_ymd(2022, 10, 3).unwrap().into();
+            let october_4: Date = "2022-10-04".parse().unwrap();
+            let october_4 = magds.sensor_search(listed_id, &october_4.into()).unwrap();
+            assert_eq!(october_4.borrow().counter(), 2);
+            let mut context = ActivationContext::new();
+            magds.sensor_activate_in(listed_id, &october_3, 1.0, true, false, &mut context).unwrap();
+            let activation = october_4.borrow().activation_in(&context);
+            assert!(activation > 0.0 && activation < 1.0);
+
+            let derived_ids = magds.add_derived_temporal_sensors(sold_id).unwrap();
+            let names: Vec<_> = derived_ids.iter().map(|id| magds.sensor_name(*id).unwrap()).collect();
+            assert_eq!(names, vec!["sold.weekday", "sold.month", "sold.hour"]);
+            let sold = DateTime::from_ymd_hms(2022, 10, 5, 13, 45, 0).unwrap();
+            let element = magds.sensor_search(sold_id, &sold.into()).unwrap();
+            let neuron = element.borrow().defined_neurons()[0].clone();
+            assert_eq!(neuron.borrow().explain_one(derived_ids[0]), Some(2u8.into()));
+            assert_eq!(neuron.borrow().explain_one(derived_ids[1]), Some(10u8.into()));
+            assert_eq!(neuron.borrow().explain_one(derived_ids[2]), Some(13u8.into()));
+            assert_eq!(magds.sensor_null_element(derived_ids[2]).unwrap().borrow().counter(), 1);
+            assert_eq!(magds.add_derived_temporal_sensors(listed_id).unwrap().len(), 2);
+            assert!(magds.add_derived_temporal_sensors(price_id).is_err());
+
+            let file_path = std::env::temp_dir().join(format!("{}_temporal.magds", module_path!()));
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+            assert_eq!(loaded.sensor_data_type(sold_id), Some(DataType::DateTime));
+            assert!(loaded.sensor_search(sold_id, &sold.into()).is_some());
+        }
+
+        #[test]
+        fn tokenized_sensors() {
+            let mut magds = parser::magds_from_csv_tokenized(
+                "cars", "data/equipment.csv", &vec![], &[("equipment", Tokenizer::list())]
+            ).unwrap();
+            let equipment_id = *magds.sensor_ids("equipment").unwrap().first().unwrap();
+            assert_eq!(magds.sensor_tokenizer(equipment_id), Some(&Tokenizer::list()));
+            let count = |magds: &MAGDS, token: &str| {
+                magds.sensor_search(equipment_id, &DataTypeValue::ArcStr(token.into()))
+                    .map_or(0, |x| x.borrow().counter())
+            };
+            assert_eq!(count(&magds, "navigation"), 3);
+            assert_eq!(count(&magds, "leather"), 2);
+            assert_eq!(count(&magds, "radio"), 2);
+            let element = magds.sensor_search(equipment_id, &Arc::<str>::from("sunroof").into()).unwrap();
+            let audi = element.borrow().defined_neurons()[0].clone();
+            assert_eq!(audi.borrow().explain().len(), 5);
+
+            let group_id = magds.neuron_group_ids()[0];
+            let id = magds.insert_record(
+                group_id, &[(equipment_id, Arc::<str>::from("radio | sunroof").into())]
+            ).unwrap();
+            assert_eq!(count(&magds, "radio"), 3);
+            assert_eq!(count(&magds, "sunroof"), 2);
+            magds.update_record(&id, &[(equipment_id, Arc::<str>::from("sunroof, radio").into())])
+                .unwrap();
+            assert_eq!(count(&magds, "radio"), 3);
+            magds.update_record(&id, &[(equipment_id, Arc::<str>::from("sunroof").into())]).unwrap();
+            assert_eq!(count(&magds, "radio"), 2);
+            assert_eq!(
+                magds.sensor_tokens(equipment_id, &Arc::<str>::from("a, b").into()),
+                vec![Arc::<str>::from("a").into(), Arc::<str>::from("b").into()]
+            );
+
+            let file_path = std::env::temp_dir().join(format!("{}_tokenized.magds", module_path!()));
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+            assert_eq!(loaded.sensor_tokenizer(equipment_id), Some(&Tokenizer::list()));
+            assert_eq!(count(&loaded, "sunroof"), 2);
+        }
+
+        #[test]
+        fn relations() {
+            let regions = polars_common::csv_to_dataframe("data/relations/regions.csv", &vec![]).unwrap();
+            let dealers = polars_common::csv_to_dataframe("data/relations/dealers.csv", &vec![]).unwrap();
+            let cars = polars_common::csv_to_dataframe("data/relations/cars.csv", &vec![]).unwrap();
+            let relations = [
+                parser::Relation {
+                    table: "cars", column: "dealer_id",
+                    referenced_table: "dealers", referenced_column: "id"
+                },
+                parser::Relation {
+                    table: "dealers", column: "region_id",
+                    referenced_table: "regions", referenced_column: "id"
+                }
+            ];
+            let mut magds = parser::magds_from_dfs(
+                &[("regions", &regions), ("dealers", &dealers), ("cars", &cars)], &relations
+            ).unwrap();
+            let (regions_id, dealers_id, cars_id) = (1, 2, 3);
+            assert_eq!(magds.neuron_group_ids_from_name("cars"), Some(&[cars_id][..]));
+            let north = magds.neuron(1, regions_id).unwrap();
+            assert_eq!(north.borrow().defined_neurons().len(), 2);
+            assert_eq!(magds.neuron(1, dealers_id).unwrap().borrow().defined_neurons().len(), 2);
+            assert_eq!(parser::add_relation(&mut magds, &relations[0]).unwrap(), 0);
+
+            let region_id = *magds.sensor_ids("region").unwrap().first().unwrap();
+            let south: DataTypeValue = Arc::<str>::from("south").into();
+            let mut context = ActivationContext::new();
+            magds.sensor_activate_in(region_id, &south, 1.0, false, true, &mut context).unwrap();
+            let activation = |id: u32| {
+                magds.neuron(id, cars_id).unwrap().borrow().activation_in(&context)
+            };
+            assert_eq!(activation(1), 0.0);
+            assert!(activation(4) > 0.0 && activation(5) > 0.0);
+            let brand_id = *magds.sensor_ids("brand").unwrap().first().unwrap();
+            let features = vec![(region_id, south.clone())];
+            let prediction = prediction::predict_custom(&magds, &features, brand_id, false, 2, 2.0)
+                .unwrap();
+            assert!(["skoda", "toyota"].contains(&prediction.0.to_string().as_str()));
+
+            let skoda = NeuronID::new(4, cars_id);
+            assert!(magds.connect_neurons(&skoda, &NeuronID::new(2, regions_id)).is_err());
+            assert!(magds.connect_neurons(&skoda, &skoda).is_err());
+            magds.connect_neurons(&NeuronID::new(2, regions_id), &skoda).unwrap();
+            magds.disconnect_neurons(&NeuronID::new(2, regions_id), &skoda).unwrap();
+
+            let file_path = std::env::temp_dir().join(format!("{}_relations.magds", module_path!()));
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let mut loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+            assert_eq!(loaded.neuron(1, regions_id).unwrap().borrow().defined_neurons().len(), 2);
+
+            loaded.remove_record(&NeuronID::new(3, dealers_id)).unwrap();
+            assert!(loaded.neuron(2, regions_id).unwrap().borrow().defined_neurons().is_empty());
+            assert!(loaded.neuron(4, cars_id).is_some());
+            let mut context = ActivationContext::new();
+            loaded.sensor_activate_in(region_id, &south, 1.0, false, true, &mut context).unwrap();
+            assert_eq!(loaded.neuron(4, cars_id).unwrap().borrow().activation_in(&context), 0.0);
+        }
+
+        #[test]
+        fn lateral_connections() {
+            let mut magds = parser::magds_from_csv_tokenized(
+                "cars", "data/equipment.csv", &vec![], &[("equipment", Tokenizer::list())]
+            ).unwrap();
+            let equipment_id = *magds.sensor_ids("equipment").unwrap().first().unwrap();
+            let price_id = *magds.sensor_ids("price").unwrap().first().unwrap();
+            let group_id = magds.neuron_group_ids()[0];
+            let (audi, bmw, fiat, skoda) = (
+                NeuronID::new(1, group_id),
+                NeuronID::new(2, group_id),
+                NeuronID::new(3, group_id),
+                NeuronID::new(4, group_id)
+            );
+            let connections = |magds: &MAGDS, id: &NeuronID, kind: ConnectionKind| {
+                magds.neuron_from_id(id).unwrap().borrow().weighted_connections(kind)
+            };
+
+            assert_eq!(magds.connect_similar(group_id, 0.4).unwrap(), 1);
+            let similar = connections(&magds, &bmw, ConnectionKind::Similarity);
+            assert_eq!(similar[0].0.borrow().id(), audi);
+            assert_eq!(similar[0].1, 0.5);
+            assert_eq!(magds.connect_competing(group_id, price_id, 0.1, 0.5).unwrap(), 3);
+            assert_eq!(connections(&magds, &skoda, ConnectionKind::Inhibitory).len(), 3);
+            assert!(connections(&magds, &audi, ConnectionKind::Inhibitory)
+                .iter()
+                .all(|(x, _weight)| x.borrow().id() == skoda));
+
+            let sunroof: DataTypeValue = Arc::<str>::from("sunroof").into();
+            let mut context = ActivationContext::new();
+            magds.sensor_activate_in(equipment_id, &sunroof, 1.0, true, true, &mut context).unwrap();
+            let activation = |magds: &MAGDS, id: &NeuronID, context: &ActivationContext| {
+                magds.neuron_from_id(id).unwrap().borrow().activation_in(context)
+            };
+            assert_eq!(activation(&magds, &audi, &context), 1.0);
+            assert_eq!(activation(&magds, &bmw, &context), 0.5);
+            assert_eq!(activation(&magds, &skoda, &context), -0.5);
+            assert_eq!(activation(&magds, &fiat, &context), 0.0);
+
+            magds.connect_lateral(&fiat, &audi, ConnectionKind::Sequential, 0.25).unwrap();
+            assert!(magds.connect_lateral(&fiat, &fiat, ConnectionKind::Similarity, 1.0).is_err());
+            assert!(connections(&magds, &audi, ConnectionKind::Sequential).is_empty());
+
+            let file_path = std::env::temp_dir().join(format!("{}_lateral.magds", module_path!()));
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let mut loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+            assert_eq!(connections(&loaded, &bmw, ConnectionKind::Similarity)[0].1, 0.5);
+            assert_eq!(connections(&loaded, &fiat, ConnectionKind::Sequential)[0].1, 0.25);
+
+            loaded.remove_record(&audi).unwrap();
+            assert!(connections(&loaded, &bmw, ConnectionKind::Similarity).is_empty());
+            assert!(connections(&loaded, &fiat, ConnectionKind::Sequential).is_empty());
+            assert_eq!(connections(&loaded, &skoda, ConnectionKind::Inhibitory).len(), 2);
+        }
+
+        #[test]
+        fn lateral_and_defining_connections() {
+            let mut magds = parser::magds_from_csv_tokenized(
+                "cars", "data/equipment.csv", &vec![], &[("equipment", Tokenizer::list())]
+            ).unwrap();
+            let equipment_id = *magds.sensor_ids("equipment").unwrap().first().unwrap();
+            let group_id = magds.neuron_group_ids()[0];
+            let (audi, bmw, fiat, skoda) = (
+                NeuronID::new(1, group_id),
+                NeuronID::new(2, group_id),
+                NeuronID::new(3, group_id),
+                NeuronID::new(4, group_id)
+            );
+
+            assert!(magds.connect_lateral(&audi, &audi, ConnectionKind::Similarity, 1.0).is_err());
+            magds.connect_lateral(&fiat, &audi, ConnectionKind::Sequential, 0.25).unwrap();
+            assert!(magds.connect_neurons(&audi, &fiat).is_err());
+            magds.connect_neurons(&audi, &bmw).unwrap();
+            for kind in super::LATERAL_CONNECTIONS {
+                assert!(magds.connect_lateral(&bmw, &audi, kind, 0.5).is_err());
+            }
+            assert!(magds.connect_lateral(&audi, &bmw, ConnectionKind::Similarity, 0.5).is_err());
+            magds.connect_lateral(&audi, &bmw, ConnectionKind::Sequential, 0.5).unwrap();
+            magds.connect_lateral(&bmw, &skoda, ConnectionKind::Inhibitory, 0.5).unwrap();
+
+            let sunroof: DataTypeValue = Arc::<str>::from("sunroof").into();
+            let mut context = ActivationContext::new();
+            magds.sensor_activate_in(equipment_id, &sunroof, 1.0, true, true, &mut context).unwrap();
+            magds.sensor_activate(equipment_id, &sunroof, 1.0, true, true).unwrap();
+            let activation = |id: &NeuronID| {
+                let neuron = magds.neuron_from_id(id).unwrap();
+                let activation = neuron.borrow().activation();
+                assert_eq!(activation, neuron.borrow().activation_in(&context));
+                activation
+            };
+            assert!(activation(&audi) > 0.0 && activation(&bmw) > 0.0);
+            assert!(activation(&skoda) < 0.0);
+            assert_eq!(activation(&fiat), 0.0);
+        }
+
+        #[test]
+        fn save_load() {
+            let df = polars_common::csv_to_dataframe("data/iris.csv", &vec![]).unwrap();
+            let magds = parser::magds_from_df("iris", &df);
+
+            let file_path = std::env::temp_dir().join("magds_save_load_sync.magds");
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+
+            assert_eq!(
+                super::super::snapshot::magds_to_snapshot(&loaded).unwrap(),
+                super::super::snapshot::magds_to_snapshot(&magds).unwrap()
+            );
+            assert_eq!(loaded.neurons().len(), magds.neurons().len());
+            assert_eq!(loaded.neuron_group_name_from_id(1), Some("iris"));
+
+            let sepal_length_id = *magds.sensor_ids("sepal.length").unwrap().first().unwrap();
+            let sl58 = magds.sensor_search(sepal_length_id, &5.8_f64.into()).unwrap();
+            let loaded_sl58 = loaded.sensor_search(sepal_length_id, &5.8_f64.into()).unwrap();
+            sl58.borrow_mut().activate(1.0, true, true);
+            loaded_sl58.borrow_mut().activate(1.0, true, true);
+            for (neuron, loaded_neuron) in magds.neurons().iter().zip(loaded.neurons()) {
+                assert_eq!(neuron.borrow().id(), loaded_neuron.borrow().id());
+                assert_eq!(neuron.borrow().activation(), loaded_neuron.borrow().activation());
+            }
+
+            assert!(MAGDS::load("data/iris.csv").is_err());
+        }
+
+        #[test]
+        fn save_load_weighting_strategy() {
+            let magds = parser::magds_from_csv_custom(
+                "iris", "data/iris.csv", &vec![], Ptr::new(OneOverOuts), 0.00001, 1
+            ).unwrap();
+
+            let file_path = std::env::temp_dir().join(format!("{}_strategy.magds", module_path!()));
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+
+            assert_eq!(
+                super::super::snapshot::magds_to_snapshot(&loaded).unwrap(),
+                super::super::snapshot::magds_to_snapshot(&magds).unwrap()
+            );
+            for neuron in loaded.neurons() {
+                assert_eq!(neuron.borrow().weighting_strategy_name(), Some("OneOverOuts"));
+            }
+        }
+
+        #[test]
+        fn save_load_nullable() {
+            let magds = parser::magds_from_csv_nullable("missing", "data/missing.csv", &vec![]).unwrap();
+
+            let file_path = std::env::temp_dir().join("magds_save_load_nullable_sync.magds");
+            let file_path = file_path.to_str().unwrap();
+            magds.save(file_path).unwrap();
+            let loaded = MAGDS::load(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+
+            assert_eq!(
+                super::super::snapshot::magds_to_snapshot(&loaded).unwrap(),
+                super::super::snapshot::magds_to_snapshot(&magds).unwrap()
+            );
+
+            let a_id = *loaded.sensor_ids("a").unwrap().first().unwrap();
+            let null_element = loaded.sensor_null_element(a_id).unwrap();
+            assert_eq!(null_element.borrow().counter(), 2);
+            null_element.borrow_mut().activate(1.0, false, true);
+            let group_id = *loaded.neuron_group_ids_from_name("missing").unwrap().first().unwrap();
+            assert!(loaded.neuron(3, group_id).unwrap().borrow().activation() > 0.0);
+            assert!(loaded.neuron(5, group_id).unwrap().borrow().activation() > 0.0);
+            assert_eq!(loaded.neuron(1, group_id).unwrap().borrow().activation(), 0.0);
+        }
+    }
+}
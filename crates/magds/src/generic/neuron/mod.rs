@@ -0,0 +1,2 @@
+pub mod simple_neuron;
+pub mod null_element;
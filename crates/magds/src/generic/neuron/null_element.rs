@@ -0,0 +1,256 @@
+use std::fmt::{ Debug, Display, Formatter, Result as FmtResult };
+
+use anyhow::Result;
+
+use witchnet_common::{
+    neuron::{ NeuronID, ActivationContext },
+    connection::{ ConnectionKind, collective::WeightingStrategy },
+    data::{ DataTypeValue, DataType }
+};
+
+use super::super::common::{
+    pointer::*,
+    neuron::Neuron,
+    collective::{
+        CollectiveConnections,
+        defining::{ DefiningConnections, DefiningWeightingStrategy }
+    }
+};
+
+pub struct NullElement {
+    pub id: NeuronID,
+    pub activation: f32,
+    pub counter: usize,
+    pub(crate) self_ptr: WeakPtr<Lock<NullElement>>,
+    pub(crate) data_type: DataType,
+    pub(crate) definitions: DefiningConnections
+}
+
+impl NullElement {
+    pub const ID: u32 = 0;
+
+    pub fn new(
+        sensor_id: u32,
+        data_type: DataType,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>
+    ) -> Ptr<Lock<NullElement>> {
+        let element_ptr = Ptr::new(
+            Lock::new(
+                NullElement {
+                    id: NeuronID::new(Self::ID, sensor_id),
+                    activation: 0.0f32,
+                    counter: 1,
+                    self_ptr: WeakPtr::new(),
+                    data_type,
+                    definitions: DefiningConnections::new(weighting_strategy)
+                }
+            )
+        );
+        element_ptr.borrow_mut().self_ptr = Ptr::downgrade(&element_ptr);
+        element_ptr
+    }
+
+    pub fn weighting_strategy_name(&self) -> &str { self.definitions.weighting_strategy.name() }
+}
+
+impl Neuron for NullElement {
+    fn id(&self) -> NeuronID { self.id.clone() }
+
+    fn value(&self) -> DataTypeValue { DataTypeValue::Null }
+
+    fn activation(&self) -> f32 { self.activation }
+
+    fn is_sensor(&self) -> bool { true }
+
+    fn data_type(&self) -> DataType { self.data_type }
+
+    fn increment_counter(&mut self) -> usize {
+        self.counter += 1;
+        self.counter
+    }
+
+    fn decrement_counter(&mut self) -> usize {
+        if self.counter > 0 { self.counter -= 1; }
+        self.counter
+    }
+
+    fn counter(&self) -> usize { self.counter }
+
+    fn explain(&self) -> &[Ptr<Lock<dyn Neuron>>] { &[] }
+
+    fn explain_one(&self, _parent: u32) -> Option<DataTypeValue> { Some(DataTypeValue::Null) }
+
+    fn defined_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        self.definitions.connected_neurons()
+    }
+
+    fn activate(
+        &mut self, signal: f32, _propagate_horizontal: bool, propagate_vertical: bool
+    ) -> f32 {
+        self.activation += signal;
+
+        let mut max_activation = 0.0f32;
+        if propagate_vertical {
+            let output_signal = self.activation * self.definitions.common_weight();
+            for neuron in self.definitions.connected_neurons() {
+                max_activation = f32::max(max_activation, output_signal);
+                neuron.borrow_mut().activate(output_signal, false, true);
+            }
+        }
+
+        max_activation
+    }
+
+    fn activate_defining(&mut self, signal: f32) -> f32 {
+        self.activation += signal;
+        let output_signal = self.activation * self.definitions.common_weight();
+        let mut max_activation = 0.0f32;
+        for neuron in self.definitions.connected_neurons() {
+            max_activation = f32::max(max_activation, output_signal);
+            neuron.borrow_mut().activate(output_signal, false, false);
+        }
+        max_activation
+    }
+
+    fn activate_in(
+        &self, 
+        signal: f32, 
+        _propagate_horizontal: bool, 
+        propagate_vertical: bool, 
+        context: &mut ActivationContext
+    ) -> f32 {
+        let activation = context.add(self.id(), true, signal);
+
+        let mut max_activation = 0.0f32;
+        if propagate_vertical {
+            let output_signal = activation * self.definitions.common_weight();
+            for neuron in self.definitions.connected_neurons() {
+                max_activation = f32::max(max_activation, output_signal);
+                neuron.borrow().activate_in(output_signal, false, true, context);
+            }
+        }
+
+        max_activation
+    }
+
+    fn deactivate(&mut self, _propagate_horizontal: bool, propagate_vertical: bool) {
+        self.activation = 0.0f32;
+
+        if propagate_vertical {
+            for neuron in self.definitions.connected_neurons() {
+                neuron.borrow_mut().deactivate(false, true);
+            }
+        }
+    }
+
+    fn connect_to(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        match kind {
+            ConnectionKind::Defining => {
+                if is_to_sensor {
+                    anyhow::bail!("connections between sensors are not allowed")
+                }
+                self.definitions.add(to);
+                Ok(())
+            }
+            _ => anyhow::bail!("only defining connection to NullElement can be created")
+        }
+    }
+
+    fn connect_bilateral(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        match kind {
+            ConnectionKind::Defining => {
+                self.connect_to(to.clone(), is_to_sensor, kind)?;
+                to.borrow_mut().connect_to(
+                    self.self_ptr.upgrade().unwrap(), true, ConnectionKind::Explanatory
+                )
+            }
+            _ => anyhow::bail!("only defining connection from NullElement can be created")
+        }
+    }
+
+    fn disconnect_from(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        match kind {
+            ConnectionKind::Defining if !is_to_sensor => {
+                if !self.definitions.remove(&to) {
+                    anyhow::bail!("null element {} has no such defining connection", self.id)
+                }
+                Ok(())
+            }
+            _ => anyhow::bail!("only defining connection from NullElement can be removed")
+        }
+    }
+
+    fn disconnect_bilateral(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        self.disconnect_from(to.clone(), is_to_sensor, kind)?;
+        to.borrow_mut().disconnect_from(
+            self.self_ptr.upgrade().unwrap(), true, ConnectionKind::Explanatory
+        )
+    }
+}
+
+impl Display for NullElement {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "[{}|null|c:{}|a:{}]", self.id, self.counter, self.activation)
+    }
+}
+
+impl Debug for NullElement {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { write!(f, "{self}") }
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use witchnet_common::{
+            neuron::NeuronID,
+            connection::{ ConnectionKind, collective::defining::ConstantOneWeight },
+            data::{ DataType, DataTypeValue }
+        };
+
+        use super::{
+            NullElement,
+            super::{
+                simple_neuron::SimpleNeuron,
+                super::common::{ pointer::*, neuron::Neuron }
+            }
+        };
+
+        #[test]
+        fn null_element() {
+            let null_element = NullElement::new(1, DataType::F64, Ptr::new(ConstantOneWeight));
+            let neuron_1 = SimpleNeuron::new(NeuronID::new(1, 1));
+            let neuron_2 = SimpleNeuron::new(NeuronID::new(2, 1));
+            null_element.borrow_mut().connect_bilateral(
+                neuron_1.clone(), false, ConnectionKind::Defining
+            ).unwrap();
+            assert!(null_element.borrow_mut().connect_bilateral(
+                neuron_2.clone(), false, ConnectionKind::Explanatory
+            ).is_err());
+
+            assert_eq!(null_element.borrow().id(), NeuronID::new(NullElement::ID, 1));
+            assert_eq!(null_element.borrow().value(), DataTypeValue::Null);
+            assert_eq!(neuron_1.borrow().explain_one(1), Some(DataTypeValue::Null));
+            assert_eq!(neuron_2.borrow().explain_one(1), None);
+
+            assert_eq!(null_element.borrow_mut().activate(1.0, true, true), 1.0);
+            assert_eq!(neuron_1.borrow().activation(), 1.0);
+            null_element.borrow_mut().deactivate(true, true);
+
+            null_element.borrow_mut().activate(-0.5, true, true);
+            assert_eq!(neuron_1.borrow().activation(), -0.5);
+            assert_eq!(neuron_2.borrow().activation(), 0.0);
+
+            null_element.borrow_mut().deactivate(true, true);
+            assert_eq!(null_element.borrow().activation(), 0.0);
+            assert_eq!(neuron_1.borrow().activation(), 0.0);
+        }
+    }
+}
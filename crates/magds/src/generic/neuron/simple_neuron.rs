@@ -0,0 +1,732 @@
+use std::{
+    fmt::{ Display, Formatter, Result as FmtResult },
+    collections::HashSet
+};
+
+use anyhow::Result;
+
+use witchnet_common::{
+    neuron::{ NeuronID, ActivationContext }, 
+    connection::{
+        ConnectionKind,
+        collective::{ WeightingStrategy, defining::ConstantOneWeight }
+    },
+    data::{ DataTypeValue, DataType }
+};
+
+use super::super::common::{
+    pointer::*,
+    neuron::Neuron,
+    collective::{
+        CollectiveConnections,
+        defining::{ DefiningConnections, DefiningWeightingStrategy },
+        explanatory::ExplanatoryConnections,
+        similarity::SimilarityConnections,
+        inhibitory::InhibitoryConnections,
+        sequential::SequentialConnections
+    }
+};
+
+pub struct SimpleNeuron {
+    pub id: NeuronID,
+    pub activation: f32,
+    pub counter: usize,
+    pub(crate) self_ptr: WeakPtr<Lock<SimpleNeuron>>,
+    pub(crate) defined_neurons: DefiningConnections,
+    pub(crate) defining_neurons: ExplanatoryConnections,
+    pub(crate) defining_sensors: ExplanatoryConnections,
+    pub(crate) similar_neurons: SimilarityConnections,
+    pub(crate) inhibited_neurons: InhibitoryConnections,
+    pub(crate) next_neurons: SequentialConnections
+}
+
+impl SimpleNeuron {
+    pub fn new(id: NeuronID) -> Ptr<Lock<SimpleNeuron>> {
+        let weighting_strategy = Ptr::new(ConstantOneWeight);
+        let neuron_ptr = Ptr::new(
+            Lock::new(
+                SimpleNeuron {
+                    id,
+                    activation: 0.0f32,
+                    counter: 1,
+                    self_ptr: WeakPtr::new(), 
+                    defined_neurons: DefiningConnections::new(weighting_strategy),
+                    defining_neurons: ExplanatoryConnections::new(),
+                    defining_sensors: ExplanatoryConnections::new(),
+                    similar_neurons: SimilarityConnections::new(),
+                    inhibited_neurons: InhibitoryConnections::new(),
+                    next_neurons: SequentialConnections::new()
+                }
+            )
+        );
+
+        neuron_ptr.borrow_mut().self_ptr = Ptr::downgrade(&neuron_ptr);
+        neuron_ptr
+    }
+
+    pub fn new_custom(
+        id: NeuronID, 
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>
+    ) -> Ptr<Lock<SimpleNeuron>> {
+        let neuron_ptr = Ptr::new(
+            Lock::new(
+                SimpleNeuron {
+                    id,
+                    counter: 1,
+                    activation: 0.0f32,
+                    self_ptr: WeakPtr::new(), 
+                    defined_neurons: DefiningConnections::new(weighting_strategy),
+                    defining_neurons: ExplanatoryConnections::new(),
+                    defining_sensors: ExplanatoryConnections::new(),
+                    similar_neurons: SimilarityConnections::new(),
+                    inhibited_neurons: InhibitoryConnections::new(),
+                    next_neurons: SequentialConnections::new()
+                }
+            )
+        );
+
+        neuron_ptr.borrow_mut().self_ptr = Ptr::downgrade(&neuron_ptr);
+        neuron_ptr
+    }
+
+    pub(crate) fn defined_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        self.defined_neurons.connected_neurons()
+    }
+
+    pub(crate) fn defining_sensors(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        self.defining_sensors.connected_neurons()
+    }
+
+    pub(crate) fn defining_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        self.defining_neurons.connected_neurons()
+    }
+
+    /// Whether `to` is among the neurons defining this one, or defined by it if `!upward`.
+    /// Neither this neuron nor `to` gets borrowed, both can be locked by a bilateral connection
+    fn reaches(&self, to: &Ptr<Lock<dyn Neuron>>, upward: bool) -> bool {
+        let start = if upward { self.defining_neurons() } else { self.defined_neurons() };
+        let mut stack = start.to_vec();
+        let mut visited: HashSet<NeuronID> = HashSet::new();
+        while let Some(neuron) = stack.pop() {
+            if Ptr::ptr_eq(&neuron, to) { return true }
+            let neuron = neuron.borrow();
+            if !visited.insert(neuron.id()) { continue }
+            let next = if upward { neuron.defining_neurons() } else { neuron.defined_neurons() };
+            stack.extend(next.iter().cloned());
+        }
+        false
+    }
+
+    pub fn id(&self) -> NeuronID { self.id.clone() }
+
+    pub fn value(&self) -> DataTypeValue { self.id().id.into() }
+
+    pub fn activation(&self) -> f32 { self.activation }
+
+    pub fn is_sensor(&self) -> bool { false }
+
+    pub fn data_type(&self) -> DataType { DataType::Unknown }
+
+    pub fn counter(&self) -> usize { self.counter }
+
+    pub fn explain(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        self.defining_sensors()
+    }
+
+    fn explain_one(&self, parent: u32) -> Option<DataTypeValue> {
+        let defining_sensors = self.defining_sensors();
+        for sensor in defining_sensors.into_iter() {
+            let sensor = sensor.borrow();
+            if sensor.id().parent_id == parent { return Some(sensor.value()) }
+        }
+        None
+    }
+
+    pub fn activate(
+        &mut self, signal: f32, propagate_horizontal: bool, propagate_vertical: bool
+    ) -> f32 {
+        self.activation += signal;
+
+        let mut max_activation = 0.0f32;
+        // let neurons_activation: Vec<Ptr<Lock<dyn Neuron>>> = self.defined_neurons()
+        //     .into_iter()
+        //     .cloned()
+        //     .collect();
+        
+        if propagate_vertical {
+            for neuron in self.defined_neurons() {
+                if !neuron.borrow().is_sensor() {
+                    let output_signal = self.activation / self.defined_neurons.common_weight();
+                    // let output_signal = self.activation /
+                    //     f32::max(self.defined_neurons().len() as f32, 1.0f32);
+                    max_activation = f32::max(max_activation, output_signal);
+                    neuron.borrow_mut().activate(
+                        output_signal, propagate_horizontal, propagate_vertical
+                    );
+                }
+            }
+        }
+
+        if propagate_horizontal {
+            for (neuron, signal) in self.lateral_signals(signal) {
+                neuron.borrow_mut().activate(signal, false, false);
+            }
+        }
+
+        max_activation
+    }
+
+    /// Signals sent one hop through the similarity, inhibitory and sequential connections
+    /// when `signal` arrives, they're linear so the order of activations doesn't matter
+    fn lateral_signals(&self, signal: f32) -> Vec<(Ptr<Lock<dyn Neuron>>, f32)> {
+        let similar = self.similar_neurons.weighted_neurons().into_iter()
+            .map(|(neuron, weight)| (neuron, signal * weight));
+        let inhibited = self.inhibited_neurons.weighted_neurons().into_iter()
+            .map(|(neuron, weight)| (neuron, -signal * weight));
+        let next = self.next_neurons.weighted_neurons().into_iter()
+            .map(|(neuron, weight)| (neuron, signal * weight));
+        similar.chain(inhibited).chain(next).collect()
+    }
+
+    pub fn activate_defining(&mut self, signal: f32) -> f32 {
+        self.activation += signal;
+        let mut max_activation = 0.0f32;
+        for neuron in self.defined_neurons() {
+            if !neuron.borrow().is_sensor() {
+                let output_signal = self.activation / self.defined_neurons.common_weight();
+                max_activation = f32::max(max_activation, output_signal);
+                neuron.borrow_mut().activate(output_signal, false, false);
+            }
+        }
+        max_activation
+    }
+
+    pub fn activate_in(
+        &self, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool, 
+        context: &mut ActivationContext
+    ) -> f32 {
+        let activation = context.add(self.id(), false, signal);
+
+        let mut max_activation = 0.0f32;
+        if propagate_vertical {
+            for neuron in self.defined_neurons() {
+                if !neuron.borrow().is_sensor() {
+                    let output_signal = activation / self.defined_neurons.common_weight();
+                    max_activation = f32::max(max_activation, output_signal);
+                    neuron.borrow().activate_in(
+                        output_signal, propagate_horizontal, propagate_vertical, context
+                    );
+                }
+            }
+        }
+
+        if propagate_horizontal {
+            for (neuron, signal) in self.lateral_signals(signal) {
+                neuron.borrow().activate_in(signal, false, false, context);
+            }
+        }
+
+        max_activation
+    }
+
+    pub fn deactivate(&mut self, propagate_horizontal: bool, propagate_vertical: bool) {
+        self.activation = 0.0f32;
+
+        if propagate_vertical {
+            for neuron in self.defined_neurons() {
+                neuron.borrow_mut().deactivate(propagate_horizontal, propagate_vertical);
+            }
+        }
+
+        if propagate_horizontal {
+            for (neuron, _signal) in self.lateral_signals(0.0f32) {
+                neuron.borrow_mut().deactivate(false, false);
+            }
+        }
+    }
+}
+
+impl Neuron for SimpleNeuron {
+    fn id(&self) -> NeuronID { self.id() }
+
+    fn value(&self) -> DataTypeValue { self.value() }
+
+    fn activation(&self) -> f32 { self.activation() }
+
+    fn is_sensor(&self) -> bool { self.is_sensor() }
+
+    fn data_type(&self) -> DataType { self.data_type() }
+
+    fn counter(&self) -> usize { self.counter() }
+
+    fn increment_counter(&mut self) -> usize {
+        self.counter += 1;
+        self.counter
+    }
+
+    fn decrement_counter(&mut self) -> usize {
+        if self.counter > 0 { self.counter -= 1; }
+        self.counter
+    }
+
+    fn explain(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        self.explain()
+    }
+
+    fn explain_one(&self, parent: u32) -> Option<DataTypeValue> {
+        self.explain_one(parent)
+    }
+
+    fn defined_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] {
+        &self.defined_neurons.connected_neurons()
+    }
+
+    fn activate(
+        &mut self, signal: f32, propagate_horizontal: bool, propagate_vertical: bool
+    ) -> f32 {
+        self.activate(signal, propagate_horizontal, propagate_vertical)
+    }
+
+    fn activate_defining(&mut self, signal: f32) -> f32 {
+        self.activate_defining(signal)
+    }
+
+    fn activate_in(
+        &self, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool, 
+        context: &mut ActivationContext
+    ) -> f32 {
+        self.activate_in(signal, propagate_horizontal, propagate_vertical, context)
+    }
+
+    fn deactivate(&mut self, propagate_horizontal: bool, propagate_vertical: bool) {
+        self.deactivate(propagate_horizontal, propagate_vertical)
+    }
+
+    fn connect_to(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        match kind {
+            ConnectionKind::Defining => {
+                if is_to_sensor {
+                    anyhow::bail!("only defining connection from sensor to neuron can be created")
+                }
+                self.defined_neurons.add(to.clone());
+                Ok(())
+            },
+            ConnectionKind::Explanatory => {
+                if is_to_sensor {
+                    self.defining_sensors.add(to.clone());
+                } else {
+                    self.defining_neurons.add(to.clone());
+                }
+                Ok(())
+            },
+            _ => self.connect_to_weighted(to, is_to_sensor, kind, 1.0f32)
+        }
+    }
+
+    fn connect_bilateral(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        if is_to_sensor {
+            anyhow::bail!("connections from SimpleNeuron to Element are not allowed")
+        } else {
+            match kind {
+                ConnectionKind::Defining => {
+                    self.connect_to(to.clone(), is_to_sensor, kind)?;
+                    to.borrow_mut().connect_to(
+                        self.self_ptr.upgrade().unwrap(), false, ConnectionKind::Explanatory
+                    )?;
+                    Ok(())
+                }
+                _ => self.connect_bilateral_weighted(to, is_to_sensor, kind, 1.0f32)
+            }
+        }
+    }
+
+    fn connect_to_weighted(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind, weight: f32
+    ) -> Result<()> {
+        if is_to_sensor { anyhow::bail!("{:?} connections to sensors are not allowed", kind) }
+        let self_ptr: Ptr<Lock<dyn Neuron>> = self.self_ptr.upgrade().unwrap();
+        if Ptr::ptr_eq(&self_ptr, &to) {
+            anyhow::bail!("neuron {} can't be connected to itself", self.id)
+        }
+        if self.reaches(&to, true) {
+            anyhow::bail!("neuron {} can't be connected laterally to a neuron defining it", self.id)
+        }
+        match kind {
+            ConnectionKind::Similarity => self.similar_neurons.add_weighted(to, weight),
+            ConnectionKind::Inhibitory => self.inhibited_neurons.add_weighted(to, weight),
+            ConnectionKind::Sequential => self.next_neurons.add_weighted(to, weight),
+            _ => anyhow::bail!("{:?} connections have no weights", kind)
+        }
+        Ok(())
+    }
+
+    fn connect_bilateral_weighted(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind, weight: f32
+    ) -> Result<()> {
+        match kind {
+            ConnectionKind::Similarity | ConnectionKind::Inhibitory => {
+                if self.reaches(&to, false) {
+                    anyhow::bail!(
+                        "neuron {} can't be connected bilaterally to a neuron it defines", self.id
+                    )
+                }
+                self.connect_to_weighted(to.clone(), is_to_sensor, kind, weight)?;
+                to.borrow_mut().connect_to_weighted(
+                    self.self_ptr.upgrade().unwrap(), false, kind, weight
+                )
+            }
+            _ => anyhow::bail!("only similarity and inhibitory connections are bilateral")
+        }
+    }
+
+    fn defining_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] { self.defining_neurons() }
+
+    fn weighting_strategy_name(&self) -> Option<&str> {
+        Some(self.defined_neurons.weighting_strategy.name())
+    }
+
+    fn weighted_connections(&self, kind: ConnectionKind) -> Vec<(Ptr<Lock<dyn Neuron>>, f32)> {
+        match kind {
+            ConnectionKind::Similarity => self.similar_neurons.weighted_neurons(),
+            ConnectionKind::Inhibitory => self.inhibited_neurons.weighted_neurons(),
+            ConnectionKind::Sequential => self.next_neurons.weighted_neurons(),
+            _ => Vec::new()
+        }
+    }
+
+    fn disconnect_from(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        let is_removed = match kind {
+            ConnectionKind::Defining if !is_to_sensor => self.defined_neurons.remove(&to),
+            ConnectionKind::Explanatory if is_to_sensor => self.defining_sensors.remove(&to),
+            ConnectionKind::Explanatory => self.defining_neurons.remove(&to),
+            ConnectionKind::Similarity if !is_to_sensor => self.similar_neurons.remove(&to),
+            ConnectionKind::Inhibitory if !is_to_sensor => self.inhibited_neurons.remove(&to),
+            ConnectionKind::Sequential if !is_to_sensor => self.next_neurons.remove(&to),
+            _ => anyhow::bail!("{:?} connections to sensors don't exist", kind)
+        };
+        if !is_removed { anyhow::bail!("neuron {} has no such {:?} connection", self.id, kind) }
+        Ok(())
+    }
+
+    fn disconnect_bilateral(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()> {
+        match kind {
+            ConnectionKind::Defining if !is_to_sensor => {
+                self.disconnect_from(to.clone(), is_to_sensor, kind)?;
+                to.borrow_mut().disconnect_from(
+                    self.self_ptr.upgrade().unwrap(), false, ConnectionKind::Explanatory
+                )
+            }
+            ConnectionKind::Similarity | ConnectionKind::Inhibitory if !is_to_sensor => {
+                self.disconnect_from(to.clone(), is_to_sensor, kind)?;
+                to.borrow_mut().disconnect_from(self.self_ptr.upgrade().unwrap(), false, kind)
+            }
+            _ => anyhow::bail!("{:?} connection can't be removed bilaterally", kind)
+        }
+    }
+}
+
+impl Display for SimpleNeuron {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f, "[{}|c:{}|a:{}]",
+            self.id(), 
+            self.counter(), 
+            self.activation()
+        )
+    }
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use witchnet_common::{
+            neuron::{ NeuronID, ActivationContext },
+            connection::ConnectionKind
+        };
+
+        use super::{
+            SimpleNeuron,
+            super::super::{
+                common::{ pointer::*, neuron::Neuron },
+                neural::element::Element
+            }
+        };
+
+        #[test]
+        fn as_neuron() {
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2 = SimpleNeuron::new(
+                NeuronID { id: 2, parent_id }
+            );
+
+            let neuron_1_id = neuron_1.borrow().id();
+            assert_eq!(neuron_1_id.id, 1);
+            assert_eq!(neuron_1_id.parent_id, parent_id);
+            let neuron_2_id = neuron_2.borrow().id();
+            assert_eq!(neuron_2_id.id, 2);
+            assert_eq!(neuron_2_id.parent_id, parent_id);
+
+            assert_eq!(neuron_1.borrow().is_sensor(), false);
+
+            assert_eq!(neuron_1.borrow().activation(), 0.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+
+            assert_eq!(neuron_1.borrow().counter(), 1usize);
+
+            neuron_1.borrow_mut().connect_to(
+                neuron_2.clone(), false, ConnectionKind::Defining
+            ).unwrap();
+
+            let max_activation = neuron_1.borrow_mut().activate(1.0f32, true, true);
+            assert!(max_activation > 0.0f32);
+            assert_eq!(neuron_1.borrow().activation(), 1.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 1.0f32);
+
+            neuron_1.borrow_mut().activate(1.0f32, false, true);
+            assert_eq!(neuron_1.borrow().activation(), 2.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 3.0f32);
+            neuron_1.borrow_mut().deactivate(true, true);
+            assert_eq!(neuron_1.borrow().activation(), 0.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+
+            neuron_1.borrow_mut().activate(1.0f32, true, false);
+            assert_eq!(neuron_1.borrow().activation(), 1.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+            neuron_1.borrow_mut().deactivate(false, true);
+            assert_eq!(neuron_1.borrow().activation(), 0.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+
+            neuron_1.borrow_mut().activate(1.0f32, false, false);
+            assert_eq!(neuron_1.borrow().activation(), 1.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+            neuron_1.borrow_mut().deactivate(true, false);
+            assert_eq!(neuron_1.borrow().activation(), 0.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+
+            neuron_1.borrow_mut().activate(1.0f32, true, true);
+            assert_eq!(neuron_1.borrow().activation(), 1.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 1.0f32);
+            neuron_1.borrow_mut().deactivate(false, false);
+            assert_eq!(neuron_1.borrow().activation(), 0.0f32);
+            assert_eq!(neuron_2.borrow().activation(), 1.0f32);
+
+            let neuron_1_borrowed_mut = neuron_1.borrow_mut();
+            let exp_1 = neuron_1_borrowed_mut.explain();
+            assert_eq!(exp_1.len(), 0);
+        }
+
+        #[test]
+        fn connect_to_neuron() {
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2 = SimpleNeuron::new(
+                NeuronID { id: 2, parent_id }
+            );
+
+            let connection_1 = neuron_1.borrow_mut().connect_to(
+                neuron_2.clone(), false, ConnectionKind::Defining
+            );
+            assert!(connection_1.is_ok());
+            assert_eq!(neuron_1.borrow().defined_neurons().len(), 1);
+            assert_eq!(neuron_1.borrow().defining_neurons().len(), 0);
+            assert_eq!(neuron_1.borrow().defining_sensors().len(), 0);
+            assert_eq!(neuron_2.borrow().defined_neurons().len(), 0);
+            assert_eq!(neuron_2.borrow().defining_neurons().len(), 0);
+            assert_eq!(neuron_2.borrow().defining_sensors().len(), 0);
+            connection_1.unwrap();
+        }
+
+        #[test]
+        fn connect_to_sensor() {
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2: Ptr<Lock<Element<i32, 3>>> = Element::new(&1, 1, 1);
+
+            let connection_1 = neuron_1.borrow_mut().connect_to(
+                neuron_2.clone(), true, ConnectionKind::Defining
+            );
+            assert!(connection_1.is_err());
+            assert_eq!(neuron_1.borrow().defined_neurons().len(), 0);
+            assert_eq!(neuron_1.borrow().defining_neurons().len(), 0);
+            assert_eq!(neuron_1.borrow().defining_sensors().len(), 0);
+            assert_eq!(neuron_2.borrow().defining_neurons().len(), 0);
+        }
+
+        #[test]
+        fn connect_bilateral_to_sensor() {
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2: Ptr<Lock<Element<i32, 3>>> = Element::new(&1, 1, 1);
+
+            let connection_1 = neuron_1.borrow_mut().connect_bilateral(
+                neuron_2.clone(), true, ConnectionKind::Defining
+            );
+            assert!(connection_1.is_err());
+            assert_eq!(neuron_1.borrow().defined_neurons().len(), 0);
+            assert_eq!(neuron_1.borrow().defining_neurons().len(), 0);
+            assert_eq!(neuron_1.borrow().defining_sensors().len(), 0);
+            assert_eq!(neuron_2.borrow().defining_neurons().len(), 0);
+        }
+
+        #[test]
+        fn connect_bilateral_to_neuron() {
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2 = SimpleNeuron::new(
+                NeuronID { id: 2, parent_id }
+            );
+
+            neuron_1.borrow_mut().connect_bilateral(
+                neuron_2.clone(), false, ConnectionKind::Defining
+            ).unwrap();
+            assert_eq!(neuron_1.borrow().defined_neurons().len(), 1);
+            assert_eq!(neuron_1.borrow().defining_neurons().len(), 0);
+            assert_eq!(neuron_1.borrow().defining_sensors().len(), 0);
+            assert_eq!(neuron_2.borrow().defined_neurons().len(), 0);
+            assert_eq!(neuron_2.borrow().defining_neurons().len(), 1);
+            assert_eq!(neuron_2.borrow().defining_sensors().len(), 0);
+        }
+
+        #[test]
+        fn connect_wrong() {
+            let parent_id = 1u32;
+            let neuron_1 = SimpleNeuron::new(
+                NeuronID { id: 1, parent_id }
+            );
+            let neuron_2 = SimpleNeuron::new(
+                NeuronID { id: 2, parent_id }
+            );
+            let sensor: Ptr<Lock<Element<i32, 3>>> = Element::new(&1, 1, 1);
+
+            let connection_1 = neuron_1.borrow_mut().connect_bilateral(
+                neuron_2.clone(), false, ConnectionKind::Sequential
+            );
+            assert!(connection_1.is_err());
+
+            let connection_1 = neuron_1.borrow_mut().connect_bilateral(
+                sensor.clone(), true, ConnectionKind::Defining
+            );
+            assert!(connection_1.is_err());
+
+            let connection_1 = neuron_1.borrow_mut().connect_bilateral(
+                sensor.clone(), true, ConnectionKind::Explanatory
+            );
+            assert!(connection_1.is_err());
+
+            let connection_1 = neuron_1.borrow_mut().connect_to_weighted(
+                neuron_2.clone(), false, ConnectionKind::Defining, 0.5
+            );
+            assert!(connection_1.is_err());
+
+            let connection_1 = neuron_1.borrow_mut().connect_to(
+                neuron_1.clone(), false, ConnectionKind::Similarity
+            );
+            assert!(connection_1.is_err());
+
+            let connection_1 = neuron_1.borrow_mut().connect_to(
+                sensor.clone(), true, ConnectionKind::Inhibitory
+            );
+            assert!(connection_1.is_err());
+        }
+
+        #[test]
+        fn lateral_connections() {
+            let neuron_1 = SimpleNeuron::new(NeuronID { id: 1, parent_id: 1 });
+            let neuron_2 = SimpleNeuron::new(NeuronID { id: 2, parent_id: 1 });
+            let neuron_3 = SimpleNeuron::new(NeuronID { id: 3, parent_id: 1 });
+            let neuron_4 = SimpleNeuron::new(NeuronID { id: 4, parent_id: 1 });
+
+            neuron_1.borrow_mut().connect_bilateral_weighted(
+                neuron_2.clone(), false, ConnectionKind::Similarity, 0.5
+            ).unwrap();
+            neuron_1.borrow_mut().connect_bilateral(
+                neuron_3.clone(), false, ConnectionKind::Inhibitory
+            ).unwrap();
+            neuron_1.borrow_mut().connect_bilateral_weighted(
+                neuron_3.clone(), false, ConnectionKind::Inhibitory, 0.25
+            ).unwrap();
+            neuron_1.borrow_mut().connect_to_weighted(
+                neuron_4.clone(), false, ConnectionKind::Sequential, 2.0
+            ).unwrap();
+            assert_eq!(neuron_1.borrow().weighted_connections(ConnectionKind::Inhibitory).len(), 1);
+            assert_eq!(neuron_3.borrow().weighted_connections(ConnectionKind::Inhibitory)[0].1, 0.25);
+            assert!(neuron_4.borrow().weighted_connections(ConnectionKind::Sequential).is_empty());
+
+            neuron_1.borrow_mut().activate(1.0f32, true, true);
+            assert_eq!(neuron_2.borrow().activation(), 0.5f32);
+            assert_eq!(neuron_3.borrow().activation(), -0.25f32);
+            assert_eq!(neuron_4.borrow().activation(), 2.0f32);
+            neuron_1.borrow_mut().activate(1.0f32, false, true);
+            assert_eq!(neuron_2.borrow().activation(), 0.5f32);
+            neuron_1.borrow_mut().deactivate(true, true);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+            assert_eq!(neuron_4.borrow().activation(), 0.0f32);
+
+            let neuron_5 = SimpleNeuron::new(NeuronID { id: 5, parent_id: 1 });
+            let neuron_6 = SimpleNeuron::new(NeuronID { id: 6, parent_id: 1 });
+            let defining = ConnectionKind::Defining;
+            neuron_4.borrow_mut().connect_bilateral(neuron_5.clone(), false, defining).unwrap();
+            neuron_5.borrow_mut().connect_bilateral(neuron_6.clone(), false, defining).unwrap();
+            for kind in [ConnectionKind::Similarity, ConnectionKind::Sequential] {
+                let connection = neuron_6.borrow_mut().connect_to_weighted(
+                    neuron_4.clone(), false, kind, 0.5
+                );
+                assert!(connection.is_err());
+            }
+            assert!(
+                neuron_4.borrow_mut().connect_bilateral_weighted(
+                    neuron_6.clone(), false, ConnectionKind::Inhibitory, 0.5
+                ).is_err()
+            );
+            assert!(neuron_6.borrow().weighted_connections(ConnectionKind::Inhibitory).is_empty());
+            neuron_4.borrow_mut().connect_to_weighted(
+                neuron_6.clone(), false, ConnectionKind::Sequential, 0.5
+            ).unwrap();
+
+            let mut context = ActivationContext::new();
+            neuron_2.borrow().activate_in(1.0f32, true, true, &mut context);
+            neuron_1.borrow().activate_in(2.0f32, true, true, &mut context);
+            assert_eq!(neuron_1.borrow().activation_in(&context), 2.5f32);
+            assert_eq!(neuron_2.borrow().activation_in(&context), 2.0f32);
+            assert_eq!(neuron_3.borrow().activation_in(&context), -0.5f32);
+            assert_eq!(neuron_2.borrow().activation(), 0.0f32);
+
+            neuron_2.borrow_mut().disconnect_bilateral(
+                neuron_1.clone(), false, ConnectionKind::Similarity
+            ).unwrap();
+            assert!(neuron_1.borrow().weighted_connections(ConnectionKind::Similarity).is_empty());
+            assert!(neuron_1.borrow_mut().disconnect_bilateral(
+                neuron_4.clone(), false, ConnectionKind::Sequential
+            ).is_err());
+            neuron_1.borrow_mut().disconnect_from(
+                neuron_4.clone(), false, ConnectionKind::Sequential
+            ).unwrap();
+            assert!(neuron_1.borrow().weighted_connections(ConnectionKind::Sequential).is_empty());
+        }
+    }
+}
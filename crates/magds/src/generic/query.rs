@@ -0,0 +1,245 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use witchnet_common::{ data::DataTypeValue, neuron::ActivationContext };
+
+use crate::query::{ Query, Select, Predicate, OrderBy, Order, Record };
+
+use super::common::pointer::*;
+
+use super::{
+    common::{ neuron::Neuron, sensor::Sensor },
+    magds::MAGDS
+};
+
+pub fn query(magds: &MAGDS, query: &str) -> Result<Vec<Record>> {
+    execute(magds, &Query::parse(query)?)
+}
+
+pub fn execute(magds: &MAGDS, query: &Query) -> Result<Vec<Record>> {
+    let group_ids = match magds.neuron_group_ids_from_name(&query.from) {
+        Some(ids) => ids.to_vec(),
+        None => anyhow::bail!("unknown neuron group {}", query.from)
+    };
+
+    let mut conditions: Vec<(Vec<u32>, Predicate<DataTypeValue>)> = Vec::new();
+    for condition in &query.conditions {
+        let sensor_ids = sensor_ids(magds, &condition.feature)?;
+        let data_type = magds.sensor_data_type(sensor_ids[0]).unwrap();
+        let predicate = condition.predicate.try_map(|x| x.to_value(data_type))?;
+        conditions.push((sensor_ids, predicate));
+    }
+
+    let mut context = ActivationContext::new();
+    for (sensor_ids, predicate) in &conditions {
+        for id in sensor_ids {
+            let sensor = magds.sensor(*id).unwrap();
+            let mut elements: Vec<Ptr<Lock<dyn Neuron>>> = sensor.borrow().neurons();
+            if let Some(null_element) = magds.sensor_null_element(*id) {
+                elements.push(null_element);
+            }
+            for element in elements {
+                if !predicate.matches(&element.borrow().value()) { continue }
+                element.borrow().activate_in(1.0f32, false, true, &mut context);
+            }
+        }
+    }
+
+    let order_sensor_ids = match &query.order_by {
+        Some(OrderBy::Feature(feature, _)) => Some(sensor_ids(magds, feature)?),
+        _ => None
+    };
+
+    let mut keyed: Vec<(DataTypeValue, Record)> = Vec::new();
+    for neuron in magds.neurons() {
+        let neuron = neuron.borrow();
+        if !group_ids.contains(&neuron.id().parent_id) { continue }
+        let is_matching = conditions.iter().all(|(sensor_ids, predicate)| {
+            predicate.matches_values(&feature_values(&*neuron, sensor_ids))
+        });
+        if !is_matching { continue }
+
+        let values = match &query.select {
+            Select::All => {
+                let mut ids: Vec<u32> = neuron.explain().iter()
+                    .map(|x| x.borrow().id().parent_id)
+                    .collect();
+                ids.sort_unstable();
+                let mut names: Vec<&str> = Vec::new();
+                for id in ids {
+                    let name = magds.sensor_name(id).unwrap_or_default();
+                    if !names.contains(&name) { names.push(name) }
+                }
+                let mut values = Vec::new();
+                for name in names {
+                    let sensor_ids = sensor_ids(magds, name)?;
+                    values.push((name.to_string(), feature_value(magds, &*neuron, &sensor_ids)));
+                }
+                values
+            }
+            Select::Features(features) => {
+                let mut values = Vec::new();
+                for feature in features {
+                    let sensor_ids = sensor_ids(magds, feature)?;
+                    values.push((feature.clone(), feature_value(magds, &*neuron, &sensor_ids)));
+                }
+                values
+            }
+        };
+
+        let key = match &order_sensor_ids {
+            Some(sensor_ids) => feature_value(magds, &*neuron, sensor_ids),
+            None => DataTypeValue::Null
+        };
+        let record = Record { id: neuron.id(), activation: neuron.activation_in(&context), values };
+        keyed.push((key, record));
+    }
+
+    match &query.order_by {
+        Some(OrderBy::Activation(order)) => keyed.sort_by(|(_, a), (_, b)| {
+            ordered(a.activation.partial_cmp(&b.activation).unwrap_or(Ordering::Equal), *order)
+        }),
+        Some(OrderBy::Feature(_, order)) => keyed.sort_by(|(a, _), (b, _)| {
+            match (a.is_null(), b.is_null()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                _ => ordered(a.partial_cmp(b).unwrap_or(Ordering::Equal), *order)
+            }
+        }),
+        None => {}
+    }
+
+    let mut records: Vec<Record> = keyed.into_iter().map(|(_, record)| record).collect();
+    if let Some(limit) = query.limit { records.truncate(limit); }
+
+    Ok(records)
+}
+
+fn sensor_ids(magds: &MAGDS, feature: &str) -> Result<Vec<u32>> {
+    match magds.sensor_ids(feature) {
+        Some(ids) if !ids.is_empty() => Ok(ids.to_vec()),
+        _ => anyhow::bail!("unknown feature {feature}")
+    }
+}
+
+/// Values the neuron connects to in the feature sensors, the tokens of the tokenized sensors
+fn feature_values(neuron: &dyn Neuron, sensor_ids: &[u32]) -> Vec<DataTypeValue> {
+    neuron.explain().iter()
+        .map(|x| x.borrow())
+        .filter(|x| sensor_ids.contains(&x.id().parent_id))
+        .map(|x| x.value())
+        .collect()
+}
+
+/// Value of the feature, with the tokens of the tokenized sensors joined back into a text
+fn feature_value(magds: &MAGDS, neuron: &dyn Neuron, sensor_ids: &[u32]) -> DataTypeValue {
+    let values = feature_values(neuron, sensor_ids);
+    match sensor_ids.iter().find_map(|id| magds.sensor_tokenizer(*id)) {
+        Some(tokenizer) if values.iter().any(|x| !x.is_null()) => {
+            let tokens: Vec<String> = values.iter()
+                .filter(|x| !x.is_null())
+                .map(|x| x.to_string())
+                .collect();
+            DataTypeValue::ArcStr(tokenizer.join(&tokens).into())
+        }
+        _ => values.into_iter().next().unwrap_or(DataTypeValue::Null)
+    }
+}
+
+fn ordered(ordering: Ordering, order: Order) -> Ordering {
+    match order {
+        Order::Asc => ordering,
+        Order::Desc => ordering.reverse()
+    }
+}
+
+generic_tests! {
+    #[cfg(test)]
+    mod tests {
+        use witchnet_common::{ data::DataTypeValue, tokenizer::Tokenizer };
+
+        use super::super::parser;
+
+        #[test]
+        fn query() {
+            let file = "data/iris_original_train.csv";
+            let magds = parser::magds_from_csv("iris", file, &vec![]).unwrap();
+
+            let records = super::query(
+                &magds,
+                "SELECT object FROM iris WHERE petal.length BETWEEN 1.0 AND 1.4 \
+                AND variety = 'setosa' ORDER BY activation LIMIT 5"
+            ).unwrap();
+            assert!(!records.is_empty() && records.len() <= 5);
+            for record in &records {
+                assert_eq!(record.values.len(), 5);
+                let petal_length = record.values.iter().find(|x| x.0 == "petal.length").unwrap();
+                let petal_length = *petal_length.1.as_f64().unwrap();
+                assert!((1.0..=1.4).contains(&petal_length));
+                let variety = &record.values.iter().find(|x| x.0 == "variety").unwrap().1;
+                assert_eq!(variety.to_string(), "setosa");
+            }
+            for pair in records.windows(2) { assert!(pair[0].activation >= pair[1].activation); }
+            assert!(magds.neurons().iter().all(|x| x.borrow().activation() == 0.0));
+
+            let records = super::query(
+                &magds,
+                "SELECT sepal.length FROM iris WHERE variety IN ('versicolor', 'virginica') \
+                ORDER BY sepal.length DESC"
+            ).unwrap();
+            assert!(!records.is_empty());
+            for pair in records.windows(2) { assert!(pair[0].values[0].1 >= pair[1].values[0].1); }
+
+            let all = super::query(&magds, "SELECT * FROM iris").unwrap();
+            assert_eq!(all.len(), magds.neurons().len());
+
+            assert!(super::query(&magds, "SELECT * FROM cars").is_err());
+            assert!(super::query(&magds, "SELECT * FROM iris WHERE price > 1").is_err());
+            assert!(super::query(&magds, "SELECT * FROM iris WHERE petal.width > 'x'").is_err());
+        }
+
+        #[test]
+        fn query_tokenized() {
+            let magds = parser::magds_from_csv_tokenized(
+                "cars", "data/equipment.csv", &vec![], &[("equipment", Tokenizer::list())]
+            ).unwrap();
+
+            let brands = |query: &str| -> Vec<String> {
+                super::query(&magds, query).unwrap()
+                    .into_iter()
+                    .map(|x| x.values[0].1.to_string())
+                    .collect()
+            };
+            assert_eq!(
+                brands("SELECT brand FROM cars WHERE equipment = 'navigation' ORDER BY brand"),
+                vec!["audi", "bmw", "skoda"]
+            );
+            assert_eq!(
+                brands("SELECT brand FROM cars WHERE equipment != 'radio' ORDER BY brand"),
+                vec!["audi", "bmw"]
+            );
+            assert!(brands("SELECT brand FROM cars WHERE equipment IS NULL").is_empty());
+
+            let records = super::query(&magds, "SELECT * FROM cars WHERE brand = 'audi'").unwrap();
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].values.len(), 3);
+            let equipment = &records[0].values.iter().find(|x| x.0 == "equipment").unwrap().1;
+            assert_eq!(equipment.to_string(), "leather, sunroof, navigation");
+        }
+
+        #[test]
+        fn query_nullable() {
+            let file = "data/missing.csv";
+            let magds = parser::magds_from_csv_nullable("missing", file, &vec![]).unwrap();
+
+            let records = super::query(&magds, "SELECT a, b FROM missing WHERE a IS NULL").unwrap();
+            assert_eq!(records.len(), 2);
+            assert!(records.iter().all(|x| x.values[0].1 == DataTypeValue::Null));
+
+            let records = super::query(&magds, "SELECT target FROM missing WHERE a >= 2").unwrap();
+            assert_eq!(records.len(), 2);
+        }
+    }
+}
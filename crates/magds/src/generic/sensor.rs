@@ -1,8 +1,6 @@
 use std::{
     fmt::{ Display, Formatter, Result as FmtResult },
-    rc::Rc,
-    sync::Arc,
-    cell::RefCell
+    sync::Arc
 };
 
 use anyhow::Result;
@@ -10,10 +8,16 @@ use anyhow::Result;
 use enum_as_inner::EnumAsInner;
 
 use witchnet_common::{
+    data::{ DataType, DataTypeValue, DataCategory },
+    neuron::ActivationContext,
+    temporal::{ Date, DateTime, Duration }
+};
+
+use super::common::{
+    pointer::*,
     neuron::Neuron,
     sensor::Sensor,
-    data::{ DataType, DataTypeValue, DataCategory }, 
-    connection::collective::defining::DefiningWeightingStrategy
+    collective::defining::DefiningWeightingStrategy
 };
 
 #[derive(EnumAsInner, Debug)]
@@ -33,6 +37,9 @@ pub enum SensorConatiner {
     ISize(Box<dyn Sensor<isize>>),
     F32(Box<dyn Sensor<f32>>),
     F64(Box<dyn Sensor<f64>>),
+    Date(Box<dyn Sensor<Date>>),
+    DateTime(Box<dyn Sensor<DateTime>>),
+    Duration(Box<dyn Sensor<Duration>>),
     ArcStr(Box<dyn Sensor<Arc<str>>>),
     String(Box<dyn Sensor<String>>)
 }
@@ -55,6 +62,9 @@ impl Display for SensorConatiner {
             SensorConatiner::ISize(v) => write!(f, "{v}"),
             SensorConatiner::F32(v) => write!(f, "{v}"),
             SensorConatiner::F64(v) => write!(f, "{v}"),
+            SensorConatiner::Date(v) => write!(f, "{v}"),
+            SensorConatiner::DateTime(v) => write!(f, "{v}"),
+            SensorConatiner::Duration(v) => write!(f, "{v}"),
             SensorConatiner::ArcStr(v) => write!(f, "{v}"),
             SensorConatiner::String(v) => write!(f, "{v}"),
         }
@@ -79,6 +89,9 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::ISize(v) => v.id(),
             SensorConatiner::F32(v) => v.id(),
             SensorConatiner::F64(v) => v.id(),
+            SensorConatiner::Date(v) => v.id(),
+            SensorConatiner::DateTime(v) => v.id(),
+            SensorConatiner::Duration(v) => v.id(),
             SensorConatiner::ArcStr(v) => v.id(),
             SensorConatiner::String(v) => v.id()
         }
@@ -101,6 +114,9 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::ISize(v) => v.data_type(),
             SensorConatiner::F32(v) => v.data_type(),
             SensorConatiner::F64(v) => v.data_type(),
+            SensorConatiner::Date(v) => v.data_type(),
+            SensorConatiner::DateTime(v) => v.data_type(),
+            SensorConatiner::Duration(v) => v.data_type(),
             SensorConatiner::ArcStr(v) => v.data_type(),
             SensorConatiner::String(v) => v.data_type()
         }
@@ -123,12 +139,15 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::ISize(v) => v.data_category(),
             SensorConatiner::F32(v) => v.data_category(),
             SensorConatiner::F64(v) => v.data_category(),
+            SensorConatiner::Date(v) => v.data_category(),
+            SensorConatiner::DateTime(v) => v.data_category(),
+            SensorConatiner::Duration(v) => v.data_category(),
             SensorConatiner::ArcStr(v) => v.data_category(),
             SensorConatiner::String(v) => v.data_category()
         }
     }
 
-    fn insert(&mut self, item: &DataTypeValue) -> Rc<RefCell<dyn Neuron>> {
+    fn insert(&mut self, item: &DataTypeValue) -> Ptr<Lock<dyn Neuron>> {
         match self {
             SensorConatiner::Bool(v) => {
                 v.insert(item.as_bool().unwrap())
@@ -175,6 +194,15 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::F64(v) => {
                 v.insert(item.as_f64().unwrap())
             },
+            SensorConatiner::Date(v) => {
+                v.insert(item.as_date().unwrap())
+            },
+            SensorConatiner::DateTime(v) => {
+                v.insert(item.as_date_time().unwrap())
+            },
+            SensorConatiner::Duration(v) => {
+                v.insert(item.as_duration().unwrap())
+            },
             SensorConatiner::ArcStr(v) => {
                 v.insert(item.as_arc_str().unwrap())
             },
@@ -187,10 +215,10 @@ impl Sensor<DataTypeValue> for SensorConatiner {
     fn insert_custom(
         &mut self, 
         item: &DataTypeValue, 
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
         interelement_activation_threshold: f32,
         interelement_activation_exponent: i32
-    ) -> Rc<RefCell<dyn Neuron>> {
+    ) -> Ptr<Lock<dyn Neuron>> {
         match self {
             SensorConatiner::Bool(v) => {
                 v.insert_custom(
@@ -312,6 +340,30 @@ impl Sensor<DataTypeValue> for SensorConatiner {
                     interelement_activation_exponent
                 )
             },
+            SensorConatiner::Date(v) => {
+                v.insert_custom(
+                    item.as_date().unwrap(), 
+                    weighting_strategy,
+                    interelement_activation_threshold,
+                    interelement_activation_exponent
+                )
+            },
+            SensorConatiner::DateTime(v) => {
+                v.insert_custom(
+                    item.as_date_time().unwrap(), 
+                    weighting_strategy,
+                    interelement_activation_threshold,
+                    interelement_activation_exponent
+                )
+            },
+            SensorConatiner::Duration(v) => {
+                v.insert_custom(
+                    item.as_duration().unwrap(), 
+                    weighting_strategy,
+                    interelement_activation_threshold,
+                    interelement_activation_exponent
+                )
+            },
             SensorConatiner::ArcStr(v) => {
                 v.insert_custom(
                     item.as_arc_str().unwrap(), 
@@ -333,114 +385,132 @@ impl Sensor<DataTypeValue> for SensorConatiner {
 
     fn fuzzy_search(
         &mut self, item: &DataTypeValue, threshold: f32, perserve_inserted_neuron: bool
-    ) -> Option<(Rc<RefCell<dyn Neuron>>, f32)> {
+    ) -> Option<(Ptr<Lock<dyn Neuron>>, f32)> {
         match self {
             SensorConatiner::Bool(v) => {
                 match v.fuzzy_search(item.as_bool()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::U8(v) => {
                 match v.fuzzy_search(item.as_u8()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::U16(v) => {
                 match v.fuzzy_search(item.as_u16()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::U32(v) => {
                 match v.fuzzy_search(item.as_u32()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::U64(v) => {
                 match v.fuzzy_search(item.as_u64()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::U128(v) => {
                 match v.fuzzy_search(item.as_u128()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::USize(v) => {
                 match v.fuzzy_search(item.as_u_size()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::I8(v) => {
                 match v.fuzzy_search(item.as_i8()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::I16(v) => {
                 match v.fuzzy_search(item.as_i16()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::I32(v) => {
                 match v.fuzzy_search(item.as_i32()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::I64(v) => {
                 match v.fuzzy_search(item.as_i64()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::I128(v) => {
                 match v.fuzzy_search(item.as_i128()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::ISize(v) => {
                 match v.fuzzy_search(item.as_i_size()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::F32(v) => {
                 match v.fuzzy_search(item.as_f32()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::F64(v) => {
                 match v.fuzzy_search(item.as_f64()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
+                    None => None
+                }
+            },
+            SensorConatiner::Date(v) => {
+                match v.fuzzy_search(item.as_date()?, threshold, perserve_inserted_neuron) {
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
+                    None => None
+                }
+            },
+            SensorConatiner::DateTime(v) => {
+                match v.fuzzy_search(item.as_date_time()?, threshold, perserve_inserted_neuron) {
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
+                    None => None
+                }
+            },
+            SensorConatiner::Duration(v) => {
+                match v.fuzzy_search(item.as_duration()?, threshold, perserve_inserted_neuron) {
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::ArcStr(v) => {
                 match v.fuzzy_search(item.as_arc_str()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             },
             SensorConatiner::String(v) => {
                 match v.fuzzy_search(item.as_string()?, threshold, perserve_inserted_neuron) {
-                    Some(n) => Some((n.0 as Rc<RefCell<dyn Neuron>>, n.1)),
+                    Some(n) => Some((n.0 as Ptr<Lock<dyn Neuron>>, n.1)),
                     None => None
                 }
             }
         }
     }
 
-    fn search(&self, item: &DataTypeValue) -> Option<Rc<RefCell<dyn Neuron>>> {
+    fn search(&self, item: &DataTypeValue) -> Option<Ptr<Lock<dyn Neuron>>> {
         match self {
             SensorConatiner::Bool(v) => {
                 v.search(item.as_bool()?)
@@ -487,6 +557,15 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::F64(v) => {
                 v.search(item.as_f64()?)
             },
+            SensorConatiner::Date(v) => {
+                v.search(item.as_date()?)
+            },
+            SensorConatiner::DateTime(v) => {
+                v.search(item.as_date_time()?)
+            },
+            SensorConatiner::Duration(v) => {
+                v.search(item.as_duration()?)
+            },
             SensorConatiner::ArcStr(v) => {
                 v.search(item.as_arc_str()?)
             },
@@ -543,6 +622,15 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::F64(v) => {
                 v.remove(item.as_f64().unwrap())
             },
+            SensorConatiner::Date(v) => {
+                v.remove(item.as_date().unwrap())
+            },
+            SensorConatiner::DateTime(v) => {
+                v.remove(item.as_date_time().unwrap())
+            },
+            SensorConatiner::Duration(v) => {
+                v.remove(item.as_duration().unwrap())
+            },
             SensorConatiner::ArcStr(v) => {
                 v.remove(item.as_arc_str().unwrap())
             },
@@ -635,6 +723,21 @@ impl Sensor<DataTypeValue> for SensorConatiner {
                     item.as_f64().unwrap(), signal, propagate_horizontal, propagate_vertical
                 )
             },
+            SensorConatiner::Date(v) => {
+                v.activate(
+                    item.as_date().unwrap(), signal, propagate_horizontal, propagate_vertical
+                )
+            },
+            SensorConatiner::DateTime(v) => {
+                v.activate(
+                    item.as_date_time().unwrap(), signal, propagate_horizontal, propagate_vertical
+                )
+            },
+            SensorConatiner::Duration(v) => {
+                v.activate(
+                    item.as_duration().unwrap(), signal, propagate_horizontal, propagate_vertical
+                )
+            },
             SensorConatiner::ArcStr(v) => {
                 v.activate(
                     item.as_arc_str().unwrap(), signal, propagate_horizontal, propagate_vertical
@@ -648,6 +751,118 @@ impl Sensor<DataTypeValue> for SensorConatiner {
         }
     }
 
+    fn activate_in(
+        &self, 
+        item: &DataTypeValue, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool,
+        context: &mut ActivationContext
+    ) -> Result<f32> {
+        match self {
+            SensorConatiner::Bool(v) => {
+                v.activate_in(
+                    item.as_bool().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::U8(v) => {
+                v.activate_in(
+                    item.as_u8().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::U16(v) => {
+                v.activate_in(
+                    item.as_u16().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::U32(v) => {
+                v.activate_in(
+                    item.as_u32().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::U64(v) => {
+                v.activate_in(
+                    item.as_u64().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::U128(v) => {
+                v.activate_in(
+                    item.as_u128().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::USize(v) => {
+                v.activate_in(
+                    item.as_u_size().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::I8(v) => {
+                v.activate_in(
+                    item.as_i8().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::I16(v) => {
+                v.activate_in(
+                    item.as_i16().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::I32(v) => {
+                v.activate_in(
+                    item.as_i32().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::I64(v) => {
+                v.activate_in(
+                    item.as_i64().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::I128(v) => {
+                v.activate_in(
+                    item.as_i128().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::ISize(v) => {
+                v.activate_in(
+                    item.as_i_size().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::F32(v) => {
+                v.activate_in(
+                    item.as_f32().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::F64(v) => {
+                v.activate_in(
+                    item.as_f64().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::Date(v) => {
+                v.activate_in(
+                    item.as_date().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::DateTime(v) => {
+                v.activate_in(
+                    item.as_date_time().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::Duration(v) => {
+                v.activate_in(
+                    item.as_duration().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::ArcStr(v) => {
+                v.activate_in(
+                    item.as_arc_str().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            },
+            SensorConatiner::String(v) => {
+                v.activate_in(
+                    item.as_string().unwrap(), signal, propagate_horizontal, propagate_vertical, context
+                )
+            }
+        }
+    }
+
     fn activate_defining(&mut self, item: &DataTypeValue, signal: f32) -> Result<f32> {
         match self {
             SensorConatiner::Bool(v) => {
@@ -695,6 +910,15 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::F64(v) => {
                 v.activate_defining(item.as_f64().unwrap(), signal)
             },
+            SensorConatiner::Date(v) => {
+                v.activate_defining(item.as_date().unwrap(), signal)
+            },
+            SensorConatiner::DateTime(v) => {
+                v.activate_defining(item.as_date_time().unwrap(), signal)
+            },
+            SensorConatiner::Duration(v) => {
+                v.activate_defining(item.as_duration().unwrap(), signal)
+            },
             SensorConatiner::ArcStr(v) => {
                 v.activate_defining(item.as_arc_str().unwrap(), signal)
             },
@@ -786,6 +1010,21 @@ impl Sensor<DataTypeValue> for SensorConatiner {
                     item.as_f64().unwrap(), propagate_horizontal, propagate_vertical
                 )
             },
+            SensorConatiner::Date(v) => {
+                v.deactivate(
+                    item.as_date().unwrap(), propagate_horizontal, propagate_vertical
+                )
+            },
+            SensorConatiner::DateTime(v) => {
+                v.deactivate(
+                    item.as_date_time().unwrap(), propagate_horizontal, propagate_vertical
+                )
+            },
+            SensorConatiner::Duration(v) => {
+                v.deactivate(
+                    item.as_duration().unwrap(), propagate_horizontal, propagate_vertical
+                )
+            },
             SensorConatiner::ArcStr(v) => {
                 v.deactivate(
                     item.as_arc_str().unwrap(), propagate_horizontal, propagate_vertical
@@ -816,12 +1055,15 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::ISize(v) => v.deactivate_sensor(),
             SensorConatiner::F32(v) => v.deactivate_sensor(),
             SensorConatiner::F64(v) => v.deactivate_sensor(),
+            SensorConatiner::Date(v) => v.deactivate_sensor(),
+            SensorConatiner::DateTime(v) => v.deactivate_sensor(),
+            SensorConatiner::Duration(v) => v.deactivate_sensor(),
             SensorConatiner::ArcStr(v) => v.deactivate_sensor(),
             SensorConatiner::String(v) => v.deactivate_sensor()
         }
     }
 
-    fn neurons(&self) -> Vec<Rc<RefCell<dyn Neuron>>> {
+    fn neurons(&self) -> Vec<Ptr<Lock<dyn Neuron>>> {
         match self {
             SensorConatiner::Bool(v) => v.neurons(),
             SensorConatiner::U8(v) => v.neurons(),
@@ -838,6 +1080,9 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::ISize(v) => v.neurons(),
             SensorConatiner::F32(v) => v.neurons(),
             SensorConatiner::F64(v) => v.neurons(),
+            SensorConatiner::Date(v) => v.neurons(),
+            SensorConatiner::DateTime(v) => v.neurons(),
+            SensorConatiner::Duration(v) => v.neurons(),
             SensorConatiner::ArcStr(v) => v.neurons(),
             SensorConatiner::String(v) => v.neurons()
         }
@@ -860,6 +1105,9 @@ impl Sensor<DataTypeValue> for SensorConatiner {
             SensorConatiner::ISize(v) => v.values().into_iter().map(|x| x.into()).collect(),
             SensorConatiner::F32(v) => v.values().into_iter().map(|x| x.into()).collect(),
             SensorConatiner::F64(v) => v.values().into_iter().map(|x| x.into()).collect(),
+            SensorConatiner::Date(v) => v.values().into_iter().map(|x| x.into()).collect(),
+            SensorConatiner::DateTime(v) => v.values().into_iter().map(|x| x.into()).collect(),
+            SensorConatiner::Duration(v) => v.values().into_iter().map(|x| x.into()).collect(),
             SensorConatiner::ArcStr(v) => v.values().into_iter().map(|x| x.into()).collect(),
             SensorConatiner::String(v) => v.values().into_iter().map(|x| x.into()).collect()
         }
@@ -956,6 +1204,24 @@ impl From<Box<dyn Sensor<f64>>> for SensorConatiner {
     }
 }
 
+impl From<Box<dyn Sensor<Date>>> for SensorConatiner {
+    fn from(sensor: Box<dyn Sensor<Date>>) -> SensorConatiner {
+        SensorConatiner::Date(sensor)
+    }
+}
+
+impl From<Box<dyn Sensor<DateTime>>> for SensorConatiner {
+    fn from(sensor: Box<dyn Sensor<DateTime>>) -> SensorConatiner {
+        SensorConatiner::DateTime(sensor)
+    }
+}
+
+impl From<Box<dyn Sensor<Duration>>> for SensorConatiner {
+    fn from(sensor: Box<dyn Sensor<Duration>>) -> SensorConatiner {
+        SensorConatiner::Duration(sensor)
+    }
+}
+
 impl From<Box<dyn Sensor<Arc<str>>>> for SensorConatiner {
     fn from(sensor: Box<dyn Sensor<Arc<str>>>) -> SensorConatiner {
         SensorConatiner::ArcStr(sensor)
@@ -0,0 +1,292 @@
+use std::{
+    any::Any,
+    marker::PhantomData,
+    collections::HashMap
+};
+
+use anyhow::Result;
+
+use witchnet_common::{
+    neuron::NeuronID,
+    connection::{ ConnectionKind, collective::defining::ConstantOneWeight },
+    sensor::SensorData,
+    data::{ DataDeductor, DataTypeValue, DataType }
+};
+
+use crate::snapshot::{
+    MAGDSSnapshot, SensorSnapshot, ElementSnapshot, NeuronSnapshot, NullElementSnapshot
+};
+
+use super::{
+    common::{
+        pointer::*,
+        neuron::Neuron,
+        sensor::Sensor,
+        collective::defining::{ DefiningWeightingStrategy, weighting_strategy_from_name }
+    },
+    neural::graph::ASAGraph,
+    neuron::{ simple_neuron::SimpleNeuron, null_element::NullElement },
+    magds::{ MAGDS, LATERAL_CONNECTIONS },
+    sensor::SensorConatiner
+};
+
+pub(crate) fn magds_to_snapshot(magds: &MAGDS) -> Result<MAGDSSnapshot> {
+    let mut snapshot = MAGDSSnapshot::default();
+
+    let mut sensor_ids: Vec<u32> = magds.sensors.keys().cloned().collect();
+    sensor_ids.sort();
+    for id in sensor_ids {
+        let sensor = magds.sensors[&id].borrow();
+        let (elements_counter, elements) = match &*sensor {
+            SensorConatiner::Bool(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::U8(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::U16(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::U32(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::U64(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::U128(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::USize(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::I8(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::I16(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::I32(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::I64(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::I128(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::ISize(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::F32(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::F64(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::ArcStr(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::String(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::Date(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::DateTime(v) => elements_snapshot(v.as_ref())?,
+            SensorConatiner::Duration(v) => elements_snapshot(v.as_ref())?
+        };
+        snapshot.sensors.push(SensorSnapshot {
+            id,
+            name: magds.sensor_name(id).unwrap_or_default().to_string(),
+            data_type: sensor.data_type(),
+            elements_counter,
+            elements,
+            null_element: magds.null_elements.get(&id).map(|null_element| {
+                let null_element = null_element.borrow();
+                NullElementSnapshot {
+                    counter: null_element.counter,
+                    weighting_strategy: null_element.weighting_strategy_name().to_string()
+                }
+            }),
+            tokenizer: magds.tokenizers.get(&id).cloned()
+        });
+    }
+
+    let mut neuron_group_ids: Vec<u32> = magds.neuron_group_names.keys().cloned().collect();
+    neuron_group_ids.sort();
+    for id in neuron_group_ids {
+        snapshot.neuron_groups.push((id, magds.neuron_group_names[&id].to_string()));
+    }
+
+    for neuron in &magds.neurons {
+        let neuron = neuron.borrow();
+        snapshot.neurons.push(NeuronSnapshot {
+            id: neuron.id(),
+            counter: neuron.counter(),
+            weighting_strategy: neuron.weighting_strategy_name()
+                .unwrap_or("ConstantOneWeight")
+                .to_string(),
+            defining_sensors: neuron.explain().iter().map(|x| x.borrow().id()).collect(),
+            defined_neurons: neuron.defined_neurons().iter().map(|x| x.borrow().id()).collect(),
+            weighted_connections: LATERAL_CONNECTIONS.iter()
+                .flat_map(|kind| neuron.weighted_connections(*kind)
+                    .into_iter()
+                    .map(|(x, weight)| (*kind, x.borrow().id(), weight))
+                )
+                .collect()
+        });
+    }
+
+    Ok(snapshot)
+}
+
+pub(crate) fn magds_from_snapshot(snapshot: &MAGDSSnapshot) -> Result<MAGDS> {
+    let mut magds = MAGDS::new();
+    let mut elements: HashMap<NeuronID, Ptr<Lock<dyn Neuron>>> = HashMap::new();
+
+    for sensor in &snapshot.sensors {
+        let container = match sensor.data_type {
+            DataType::Bool => SensorConatiner::Bool(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::U8 => SensorConatiner::U8(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::U16 => SensorConatiner::U16(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::U32 => SensorConatiner::U32(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::U64 => SensorConatiner::U64(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::U128 => SensorConatiner::U128(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::USize => SensorConatiner::USize(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::I8 => SensorConatiner::I8(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::I16 => SensorConatiner::I16(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::I32 => SensorConatiner::I32(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::I64 => SensorConatiner::I64(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::I128 => SensorConatiner::I128(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::ISize => SensorConatiner::ISize(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::F32 => SensorConatiner::F32(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::F64 => SensorConatiner::F64(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::ArcStr => SensorConatiner::ArcStr(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::String => SensorConatiner::String(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::Date => SensorConatiner::Date(graph_from_snapshot(sensor, &mut elements)?),
+            DataType::DateTime => {
+                SensorConatiner::DateTime(graph_from_snapshot(sensor, &mut elements)?)
+            }
+            DataType::Duration => {
+                SensorConatiner::Duration(graph_from_snapshot(sensor, &mut elements)?)
+            }
+            DataType::Unknown => anyhow::bail!("unknown data type sensor {} is not allowed", sensor.name)
+        };
+
+        let name: Ptr<str> = Ptr::from(sensor.name.as_str());
+        magds.sensors.insert(sensor.id, Ptr::new(Lock::new(container)));
+        if let Some(null_element_snapshot) = &sensor.null_element {
+            let null_element = NullElement::new(
+                sensor.id,
+                sensor.data_type,
+                weighting_strategy(&null_element_snapshot.weighting_strategy)
+            );
+            null_element.borrow_mut().counter = null_element_snapshot.counter;
+            let null_element_id = NeuronID::new(NullElement::ID, sensor.id);
+            elements.insert(null_element_id, null_element.clone() as Ptr<Lock<dyn Neuron>>);
+            magds.null_elements.insert(sensor.id, null_element);
+        }
+        if let Some(tokenizer) = &sensor.tokenizer {
+            magds.tokenizers.insert(sensor.id, tokenizer.clone());
+        }
+        magds.sensor_names.insert(sensor.id, name.clone());
+        match magds.sensor_ids.get_mut(&name) {
+            Some(v) => v.push(sensor.id),
+            None => { magds.sensor_ids.insert(name, vec![sensor.id]); }
+        }
+    }
+
+    for (id, name) in &snapshot.neuron_groups { magds.add_neuron_group(name, Some(*id)); }
+
+    let mut neurons: HashMap<NeuronID, Ptr<Lock<dyn Neuron>>> = HashMap::new();
+    for neuron_snapshot in &snapshot.neurons {
+        let neuron = SimpleNeuron::new_custom(
+            neuron_snapshot.id.clone(), weighting_strategy(&neuron_snapshot.weighting_strategy)
+        );
+        neuron.borrow_mut().counter = neuron_snapshot.counter;
+
+        for element_id in &neuron_snapshot.defining_sensors {
+            let element = match elements.get(element_id) {
+                Some(element) => element,
+                None => anyhow::bail!("neuron {} refers to missing element {element_id}", neuron_snapshot.id)
+            };
+            element.borrow_mut().connect_bilateral(neuron.clone(), false, ConnectionKind::Defining)?;
+        }
+
+        neurons.insert(neuron_snapshot.id.clone(), neuron.clone());
+        magds.add_neuron(neuron);
+    }
+
+    for neuron_snapshot in &snapshot.neurons {
+        let neuron = &neurons[&neuron_snapshot.id];
+        for defined_id in &neuron_snapshot.defined_neurons {
+            let defined = match neurons.get(defined_id) {
+                Some(defined) => defined.clone(),
+                None => anyhow::bail!("neuron {} refers to missing neuron {defined_id}", neuron_snapshot.id)
+            };
+            neuron.borrow_mut().connect_bilateral(defined, false, ConnectionKind::Defining)?;
+        }
+        for (kind, connected_id, weight) in &neuron_snapshot.weighted_connections {
+            let connected = match neurons.get(connected_id) {
+                Some(connected) => connected.clone(),
+                None => anyhow::bail!("neuron {} refers to missing neuron {connected_id}", neuron_snapshot.id)
+            };
+            neuron.borrow_mut().connect_to_weighted(connected, false, *kind, *weight)?;
+        }
+    }
+
+    Ok(magds)
+}
+
+fn elements_snapshot<Key>(sensor: &dyn Sensor<Key>) -> Result<(u32, Vec<ElementSnapshot>)>
+where Key: SensorData + ThreadSafety, PhantomData<Key>: DataDeductor, DataTypeValue: From<Key> {
+    let graph = match (sensor as &dyn Any).downcast_ref::<ASAGraph<Key>>() {
+        Some(graph) => graph,
+        None => anyhow::bail!("sensor {} is not a default order asa-graph", sensor.id())
+    };
+
+    let mut elements = Vec::new();
+    for element in graph {
+        let element = element.borrow();
+        elements.push(ElementSnapshot {
+            id: element.id,
+            key: element.value(),
+            counter: element.counter,
+            prev_weight: element.prev.as_ref().map(|x| x.1),
+            next_weight: element.next.as_ref().map(|x| x.1),
+            interelement_activation_threshold: element.interelement_activation_threshold,
+            interelement_activation_exponent: element.interelement_activation_exponent,
+            weighting_strategy: element.definitions.weighting_strategy.name().to_string()
+        });
+    }
+
+    Ok((graph.elements_counter, elements))
+}
+
+fn weighting_strategy(name: &str) -> Ptr<dyn DefiningWeightingStrategy> {
+    match weighting_strategy_from_name(name) {
+        Some(strategy) => strategy,
+        None => {
+            log::warn!("unknown weighting strategy {name}, using ConstantOneWeight");
+            Ptr::new(ConstantOneWeight)
+        }
+    }
+}
+
+fn graph_from_snapshot<Key>(
+    sensor: &SensorSnapshot, elements: &mut HashMap<NeuronID, Ptr<Lock<dyn Neuron>>>
+) -> Result<Box<ASAGraph<Key>>>
+where
+    Key: SensorData + ThreadSafety,
+    PhantomData<Key>: DataDeductor,
+    DataTypeValue: From<Key>,
+    Option<Key>: From<DataTypeValue>
+{
+    let mut graph = ASAGraph::<Key>::new_box(sensor.id);
+
+    // min and max go first so that inserting the rest does not recalculate all weights
+    let len = sensor.elements.len();
+    let mut insert_order: Vec<usize> = if len > 0 { vec![0, len - 1] } else { vec![] };
+    insert_order.dedup();
+    insert_order.extend(1..len.saturating_sub(1));
+
+    for index in insert_order {
+        let element_snapshot = &sensor.elements[index];
+        let key: Option<Key> = element_snapshot.key.clone().into();
+        let key = match key {
+            Some(key) => key,
+            None => anyhow::bail!(
+                "element {} doesn't match {:?} sensor {}",
+                element_snapshot.key, sensor.data_type, sensor.name
+            )
+        };
+        let weighting_strategy = weighting_strategy(&element_snapshot.weighting_strategy);
+
+        let element = graph.insert_custom(
+            &key,
+            weighting_strategy,
+            element_snapshot.interelement_activation_threshold,
+            element_snapshot.interelement_activation_exponent
+        );
+        element.borrow_mut().id = element_snapshot.id;
+        element.borrow_mut().counter = element_snapshot.counter;
+        elements.insert(NeuronID::new(element_snapshot.id, sensor.id), element);
+    }
+    graph.elements_counter = sensor.elements_counter;
+
+    for (element, element_snapshot) in graph.into_iter().zip(&sensor.elements) {
+        let mut element = element.borrow_mut();
+        if let (Some(prev), Some(weight)) = (element.prev.as_mut(), element_snapshot.prev_weight) {
+            prev.1 = weight;
+        }
+        if let (Some(next), Some(weight)) = (element.next.as_mut(), element_snapshot.next_weight) {
+            next.1 = weight;
+        }
+    }
+
+    Ok(graph)
+}
@@ -0,0 +1,396 @@
+use std::{
+    fs::{ self, File, OpenOptions },
+    path::{ Path, PathBuf },
+    io::{ Read, Write }
+};
+
+use anyhow::{ Result, Context };
+
+use witchnet_common::{
+    data::{ DataType, DataTypeValue },
+    neuron::NeuronID,
+    connection::ConnectionKind,
+    tokenizer::Tokenizer
+};
+
+use crate::snapshot::{
+    data_type_tag, data_type_from_tag,
+    write_value, read_value,
+    write_neuron_id, read_neuron_id,
+    write_str, read_str,
+    write_tokenizer, read_tokenizer,
+    connection_kind_tag, connection_kind_from_tag,
+    write_u8, read_u8,
+    write_u32, read_u32,
+    write_u64, read_u64,
+    write_f32, read_f32
+};
+
+pub(crate) const MAGIC: &[u8; 8] = b"WNJOURN\0";
+pub(crate) const VERSION: u32 = 1;
+
+const HEADER_LEN: usize = 16;
+const FRAME_HEADER_LEN: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JournalEntry {
+    CreateSensor { id: u32, name: String, data_type: DataType },
+    SensorInsert { sensor_id: u32, value: DataTypeValue },
+    AddNeuronGroup { id: u32, name: String },
+    AddNeuron { id: NeuronID },
+    ConnectBilateral { sensor_id: u32, value: DataTypeValue, neuron_id: NeuronID },
+    InsertRecord { group_id: u32, features: Vec<(u32, DataTypeValue)> },
+    UpdateRecord { id: NeuronID, changes: Vec<(u32, DataTypeValue)> },
+    RemoveRecord { id: NeuronID },
+    CreateTokenizedSensor { id: u32, name: String, tokenizer: Tokenizer },
+    ConnectNeurons { parent: NeuronID, child: NeuronID },
+    AddRelation {
+        group_id: u32, sensor_id: u32, referenced_group_id: u32, referenced_sensor_id: u32
+    },
+    ConnectLateral { from: NeuronID, to: NeuronID, kind: ConnectionKind, weight: f32 },
+    ConnectSimilar { group_id: u32, min_similarity: f32 },
+    ConnectCompeting { group_id: u32, target_id: u32, min_similarity: f32, weight: f32 }
+}
+
+impl JournalEntry {
+    fn write<W: Write>(&self, w: &mut W) -> Result<()> {
+        match self {
+            JournalEntry::CreateSensor { id, name, data_type } => {
+                write_u8(w, 0)?;
+                write_u32(w, *id)?;
+                write_str(w, name)?;
+                write_u8(w, data_type_tag(*data_type))
+            }
+            JournalEntry::SensorInsert { sensor_id, value } => {
+                write_u8(w, 1)?;
+                write_u32(w, *sensor_id)?;
+                write_value(w, value)
+            }
+            JournalEntry::AddNeuronGroup { id, name } => {
+                write_u8(w, 2)?;
+                write_u32(w, *id)?;
+                write_str(w, name)
+            }
+            JournalEntry::AddNeuron { id } => {
+                write_u8(w, 3)?;
+                write_neuron_id(w, id)
+            }
+            JournalEntry::ConnectBilateral { sensor_id, value, neuron_id } => {
+                write_u8(w, 4)?;
+                write_u32(w, *sensor_id)?;
+                write_value(w, value)?;
+                write_neuron_id(w, neuron_id)
+            }
+            JournalEntry::InsertRecord { group_id, features } => {
+                write_u8(w, 5)?;
+                write_u32(w, *group_id)?;
+                write_features(w, features)
+            }
+            JournalEntry::UpdateRecord { id, changes } => {
+                write_u8(w, 6)?;
+                write_neuron_id(w, id)?;
+                write_features(w, changes)
+            }
+            JournalEntry::RemoveRecord { id } => {
+                write_u8(w, 7)?;
+                write_neuron_id(w, id)
+            }
+            JournalEntry::CreateTokenizedSensor { id, name, tokenizer } => {
+                write_u8(w, 8)?;
+                write_u32(w, *id)?;
+                write_str(w, name)?;
+                write_tokenizer(w, tokenizer)
+            }
+            JournalEntry::ConnectNeurons { parent, child } => {
+                write_u8(w, 9)?;
+                write_neuron_id(w, parent)?;
+                write_neuron_id(w, child)
+            }
+            JournalEntry::AddRelation {
+                group_id, sensor_id, referenced_group_id, referenced_sensor_id
+            } => {
+                write_u8(w, 10)?;
+                write_u32(w, *group_id)?;
+                write_u32(w, *sensor_id)?;
+                write_u32(w, *referenced_group_id)?;
+                write_u32(w, *referenced_sensor_id)
+            }
+            JournalEntry::ConnectLateral { from, to, kind, weight } => {
+                write_u8(w, 11)?;
+                write_neuron_id(w, from)?;
+                write_neuron_id(w, to)?;
+                write_u8(w, connection_kind_tag(*kind))?;
+                write_f32(w, *weight)
+            }
+            JournalEntry::ConnectSimilar { group_id, min_similarity } => {
+                write_u8(w, 12)?;
+                write_u32(w, *group_id)?;
+                write_f32(w, *min_similarity)
+            }
+            JournalEntry::ConnectCompeting { group_id, target_id, min_similarity, weight } => {
+                write_u8(w, 13)?;
+                write_u32(w, *group_id)?;
+                write_u32(w, *target_id)?;
+                write_f32(w, *min_similarity)?;
+                write_f32(w, *weight)
+            }
+        }
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<JournalEntry> {
+        let entry = match read_u8(r)? {
+            0 => JournalEntry::CreateSensor {
+                id: read_u32(r)?, name: read_str(r)?, data_type: data_type_from_tag(read_u8(r)?)?
+            },
+            1 => JournalEntry::SensorInsert { sensor_id: read_u32(r)?, value: read_value(r)? },
+            2 => JournalEntry::AddNeuronGroup { id: read_u32(r)?, name: read_str(r)? },
+            3 => JournalEntry::AddNeuron { id: read_neuron_id(r)? },
+            4 => JournalEntry::ConnectBilateral {
+                sensor_id: read_u32(r)?, value: read_value(r)?, neuron_id: read_neuron_id(r)?
+            },
+            5 => JournalEntry::InsertRecord { group_id: read_u32(r)?, features: read_features(r)? },
+            6 => JournalEntry::UpdateRecord { id: read_neuron_id(r)?, changes: read_features(r)? },
+            7 => JournalEntry::RemoveRecord { id: read_neuron_id(r)? },
+            8 => JournalEntry::CreateTokenizedSensor {
+                id: read_u32(r)?, name: read_str(r)?, tokenizer: read_tokenizer(r)?
+            },
+            9 => JournalEntry::ConnectNeurons {
+                parent: read_neuron_id(r)?, child: read_neuron_id(r)?
+            },
+            10 => JournalEntry::AddRelation {
+                group_id: read_u32(r)?,
+                sensor_id: read_u32(r)?,
+                referenced_group_id: read_u32(r)?,
+                referenced_sensor_id: read_u32(r)?
+            },
+            11 => JournalEntry::ConnectLateral {
+                from: read_neuron_id(r)?,
+                to: read_neuron_id(r)?,
+                kind: connection_kind_from_tag(read_u8(r)?)?,
+                weight: read_f32(r)?
+            },
+            12 => JournalEntry::ConnectSimilar {
+                group_id: read_u32(r)?, min_similarity: read_f32(r)?
+            },
+            13 => JournalEntry::ConnectCompeting {
+                group_id: read_u32(r)?,
+                target_id: read_u32(r)?,
+                min_similarity: read_f32(r)?,
+                weight: read_f32(r)?
+            },
+            tag => anyhow::bail!("unknown journal entry tag {tag}")
+        };
+        Ok(entry)
+    }
+}
+
+/// Append-only log of magds mutations made on top of the snapshot with checksum `base`.
+/// Every entry is framed with its length and crc32, so a torn tail left by a crash is
+/// detected and cut off when the journal is opened.
+pub(crate) struct Journal {
+    file: File,
+    entries: usize
+}
+
+impl Journal {
+    /// Writes an empty journal based on the snapshot with checksum `base`
+    pub fn write_empty(path: &Path, base: u32) -> Result<()> {
+        let mut header: Vec<u8> = Vec::with_capacity(HEADER_LEN);
+        header.write_all(MAGIC)?;
+        write_u32(&mut header, VERSION)?;
+        write_u32(&mut header, base)?;
+        write_synced(path, &header)
+    }
+
+    /// Opens the journal for appending, returns it with its base checksum and valid entries
+    pub fn open(path: &Path) -> Result<(Journal, u32, Vec<JournalEntry>)> {
+        let bytes = fs::read(path).context(format!("error reading {}", path.display()))?;
+        if bytes.len() < HEADER_LEN || &bytes[..8] != MAGIC {
+            anyhow::bail!("{} is not a magds journal", path.display())
+        }
+        let mut header = &bytes[8..HEADER_LEN];
+        let version = read_u32(&mut header)?;
+        if version == 0 || version > VERSION {
+            anyhow::bail!("unsupported magds journal version {version}, expected <= {VERSION}")
+        }
+        let base = read_u32(&mut header)?;
+
+        let mut entries = Vec::new();
+        let mut position = HEADER_LEN;
+        while let Some(payload) = frame(&bytes[position..]) {
+            let entry = JournalEntry::read(&mut &payload[..])
+                .context(format!("error decoding journal entry {}", entries.len()))?;
+            entries.push(entry);
+            position += FRAME_HEADER_LEN + payload.len();
+        }
+
+        let file = OpenOptions::new()
+            .append(true)
+            .open(path)
+            .context(format!("error opening {}", path.display()))?;
+        if position < bytes.len() {
+            log::warn!(
+                "journal {}: dropping {} bytes of torn or corrupted entries after entry {}",
+                path.display(), bytes.len() - position, entries.len()
+            );
+            file.set_len(position as u64)?;
+            file.sync_all()?;
+        }
+
+        let journal = Journal { file, entries: entries.len() };
+        Ok((journal, base, entries))
+    }
+
+    /// Appends `entry` and syncs it to disk before returning the offset it starts at
+    pub fn append(&mut self, entry: &JournalEntry) -> Result<u64> {
+        let mut payload: Vec<u8> = Vec::new();
+        entry.write(&mut payload)?;
+        let mut buffer: Vec<u8> = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+        write_u32(&mut buffer, payload.len() as u32)?;
+        write_u32(&mut buffer, checksum(&payload))?;
+        buffer.extend_from_slice(&payload);
+
+        let start = self.file.metadata()?.len();
+        self.file.write_all(&buffer)?;
+        self.file.sync_data()?;
+        self.entries += 1;
+        Ok(start)
+    }
+
+    /// Cuts off the last entry, appended at `start`, when it turned out it can't be applied
+    pub fn remove_last(&mut self, start: u64) -> Result<()> {
+        self.file.set_len(start)?;
+        self.file.sync_all()?;
+        self.entries -= 1;
+        Ok(())
+    }
+
+    /// Journal opened for reading only, so appending to it fails
+    #[cfg(test)]
+    pub fn open_read_only(path: &Path) -> Result<Journal> {
+        let (journal, _base, _entries) = Journal::open(path)?;
+        Ok(Journal { file: File::open(path)?, entries: journal.entries })
+    }
+
+    pub fn len(&self) -> usize { self.entries }
+}
+
+pub(crate) fn checksum(bytes: &[u8]) -> u32 { crc32fast::hash(bytes) }
+
+/// Path the next version of `path` is written to before it's renamed over it
+pub(crate) fn pending_path(path: &Path) -> PathBuf {
+    let mut pending = path.as_os_str().to_owned();
+    pending.push(".pending");
+    PathBuf::from(pending)
+}
+
+pub(crate) fn write_synced(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut file = File::create(path).context(format!("error creating {}", path.display()))?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+/// Payload of the first frame in `bytes` if it's complete and its checksum matches
+fn frame(bytes: &[u8]) -> Option<&[u8]> {
+    if bytes.len() < FRAME_HEADER_LEN { return None }
+    let mut header = &bytes[..FRAME_HEADER_LEN];
+    let len = read_u32(&mut header).ok()? as usize;
+    let expected = read_u32(&mut header).ok()?;
+    let payload = bytes.get(FRAME_HEADER_LEN..FRAME_HEADER_LEN + len)?;
+    if checksum(payload) != expected { return None }
+    Some(payload)
+}
+
+fn write_features<W: Write>(w: &mut W, features: &[(u32, DataTypeValue)]) -> Result<()> {
+    write_u64(w, features.len() as u64)?;
+    for (id, value) in features {
+        write_u32(w, *id)?;
+        write_value(w, value)?;
+    }
+    Ok(())
+}
+
+fn read_features<R: Read>(r: &mut R) -> Result<Vec<(u32, DataTypeValue)>> {
+    let mut features = Vec::new();
+    for _ in 0..read_u64(r)? {
+        let id = read_u32(r)?;
+        features.push((id, read_value(r)?));
+    }
+    Ok(features)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use witchnet_common::{
+        data::{ DataType, DataTypeValue },
+        neuron::NeuronID
+    };
+
+    use super::*;
+
+    #[test]
+    fn append_open() {
+        let entries = vec![
+            JournalEntry::CreateSensor {
+                id: 1, name: "brand".to_string(), data_type: DataType::ArcStr
+            },
+            JournalEntry::SensorInsert {
+                sensor_id: 1, value: DataTypeValue::ArcStr(Arc::from("audi"))
+            },
+            JournalEntry::AddNeuronGroup { id: 1, name: "cars".to_string() },
+            JournalEntry::AddNeuron { id: NeuronID::new(1, 1) },
+            JournalEntry::ConnectBilateral {
+                sensor_id: 1, value: DataTypeValue::Null, neuron_id: NeuronID::new(1, 1)
+            },
+            JournalEntry::InsertRecord {
+                group_id: 1, features: vec![(1, DataTypeValue::F64(2.5))]
+            },
+            JournalEntry::UpdateRecord { id: NeuronID::new(2, 1), changes: vec![] },
+            JournalEntry::RemoveRecord { id: NeuronID::new(2, 1) },
+            JournalEntry::CreateTokenizedSensor {
+                id: 2, name: "equipment".to_string(), tokenizer: Tokenizer::list()
+            },
+            JournalEntry::ConnectNeurons { parent: NeuronID::new(1, 2), child: NeuronID::new(1, 1) },
+            JournalEntry::AddRelation {
+                group_id: 1, sensor_id: 3, referenced_group_id: 2, referenced_sensor_id: 4
+            },
+            JournalEntry::ConnectLateral {
+                from: NeuronID::new(1, 1),
+                to: NeuronID::new(2, 1),
+                kind: ConnectionKind::Inhibitory,
+                weight: 0.5
+            },
+            JournalEntry::ConnectSimilar { group_id: 1, min_similarity: 0.25 },
+            JournalEntry::ConnectCompeting {
+                group_id: 1, target_id: 1, min_similarity: 0.25, weight: 0.5
+            }
+        ];
+
+        let path = std::env::temp_dir()
+            .join(format!("magds_journal_append_open_{}.wal", std::process::id()));
+        Journal::write_empty(&path, 42).unwrap();
+        let (mut journal, base, loaded) = Journal::open(&path).unwrap();
+        assert_eq!(base, 42);
+        assert!(loaded.is_empty());
+        for entry in &entries { journal.append(entry).unwrap(); }
+        assert_eq!(journal.len(), entries.len());
+        drop(journal);
+
+        let (_journal, _base, loaded) = Journal::open(&path).unwrap();
+        assert_eq!(loaded, entries);
+
+        let mut bytes = fs::read(&path).unwrap();
+        let corrupted = bytes.len() - 3;
+        bytes[corrupted] ^= 0xff;
+        fs::write(&path, &bytes).unwrap();
+        let (_journal, _base, loaded) = Journal::open(&path).unwrap();
+        assert_eq!(loaded, entries[..entries.len() - 1]);
+
+        fs::write(&path, b"garbage").unwrap();
+        assert!(Journal::open(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
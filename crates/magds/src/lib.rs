@@ -6,6 +6,9 @@
 pub mod neuron;
 pub mod synchronous;
 pub mod asynchronous;
+pub mod snapshot;
+pub mod journal;
+pub mod query;
 
 #[cfg(test)]
 mod tests {
@@ -1,2 +1,7 @@
-pub mod simple_neuron;
-pub mod simple_neuron_async;
\ No newline at end of file
+pub use crate::{
+    synchronous::neuron::{ simple_neuron, null_element },
+    asynchronous::neuron::{
+        simple_neuron as simple_neuron_async,
+        null_element as null_element_async
+    }
+};
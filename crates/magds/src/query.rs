@@ -0,0 +1,477 @@
+use std::{
+    sync::Arc,
+    fmt::{ Display, Formatter, Result as FmtResult }
+};
+
+use anyhow::Result;
+
+use witchnet_common::{
+    data::{ DataType, DataTypeValue },
+    neuron::NeuronID,
+    temporal::{ Date, DateTime, Duration }
+};
+
+/// Parsed form of a query like
+/// `SELECT object FROM cars WHERE price BETWEEN 10000 AND 20000 AND brand = 'audi'
+/// ORDER BY activation LIMIT 10`.
+///
+/// `ORDER BY activation` defaults to descending order, `ORDER BY <feature>` to ascending.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub select: Select,
+    pub from: String,
+    pub conditions: Vec<Condition>,
+    pub order_by: Option<OrderBy>,
+    pub limit: Option<usize>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Select {
+    All,
+    Features(Vec<String>)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub feature: String,
+    pub predicate: Predicate
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate<V = Literal> {
+    Eq(V),
+    NotEq(V),
+    Lt(V),
+    Le(V),
+    Gt(V),
+    Ge(V),
+    Between(V, V),
+    In(Vec<V>),
+    IsNull,
+    IsNotNull
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Number(String),
+    String(String),
+    Bool(bool)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Order {
+    Asc,
+    Desc
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderBy {
+    Activation(Order),
+    Feature(String, Order)
+}
+
+/// Record reconstructed from the neuron's defining sensors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub id: NeuronID,
+    pub activation: f32,
+    pub values: Vec<(String, DataTypeValue)>
+}
+
+impl Query {
+    pub fn parse(query: &str) -> Result<Query> {
+        Parser { tokens: tokenize(query)?, position: 0 }.query()
+    }
+}
+
+impl<V> Predicate<V> {
+    pub fn try_map<U>(&self, f: impl Fn(&V) -> Result<U>) -> Result<Predicate<U>> {
+        Ok(match self {
+            Predicate::Eq(v) => Predicate::Eq(f(v)?),
+            Predicate::NotEq(v) => Predicate::NotEq(f(v)?),
+            Predicate::Lt(v) => Predicate::Lt(f(v)?),
+            Predicate::Le(v) => Predicate::Le(f(v)?),
+            Predicate::Gt(v) => Predicate::Gt(f(v)?),
+            Predicate::Ge(v) => Predicate::Ge(f(v)?),
+            Predicate::Between(lo, hi) => Predicate::Between(f(lo)?, f(hi)?),
+            Predicate::In(values) => Predicate::In(
+                values.iter().map(&f).collect::<Result<Vec<U>>>()?
+            ),
+            Predicate::IsNull => Predicate::IsNull,
+            Predicate::IsNotNull => Predicate::IsNotNull
+        })
+    }
+}
+
+impl Predicate<DataTypeValue> {
+    pub fn matches(&self, value: &DataTypeValue) -> bool {
+        match self {
+            Predicate::IsNull => return value.is_null(),
+            Predicate::IsNotNull => return !value.is_null(),
+            _ => if value.is_null() { return false }
+        }
+        match self {
+            Predicate::Eq(v) => value == v,
+            Predicate::NotEq(v) => value != v,
+            Predicate::Lt(v) => value < v,
+            Predicate::Le(v) => value <= v,
+            Predicate::Gt(v) => value > v,
+            Predicate::Ge(v) => value >= v,
+            Predicate::Between(lo, hi) => value >= lo && value <= hi,
+            Predicate::In(values) => values.contains(value),
+            Predicate::IsNull | Predicate::IsNotNull => unreachable!()
+        }
+    }
+
+    /// Matches the values of a tokenized feature, any of them has to match,
+    /// all of them for `!=` and `IS NULL`
+    pub fn matches_values(&self, values: &[DataTypeValue]) -> bool {
+        match self {
+            Predicate::IsNull => values.iter().all(|x| self.matches(x)),
+            Predicate::NotEq(_) => !values.is_empty() && values.iter().all(|x| self.matches(x)),
+            _ => values.iter().any(|x| self.matches(x))
+        }
+    }
+}
+
+impl Literal {
+    pub fn to_value(&self, data_type: DataType) -> Result<DataTypeValue> {
+        let text = match self {
+            Literal::Number(v) | Literal::String(v) => v.as_str(),
+            Literal::Bool(v) => return match data_type {
+                DataType::Bool => Ok((*v).into()),
+                _ => anyhow::bail!("boolean literal {v} doesn't match {:?} feature", data_type)
+            }
+        };
+        let value: DataTypeValue = match data_type {
+            DataType::Bool => text.parse::<bool>()?.into(),
+            DataType::U8 => text.parse::<u8>()?.into(),
+            DataType::U16 => text.parse::<u16>()?.into(),
+            DataType::U32 => text.parse::<u32>()?.into(),
+            DataType::U64 => text.parse::<u64>()?.into(),
+            DataType::U128 => text.parse::<u128>()?.into(),
+            DataType::USize => text.parse::<usize>()?.into(),
+            DataType::I8 => text.parse::<i8>()?.into(),
+            DataType::I16 => text.parse::<i16>()?.into(),
+            DataType::I32 => text.parse::<i32>()?.into(),
+            DataType::I64 => text.parse::<i64>()?.into(),
+            DataType::I128 => text.parse::<i128>()?.into(),
+            DataType::ISize => text.parse::<isize>()?.into(),
+            DataType::F32 => text.parse::<f32>()?.into(),
+            DataType::F64 => text.parse::<f64>()?.into(),
+            DataType::ArcStr => Arc::<str>::from(text).into(),
+            DataType::String => text.to_string().into(),
+            DataType::Date => text.parse::<Date>()?.into(),
+            DataType::DateTime => text.parse::<DateTime>()?.into(),
+            DataType::Duration => text.parse::<Duration>()?.into(),
+            DataType::Unknown => anyhow::bail!("cannot compare {text} with unknown data type")
+        };
+        Ok(value)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "[{}|a:{}]", self.id, self.activation)?;
+        for (name, value) in &self.values {
+            write!(f, " {name}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    String(String),
+    Number(String),
+    Symbol(&'static str)
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>> {
+    const SYMBOLS: [&str; 10] = ["<=", ">=", "!=", "<>", "=", "<", ">", ",", "(", ")"];
+
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '\'' || c == '"' || c == '`' {
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => anyhow::bail!("unterminated {c} quote in query"),
+                    Some(&x) if x == c && chars.get(i + 1) == Some(&c) => { text.push(c); i += 2; }
+                    Some(&x) if x == c => { i += 1; break }
+                    Some(&x) => { text.push(x); i += 1; }
+                }
+            }
+            tokens.push(if c == '\'' { Token::String(text) } else { Token::Quoted(text) });
+        } else if c.is_ascii_digit()
+            || (c == '-' && chars.get(i + 1).map_or(false, |x| x.is_ascii_digit())) {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                i += 1;
+                if matches!(chars[i - 1], 'e' | 'E') && matches!(chars.get(i), Some('-' | '+')) {
+                    i += 1;
+                }
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '.')) {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else if c == '*' {
+            tokens.push(Token::Symbol("*"));
+            i += 1;
+        } else {
+            let rest: String = chars[i..usize::min(i + 2, chars.len())].iter().collect();
+            match SYMBOLS.iter().find(|symbol| rest.starts_with(*symbol)) {
+                Some(symbol) => {
+                    tokens.push(Token::Symbol(if *symbol == "<>" { "!=" } else { symbol }));
+                    i += symbol.len();
+                }
+                None => anyhow::bail!("unexpected character {c} at {i}")
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize
+}
+
+impl Parser {
+    fn query(&mut self) -> Result<Query> {
+        self.expect_keyword("SELECT")?;
+        let select = if self.next_symbol("*") || self.next_keyword("OBJECT") {
+            Select::All
+        } else {
+            let mut features = vec![self.identifier()?];
+            while self.next_symbol(",") { features.push(self.identifier()?); }
+            Select::Features(features)
+        };
+
+        self.expect_keyword("FROM")?;
+        let from = self.identifier()?;
+
+        let mut conditions = Vec::new();
+        if self.next_keyword("WHERE") {
+            conditions.push(self.condition()?);
+            while self.next_keyword("AND") { conditions.push(self.condition()?); }
+        }
+
+        let mut order_by = None;
+        if self.next_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            let activation = self.next_keyword("ACTIVATION");
+            let feature = if activation { None } else { Some(self.identifier()?) };
+            let order = if self.next_keyword("DESC") {
+                Order::Desc
+            } else if self.next_keyword("ASC") || feature.is_some() {
+                Order::Asc
+            } else {
+                Order::Desc
+            };
+            order_by = Some(match feature {
+                Some(feature) => OrderBy::Feature(feature, order),
+                None => OrderBy::Activation(order)
+            });
+        }
+
+        let mut limit = None;
+        if self.next_keyword("LIMIT") {
+            limit = match self.advance() {
+                Some(Token::Number(v)) => Some(v.parse::<usize>()?),
+                other => anyhow::bail!("expected LIMIT value, found {:?}", other)
+            };
+        }
+
+        if let Some(token) = self.tokens.get(self.position) {
+            anyhow::bail!("unexpected {:?} at the end of query", token)
+        }
+
+        Ok(Query { select, from, conditions, order_by, limit })
+    }
+
+    fn condition(&mut self) -> Result<Condition> {
+        let feature = self.identifier()?;
+        let predicate = if self.next_symbol("=") {
+            Predicate::Eq(self.literal()?)
+        } else if self.next_symbol("!=") {
+            Predicate::NotEq(self.literal()?)
+        } else if self.next_symbol("<=") {
+            Predicate::Le(self.literal()?)
+        } else if self.next_symbol(">=") {
+            Predicate::Ge(self.literal()?)
+        } else if self.next_symbol("<") {
+            Predicate::Lt(self.literal()?)
+        } else if self.next_symbol(">") {
+            Predicate::Gt(self.literal()?)
+        } else if self.next_keyword("BETWEEN") {
+            let lo = self.literal()?;
+            self.expect_keyword("AND")?;
+            Predicate::Between(lo, self.literal()?)
+        } else if self.next_keyword("IN") {
+            if !self.next_symbol("(") { anyhow::bail!("expected ( after IN") }
+            let mut values = vec![self.literal()?];
+            while self.next_symbol(",") { values.push(self.literal()?); }
+            if !self.next_symbol(")") { anyhow::bail!("expected ) after IN values") }
+            Predicate::In(values)
+        } else if self.next_keyword("IS") {
+            let not = self.next_keyword("NOT");
+            self.expect_keyword("NULL")?;
+            if not { Predicate::IsNotNull } else { Predicate::IsNull }
+        } else {
+            anyhow::bail!("expected comparison for feature {feature}, found {:?}", self.peek())
+        };
+        Ok(Condition { feature, predicate })
+    }
+
+    fn identifier(&mut self) -> Result<String> {
+        match self.advance() {
+            Some(Token::Word(v)) | Some(Token::Quoted(v)) => Ok(v),
+            other => anyhow::bail!("expected identifier, found {:?}", other)
+        }
+    }
+
+    fn literal(&mut self) -> Result<Literal> {
+        match self.advance() {
+            Some(Token::Number(v)) => Ok(Literal::Number(v)),
+            Some(Token::String(v)) => Ok(Literal::String(v)),
+            Some(Token::Word(v)) if v.eq_ignore_ascii_case("true") => Ok(Literal::Bool(true)),
+            Some(Token::Word(v)) if v.eq_ignore_ascii_case("false") => Ok(Literal::Bool(false)),
+            other => anyhow::bail!("expected literal, found {:?}", other)
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> { self.tokens.get(self.position) }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Word(v)) if v.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false
+        }
+    }
+
+    fn next_symbol(&mut self, symbol: &str) -> bool {
+        match self.peek() {
+            Some(Token::Symbol(v)) if *v == symbol => {
+                self.position += 1;
+                true
+            }
+            _ => false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.next_keyword(keyword) {
+            Ok(())
+        } else {
+            anyhow::bail!("expected {keyword}, found {:?}", self.peek())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use witchnet_common::data::{ DataType, DataTypeValue };
+
+    use super::{ Query, Select, Condition, Predicate, Literal, OrderBy, Order };
+
+    #[test]
+    fn parse() {
+        let query = Query::parse(
+            "SELECT object FROM cars WHERE price BETWEEN 10000 AND 20000 AND brand = 'audi' \
+            ORDER BY activation LIMIT 10"
+        ).unwrap();
+        assert_eq!(query, Query {
+            select: Select::All,
+            from: "cars".to_string(),
+            conditions: vec![
+                Condition {
+                    feature: "price".to_string(),
+                    predicate: Predicate::Between(
+                        Literal::Number("10000".to_string()), Literal::Number("20000".to_string())
+                    )
+                },
+                Condition {
+                    feature: "brand".to_string(),
+                    predicate: Predicate::Eq(Literal::String("audi".to_string()))
+                }
+            ],
+            order_by: Some(OrderBy::Activation(Order::Desc)),
+            limit: Some(10)
+        });
+
+        let query = Query::parse(
+            "select \"sepal.length\", variety from iris where petal.width >= -1.5e-1 \
+            and variety in ('Setosa', 'Virginica') and x is not null and y <> 'it''s' \
+            order by sepal.length desc"
+        ).unwrap();
+        assert_eq!(
+            query.select,
+            Select::Features(vec!["sepal.length".to_string(), "variety".to_string()])
+        );
+        assert_eq!(query.from, "iris");
+        assert_eq!(query.conditions[0].predicate, Predicate::Ge(Literal::Number("-1.5e-1".into())));
+        assert_eq!(query.conditions[1].predicate, Predicate::In(vec![
+            Literal::String("Setosa".into()), Literal::String("Virginica".into())
+        ]));
+        assert_eq!(query.conditions[2].predicate, Predicate::IsNotNull);
+        assert_eq!(query.conditions[3].predicate, Predicate::NotEq(Literal::String("it's".into())));
+        assert_eq!(query.order_by, Some(OrderBy::Feature("sepal.length".into(), Order::Desc)));
+        assert_eq!(query.limit, None);
+
+        assert!(Query::parse("SELECT * FROM").is_err());
+        assert!(Query::parse("SELECT * FROM iris WHERE x").is_err());
+        assert!(Query::parse("SELECT * FROM iris WHERE x = 'a").is_err());
+        assert!(Query::parse("SELECT * FROM iris LIMIT 10 x").is_err());
+    }
+
+    #[test]
+    fn predicate() {
+        let predicate = Predicate::Between(Literal::Number("1".into()), Literal::Number("3".into()))
+            .try_map(|x| x.to_value(DataType::I32))
+            .unwrap();
+        assert!(predicate.matches(&2i32.into()));
+        assert!(predicate.matches(&3i32.into()));
+        assert!(!predicate.matches(&4i32.into()));
+        assert!(!predicate.matches(&DataTypeValue::Null));
+        assert!(Predicate::<DataTypeValue>::IsNull.matches(&DataTypeValue::Null));
+
+        let token = |x: &str| -> DataTypeValue { Arc::<str>::from(x).into() };
+        let tokens = vec![token("radio"), token("navigation")];
+        assert!(Predicate::Eq(token("radio")).matches_values(&tokens));
+        assert!(!Predicate::Eq(token("radio")).matches_values(&tokens[1..]));
+        assert!(!Predicate::NotEq(token("radio")).matches_values(&tokens));
+        assert!(Predicate::NotEq(token("radio")).matches_values(&tokens[1..]));
+        assert!(!Predicate::NotEq(token("radio")).matches_values(&[]));
+        assert!(Predicate::<DataTypeValue>::IsNull.matches_values(&[]));
+        assert!(!Predicate::<DataTypeValue>::IsNull.matches_values(&tokens));
+
+        assert!(Literal::Number("1.5".into()).to_value(DataType::I32).is_err());
+        assert!(Literal::Bool(true).to_value(DataType::F64).is_err());
+        assert_eq!(Literal::Bool(true).to_value(DataType::Bool).unwrap(), true.into());
+    }
+}
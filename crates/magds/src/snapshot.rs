@@ -0,0 +1,552 @@
+use std::{
+    fs::File,
+    path::Path,
+    sync::Arc,
+    io::{ BufReader, BufWriter, Read, Write }
+};
+
+use anyhow::{ Result, Context };
+
+use witchnet_common::{
+    data::{ DataType, DataTypeValue },
+    neuron::NeuronID,
+    connection::ConnectionKind,
+    temporal::{ Date, DateTime, Duration },
+    tokenizer::Tokenizer
+};
+
+pub(crate) const MAGIC: &[u8; 8] = b"WNMAGDS\0";
+pub(crate) const VERSION: u32 = 1;
+
+const NULL_TAG: u8 = 254;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ElementSnapshot {
+    pub id: u32,
+    pub key: DataTypeValue,
+    pub counter: usize,
+    pub prev_weight: Option<f32>,
+    pub next_weight: Option<f32>,
+    pub interelement_activation_threshold: f32,
+    pub interelement_activation_exponent: i32,
+    pub weighting_strategy: String
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SensorSnapshot {
+    pub id: u32,
+    pub name: String,
+    pub data_type: DataType,
+    pub elements_counter: u32,
+    pub elements: Vec<ElementSnapshot>,
+    pub null_element: Option<NullElementSnapshot>,
+    pub tokenizer: Option<Tokenizer>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NullElementSnapshot {
+    pub counter: usize,
+    pub weighting_strategy: String
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NeuronSnapshot {
+    pub id: NeuronID,
+    pub counter: usize,
+    pub weighting_strategy: String,
+    pub defining_sensors: Vec<NeuronID>,
+    pub defined_neurons: Vec<NeuronID>,
+    pub weighted_connections: Vec<(ConnectionKind, NeuronID, f32)>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct MAGDSSnapshot {
+    pub sensors: Vec<SensorSnapshot>,
+    pub neuron_groups: Vec<(u32, String)>,
+    pub neurons: Vec<NeuronSnapshot>
+}
+
+impl MAGDSSnapshot {
+    pub fn save(&self, file_path: &str) -> Result<()> {
+        let path = Path::new(file_path);
+        let file = File::create(path).context(format!("error creating {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        self.write(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn load(file_path: &str) -> Result<MAGDSSnapshot> {
+        let path = Path::new(file_path);
+        let file = File::open(path).context(format!("error opening {}", path.display()))?;
+        MAGDSSnapshot::read(&mut BufReader::new(file))
+    }
+
+    pub fn write<W: Write>(&self, w: &mut W) -> Result<()> {
+        w.write_all(MAGIC)?;
+        write_u32(w, VERSION)?;
+
+        write_u64(w, self.sensors.len() as u64)?;
+        for sensor in &self.sensors {
+            write_u32(w, sensor.id)?;
+            write_str(w, &sensor.name)?;
+            write_u8(w, data_type_tag(sensor.data_type))?;
+            write_u32(w, sensor.elements_counter)?;
+            write_u64(w, sensor.elements.len() as u64)?;
+            for element in &sensor.elements {
+                write_u32(w, element.id)?;
+                write_value(w, &element.key)?;
+                write_u64(w, element.counter as u64)?;
+                write_weight(w, element.prev_weight)?;
+                write_weight(w, element.next_weight)?;
+                write_f32(w, element.interelement_activation_threshold)?;
+                write_i32(w, element.interelement_activation_exponent)?;
+                write_str(w, &element.weighting_strategy)?;
+            }
+            match &sensor.null_element {
+                Some(null_element) => {
+                    write_u8(w, 1)?;
+                    write_u64(w, null_element.counter as u64)?;
+                    write_str(w, &null_element.weighting_strategy)?;
+                }
+                None => write_u8(w, 0)?
+            }
+            match &sensor.tokenizer {
+                Some(tokenizer) => {
+                    write_u8(w, 1)?;
+                    write_tokenizer(w, tokenizer)?;
+                }
+                None => write_u8(w, 0)?
+            }
+        }
+
+        write_u64(w, self.neuron_groups.len() as u64)?;
+        for (id, name) in &self.neuron_groups {
+            write_u32(w, *id)?;
+            write_str(w, name)?;
+        }
+
+        write_u64(w, self.neurons.len() as u64)?;
+        for neuron in &self.neurons {
+            write_neuron_id(w, &neuron.id)?;
+            write_u64(w, neuron.counter as u64)?;
+            write_str(w, &neuron.weighting_strategy)?;
+            write_u64(w, neuron.defining_sensors.len() as u64)?;
+            for id in &neuron.defining_sensors { write_neuron_id(w, id)?; }
+            write_u64(w, neuron.defined_neurons.len() as u64)?;
+            for id in &neuron.defined_neurons { write_neuron_id(w, id)?; }
+            write_u64(w, neuron.weighted_connections.len() as u64)?;
+            for (kind, id, weight) in &neuron.weighted_connections {
+                write_u8(w, connection_kind_tag(*kind))?;
+                write_neuron_id(w, id)?;
+                write_f32(w, *weight)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn read<R: Read>(r: &mut R) -> Result<MAGDSSnapshot> {
+        let mut magic = [0u8; 8];
+        r.read_exact(&mut magic).context("error reading snapshot header")?;
+        if &magic != MAGIC { anyhow::bail!("not a magds snapshot") }
+        let version = read_u32(r)?;
+        if version != VERSION {
+            anyhow::bail!("unsupported magds snapshot version {version}, expected {VERSION}")
+        }
+
+        let mut snapshot = MAGDSSnapshot::default();
+
+        let sensors_len = read_u64(r)?;
+        for _ in 0..sensors_len {
+            let id = read_u32(r)?;
+            let name = read_str(r)?;
+            let data_type = data_type_from_tag(read_u8(r)?)?;
+            let elements_counter = read_u32(r)?;
+            let elements_len = read_u64(r)?;
+            let mut elements = Vec::new();
+            for _ in 0..elements_len {
+                elements.push(ElementSnapshot {
+                    id: read_u32(r)?,
+                    key: read_value(r)?,
+                    counter: read_u64(r)? as usize,
+                    prev_weight: read_weight(r)?,
+                    next_weight: read_weight(r)?,
+                    interelement_activation_threshold: read_f32(r)?,
+                    interelement_activation_exponent: read_i32(r)?,
+                    weighting_strategy: read_str(r)?
+                });
+            }
+            let null_element = if read_u8(r)? == 1 {
+                Some(NullElementSnapshot {
+                    counter: read_u64(r)? as usize,
+                    weighting_strategy: read_str(r)?
+                })
+            } else { None };
+            let tokenizer = if read_u8(r)? == 1 {
+                Some(read_tokenizer(r)?)
+            } else { None };
+            snapshot.sensors.push(SensorSnapshot {
+                id, name, data_type, elements_counter, elements, null_element, tokenizer
+            });
+        }
+
+        let neuron_groups_len = read_u64(r)?;
+        for _ in 0..neuron_groups_len {
+            let id = read_u32(r)?;
+            snapshot.neuron_groups.push((id, read_str(r)?));
+        }
+
+        let neurons_len = read_u64(r)?;
+        for _ in 0..neurons_len {
+            let id = read_neuron_id(r)?;
+            let counter = read_u64(r)? as usize;
+            let weighting_strategy = read_str(r)?;
+            let mut defining_sensors = Vec::new();
+            for _ in 0..read_u64(r)? { defining_sensors.push(read_neuron_id(r)?); }
+            let mut defined_neurons = Vec::new();
+            for _ in 0..read_u64(r)? { defined_neurons.push(read_neuron_id(r)?); }
+            let mut weighted_connections = Vec::new();
+            for _ in 0..read_u64(r)? {
+                let kind = connection_kind_from_tag(read_u8(r)?)?;
+                weighted_connections.push((kind, read_neuron_id(r)?, read_f32(r)?));
+            }
+            snapshot.neurons.push(NeuronSnapshot {
+                id,
+                counter,
+                weighting_strategy,
+                defining_sensors,
+                defined_neurons,
+                weighted_connections
+            });
+        }
+
+        Ok(snapshot)
+    }
+}
+
+pub(crate) fn data_type_tag(data_type: DataType) -> u8 {
+    match data_type {
+        DataType::Bool => 0,
+        DataType::U8 => 1,
+        DataType::U16 => 2,
+        DataType::U32 => 3,
+        DataType::U64 => 4,
+        DataType::U128 => 5,
+        DataType::USize => 6,
+        DataType::I8 => 7,
+        DataType::I16 => 8,
+        DataType::I32 => 9,
+        DataType::I64 => 10,
+        DataType::I128 => 11,
+        DataType::ISize => 12,
+        DataType::F32 => 13,
+        DataType::F64 => 14,
+        DataType::ArcStr => 15,
+        DataType::String => 16,
+        DataType::Date => 17,
+        DataType::DateTime => 18,
+        DataType::Duration => 19,
+        DataType::Unknown => 255
+    }
+}
+
+pub(crate) fn data_type_from_tag(tag: u8) -> Result<DataType> {
+    let data_type = match tag {
+        0 => DataType::Bool,
+        1 => DataType::U8,
+        2 => DataType::U16,
+        3 => DataType::U32,
+        4 => DataType::U64,
+        5 => DataType::U128,
+        6 => DataType::USize,
+        7 => DataType::I8,
+        8 => DataType::I16,
+        9 => DataType::I32,
+        10 => DataType::I64,
+        11 => DataType::I128,
+        12 => DataType::ISize,
+        13 => DataType::F32,
+        14 => DataType::F64,
+        15 => DataType::ArcStr,
+        16 => DataType::String,
+        17 => DataType::Date,
+        18 => DataType::DateTime,
+        19 => DataType::Duration,
+        255 => DataType::Unknown,
+        _ => anyhow::bail!("unknown data type tag {tag}")
+    };
+    Ok(data_type)
+}
+
+pub(crate) fn connection_kind_tag(kind: ConnectionKind) -> u8 {
+    match kind {
+        ConnectionKind::Defining => 0,
+        ConnectionKind::Explanatory => 1,
+        ConnectionKind::Similarity => 2,
+        ConnectionKind::Inhibitory => 3,
+        ConnectionKind::Sequential => 4
+    }
+}
+
+pub(crate) fn connection_kind_from_tag(tag: u8) -> Result<ConnectionKind> {
+    let kind = match tag {
+        0 => ConnectionKind::Defining,
+        1 => ConnectionKind::Explanatory,
+        2 => ConnectionKind::Similarity,
+        3 => ConnectionKind::Inhibitory,
+        4 => ConnectionKind::Sequential,
+        _ => anyhow::bail!("unknown connection kind tag {tag}")
+    };
+    Ok(kind)
+}
+
+pub(crate) fn write_value<W: Write>(w: &mut W, value: &DataTypeValue) -> Result<()> {
+    match value {
+        DataTypeValue::Null => write_u8(w, NULL_TAG)?,
+        _ => write_u8(w, data_type_tag(DataType::from(value)))?
+    }
+    match value {
+        DataTypeValue::Bool(v) => write_u8(w, *v as u8),
+        DataTypeValue::U8(v) => write_u8(w, *v),
+        DataTypeValue::U16(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::U32(v) => write_u32(w, *v),
+        DataTypeValue::U64(v) => write_u64(w, *v),
+        DataTypeValue::U128(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::USize(v) => write_u64(w, *v as u64),
+        DataTypeValue::I8(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::I16(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::I32(v) => write_i32(w, *v),
+        DataTypeValue::I64(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::I128(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::ISize(v) => Ok(w.write_all(&(*v as i64).to_le_bytes())?),
+        DataTypeValue::F32(v) => write_f32(w, *v),
+        DataTypeValue::F64(v) => Ok(w.write_all(&v.to_le_bytes())?),
+        DataTypeValue::ArcStr(v) => write_str(w, v),
+        DataTypeValue::String(v) => write_str(w, v),
+        DataTypeValue::Date(v) => write_i32(w, v.0),
+        DataTypeValue::DateTime(v) => Ok(w.write_all(&v.0.to_le_bytes())?),
+        DataTypeValue::Duration(v) => Ok(w.write_all(&v.0.to_le_bytes())?),
+        DataTypeValue::Null | DataTypeValue::Unknown => Ok(())
+    }
+}
+
+pub(crate) fn read_value<R: Read>(r: &mut R) -> Result<DataTypeValue> {
+    let tag = read_u8(r)?;
+    if tag == NULL_TAG { return Ok(DataTypeValue::Null) }
+    let value = match data_type_from_tag(tag)? {
+        DataType::Bool => DataTypeValue::Bool(read_u8(r)? != 0),
+        DataType::U8 => DataTypeValue::U8(read_u8(r)?),
+        DataType::U16 => DataTypeValue::U16(u16::from_le_bytes(read_array(r)?)),
+        DataType::U32 => DataTypeValue::U32(read_u32(r)?),
+        DataType::U64 => DataTypeValue::U64(read_u64(r)?),
+        DataType::U128 => DataTypeValue::U128(u128::from_le_bytes(read_array(r)?)),
+        DataType::USize => DataTypeValue::USize(read_u64(r)? as usize),
+        DataType::I8 => DataTypeValue::I8(i8::from_le_bytes(read_array(r)?)),
+        DataType::I16 => DataTypeValue::I16(i16::from_le_bytes(read_array(r)?)),
+        DataType::I32 => DataTypeValue::I32(read_i32(r)?),
+        DataType::I64 => DataTypeValue::I64(i64::from_le_bytes(read_array(r)?)),
+        DataType::I128 => DataTypeValue::I128(i128::from_le_bytes(read_array(r)?)),
+        DataType::ISize => DataTypeValue::ISize(i64::from_le_bytes(read_array(r)?) as isize),
+        DataType::F32 => DataTypeValue::F32(read_f32(r)?),
+        DataType::F64 => DataTypeValue::F64(f64::from_le_bytes(read_array(r)?)),
+        DataType::ArcStr => DataTypeValue::ArcStr(Arc::from(read_str(r)?)),
+        DataType::String => DataTypeValue::String(read_str(r)?),
+        DataType::Date => DataTypeValue::Date(Date(read_i32(r)?)),
+        DataType::DateTime => DataTypeValue::DateTime(DateTime(i64::from_le_bytes(read_array(r)?))),
+        DataType::Duration => DataTypeValue::Duration(Duration(i64::from_le_bytes(read_array(r)?))),
+        DataType::Unknown => DataTypeValue::Unknown
+    };
+    Ok(value)
+}
+
+pub(crate) fn write_neuron_id<W: Write>(w: &mut W, id: &NeuronID) -> Result<()> {
+    write_u32(w, id.id)?;
+    write_u32(w, id.parent_id)
+}
+
+pub(crate) fn read_neuron_id<R: Read>(r: &mut R) -> Result<NeuronID> {
+    let id = read_u32(r)?;
+    Ok(NeuronID::new(id, read_u32(r)?))
+}
+
+fn write_weight<W: Write>(w: &mut W, weight: Option<f32>) -> Result<()> {
+    match weight {
+        Some(weight) => { write_u8(w, 1)?; write_f32(w, weight) }
+        None => write_u8(w, 0)
+    }
+}
+
+fn read_weight<R: Read>(r: &mut R) -> Result<Option<f32>> {
+    match read_u8(r)? {
+        0 => Ok(None),
+        1 => Ok(Some(read_f32(r)?)),
+        flag => anyhow::bail!("invalid weight flag {flag}")
+    }
+}
+
+pub(crate) fn write_str<W: Write>(w: &mut W, v: &str) -> Result<()> {
+    write_u64(w, v.len() as u64)?;
+    Ok(w.write_all(v.as_bytes())?)
+}
+
+pub(crate) fn read_str<R: Read>(r: &mut R) -> Result<String> {
+    let len = read_u64(r)?;
+    let mut buffer = Vec::new();
+    r.take(len).read_to_end(&mut buffer)?;
+    if buffer.len() as u64 != len { anyhow::bail!("unexpected end of magds snapshot") }
+    Ok(String::from_utf8(buffer)?)
+}
+
+pub(crate) fn write_tokenizer<W: Write>(w: &mut W, tokenizer: &Tokenizer) -> Result<()> {
+    write_str(w, &tokenizer.separators.iter().collect::<String>())?;
+    write_u8(w, tokenizer.split_whitespace as u8)?;
+    write_u8(w, tokenizer.lowercase as u8)?;
+    write_u64(w, tokenizer.min_length as u64)
+}
+
+pub(crate) fn read_tokenizer<R: Read>(r: &mut R) -> Result<Tokenizer> {
+    Ok(Tokenizer {
+        separators: read_str(r)?.chars().collect(),
+        split_whitespace: read_u8(r)? == 1,
+        lowercase: read_u8(r)? == 1,
+        min_length: read_u64(r)? as usize
+    })
+}
+
+pub(crate) fn write_u8<W: Write>(w: &mut W, v: u8) -> Result<()> { Ok(w.write_all(&[v])?) }
+
+pub(crate) fn write_u32<W: Write>(w: &mut W, v: u32) -> Result<()> { Ok(w.write_all(&v.to_le_bytes())?) }
+
+pub(crate) fn write_u64<W: Write>(w: &mut W, v: u64) -> Result<()> { Ok(w.write_all(&v.to_le_bytes())?) }
+
+fn write_i32<W: Write>(w: &mut W, v: i32) -> Result<()> { Ok(w.write_all(&v.to_le_bytes())?) }
+
+pub(crate) fn write_f32<W: Write>(w: &mut W, v: f32) -> Result<()> { Ok(w.write_all(&v.to_le_bytes())?) }
+
+fn read_array<R: Read, const N: usize>(r: &mut R) -> Result<[u8; N]> {
+    let mut buffer = [0u8; N];
+    r.read_exact(&mut buffer).context("unexpected end of magds snapshot")?;
+    Ok(buffer)
+}
+
+pub(crate) fn read_u8<R: Read>(r: &mut R) -> Result<u8> { Ok(read_array::<_, 1>(r)?[0]) }
+
+pub(crate) fn read_u32<R: Read>(r: &mut R) -> Result<u32> { Ok(u32::from_le_bytes(read_array(r)?)) }
+
+pub(crate) fn read_u64<R: Read>(r: &mut R) -> Result<u64> { Ok(u64::from_le_bytes(read_array(r)?)) }
+
+fn read_i32<R: Read>(r: &mut R) -> Result<i32> { Ok(i32::from_le_bytes(read_array(r)?)) }
+
+pub(crate) fn read_f32<R: Read>(r: &mut R) -> Result<f32> { Ok(f32::from_le_bytes(read_array(r)?)) }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use witchnet_common::{
+        data::{ DataType, DataTypeValue },
+        neuron::NeuronID
+    };
+
+    use super::*;
+
+    #[test]
+    fn write_read() {
+        let snapshot = MAGDSSnapshot {
+            sensors: vec![SensorSnapshot {
+                id: 1,
+                name: "brand".to_string(),
+                data_type: DataType::ArcStr,
+                elements_counter: 2,
+                elements: vec![
+                    ElementSnapshot {
+                        id: 2,
+                        key: DataTypeValue::ArcStr(Arc::from("audi")),
+                        counter: 3,
+                        prev_weight: None,
+                        next_weight: Some(0.5),
+                        interelement_activation_threshold: 0.8,
+                        interelement_activation_exponent: 2,
+                        weighting_strategy: "OneOverOuts".to_string()
+                    },
+                    ElementSnapshot {
+                        id: 1,
+                        key: DataTypeValue::ArcStr(Arc::from("bmw")),
+                        counter: 1,
+                        prev_weight: Some(0.5),
+                        next_weight: None,
+                        interelement_activation_threshold: 0.8,
+                        interelement_activation_exponent: 2,
+                        weighting_strategy: "OneOverOuts".to_string()
+                    }
+                ],
+                null_element: Some(NullElementSnapshot {
+                    counter: 1, weighting_strategy: "ConstantOneWeight".to_string()
+                }),
+                tokenizer: Some(Tokenizer::words())
+            }],
+            neuron_groups: vec![(1, "cars".to_string())],
+            neurons: vec![NeuronSnapshot {
+                id: NeuronID::new(1, 1),
+                counter: 1,
+                weighting_strategy: "OneOverOutsUpperHalf".to_string(),
+                defining_sensors: vec![NeuronID::new(2, 1)],
+                defined_neurons: vec![],
+                weighted_connections: vec![(ConnectionKind::Similarity, NeuronID::new(2, 1), 0.5)]
+            }]
+        };
+
+        let mut buffer: Vec<u8> = Vec::new();
+        snapshot.write(&mut buffer).unwrap();
+        let loaded = MAGDSSnapshot::read(&mut buffer.as_slice()).unwrap();
+        assert_eq!(loaded, snapshot);
+
+        buffer[8] = 99;
+        assert!(MAGDSSnapshot::read(&mut buffer.as_slice()).is_err());
+        assert!(MAGDSSnapshot::read(&mut &b"garbage"[..]).is_err());
+    }
+
+    #[test]
+    fn corrupt_str_length() {
+        let mut buffer: Vec<u8> = Vec::new();
+        write_str(&mut buffer, "brand").unwrap();
+        buffer[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(read_str(&mut buffer.as_slice()).is_err());
+        buffer.truncate(10);
+        buffer[..8].copy_from_slice(&5u64.to_le_bytes());
+        assert!(read_str(&mut buffer.as_slice()).is_err());
+    }
+
+    #[test]
+    fn values() {
+        let values = vec![
+            DataTypeValue::Bool(true),
+            DataTypeValue::U8(8),
+            DataTypeValue::U16(16),
+            DataTypeValue::U32(32),
+            DataTypeValue::U64(64),
+            DataTypeValue::U128(u128::MAX),
+            DataTypeValue::USize(7),
+            DataTypeValue::I8(-8),
+            DataTypeValue::I16(-16),
+            DataTypeValue::I32(-32),
+            DataTypeValue::I64(-64),
+            DataTypeValue::I128(i128::MIN),
+            DataTypeValue::ISize(-7),
+            DataTypeValue::F32(3.5),
+            DataTypeValue::F64(-2.25),
+            DataTypeValue::ArcStr(Arc::from("arc")),
+            DataTypeValue::String("string".to_string()),
+            DataTypeValue::Date(Date(-1)),
+            DataTypeValue::DateTime(DateTime(1_664_977_500_000)),
+            DataTypeValue::Duration(Duration(1500)),
+            DataTypeValue::Null,
+            DataTypeValue::Unknown
+        ];
+        let mut buffer: Vec<u8> = Vec::new();
+        for value in &values { write_value(&mut buffer, value).unwrap(); }
+        let mut reader = buffer.as_slice();
+        for value in &values { assert_eq!(&read_value(&mut reader).unwrap(), value); }
+    }
+}
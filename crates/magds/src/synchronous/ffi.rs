@@ -57,7 +57,7 @@ unsafe fn extern_supervised_performance(
         Rc::new(ConstantOneWeight)
     };
 
-    let mut magds_train = parser::magds_from_csv_custom(
+    let magds_train = parser::magds_from_csv_custom(
         &name,
         &train_file,
         &vec![],
@@ -72,7 +72,7 @@ unsafe fn extern_supervised_performance(
         .unwrap();
 
     prediction::prediction_score_df_custom(
-        &mut magds_train,
+        &magds_train,
         &test,
         (target.as_str()).into(),
         fuzzy,
@@ -1,5 +1,35 @@
+//! `Rc<RefCell>` instantiation of the magds sources in `generic`
+//!
+//! Each `generic` source is included once here and once in `asynchronous`, with `Ptr`, `Lock`,
+//! `common` and `neural` resolving to this family, hence the allowed `clippy::duplicate_mod`
+#![allow(clippy::duplicate_mod)]
+
+use std::{ rc::Rc, cell::RefCell };
+
+use witchnet_common::synchronous as common;
+use asa_graphs::neural;
+
+/// Keeps the tests of the `generic` sources, they run once, in this family
+macro_rules! generic_tests { ($($tests:item)*) => { $($tests)* } }
+
+#[path = "../generic/magds.rs"]
 pub mod magds;
+#[path = "../generic/sensor.rs"]
 pub mod sensor;
+#[path = "../generic/parser.rs"]
 pub mod parser;
+#[path = "../generic/snapshot.rs"]
+pub mod snapshot;
+#[path = "../generic/journal.rs"]
+pub mod journal;
+#[path = "../generic/algorithm/mod.rs"]
 pub mod algorithm;
-pub mod ffi;
\ No newline at end of file
+#[path = "../generic/query.rs"]
+pub mod query;
+#[path = "../generic/neuron/mod.rs"]
+pub mod neuron;
+pub mod ffi;
+
+impl magds::MAGDS {
+    pub fn new_rc() -> Rc<RefCell<magds::MAGDS>> { Self::new_ptr() }
+}
@@ -0,0 +1,106 @@
+use std::{
+    env,
+    fs,
+    path::PathBuf,
+    process::{ Command, Output }
+};
+
+fn magds(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_magds")).args(args).output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("magds_cli_{name}_{}", std::process::id()))
+}
+
+const IRIS: &str = "data/iris.csv";
+
+#[test]
+fn build() {
+    let snapshot = temp_path("build");
+    stdout(&magds(&["build", IRIS, snapshot.to_str().unwrap()]));
+    assert!(snapshot.is_file());
+
+    let output = stdout(&magds(&["inspect", snapshot.to_str().unwrap()]));
+    let mut lines = output.lines();
+    assert_eq!(
+        lines.next().unwrap(), "id,name,data_type,category,elements,nulls,min,max,entropy"
+    );
+    assert_eq!(lines.count(), 5);
+
+    assert!(!magds(&["build", IRIS]).status.success());
+    fs::remove_file(snapshot).unwrap();
+}
+
+#[test]
+fn query() {
+    let snapshot = temp_path("query");
+    stdout(&magds(&["build", IRIS, snapshot.to_str().unwrap()]));
+
+    let output = stdout(&magds(&[
+        "query",
+        snapshot.to_str().unwrap(),
+        "SELECT variety FROM iris WHERE petal.width >= 2.3 ORDER BY activation"
+    ]));
+    let mut lines = output.lines();
+    assert_eq!(lines.next().unwrap(), "id,activation,variety");
+    let records: Vec<&str> = lines.collect();
+    assert!(!records.is_empty());
+    assert!(records.iter().all(|x| x.ends_with(",Virginica")));
+
+    let output = stdout(&magds(&[
+        "query", IRIS, "SELECT * FROM iris LIMIT 3", "--format", "json"
+    ]));
+    let records: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(records.as_array().unwrap().len(), 3);
+    assert!(records[0].get("petal.length").is_some());
+
+    assert!(!magds(&["query", snapshot.to_str().unwrap(), "SELECT * FROM cars"]).status.success());
+    fs::remove_file(snapshot).unwrap();
+}
+
+#[test]
+fn predict() {
+    let snapshot = temp_path("predict");
+    stdout(&magds(&["build", IRIS, snapshot.to_str().unwrap()]));
+    let size = fs::metadata(&snapshot).unwrap().len();
+
+    let output = stdout(&magds(&[
+        "predict",
+        snapshot.to_str().unwrap(),
+        "--target", "variety",
+        "--features", "petal.length=6.05,petal.width=2.45"
+    ]));
+    let mut lines = output.lines();
+    assert_eq!(lines.next().unwrap(), "row,variety,probability");
+    assert!(lines.next().unwrap().starts_with("0,Virginica,"));
+    assert_eq!(fs::metadata(&snapshot).unwrap().len(), size);
+
+    let output = stdout(&magds(&[
+        "predict", snapshot.to_str().unwrap(), "--target", "variety", "--input", IRIS
+    ]));
+    assert_eq!(output.lines().count(), 151);
+
+    assert!(!magds(&["predict", snapshot.to_str().unwrap(), "--target", "variety"]).status.success());
+    fs::remove_file(snapshot).unwrap();
+}
+
+#[test]
+fn evaluate() {
+    let output = stdout(&magds(&[
+        "evaluate", IRIS, "--target", "variety", "--k-fold", "3", "--format", "json"
+    ]));
+    let metrics: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(metrics[0]["metric"], "accuracy");
+    assert!(metrics[0]["mean"].as_f64().unwrap() > 0.8);
+
+    let output = stdout(&magds(&["evaluate", IRIS, "--target", "variety", "--holdout", "0.2"]));
+    assert!(output.starts_with("metric,mean,std\naccuracy,"));
+
+    assert!(!magds(&["evaluate", IRIS, "--target", "variety"]).status.success());
+}
@@ -0,0 +1,156 @@
+use witchnet_common::{
+    data::{ DataTypeValue, DataPoint2D, DataDeductor },
+    sensor::SensorData
+};
+
+use super::smagds::{ SMAGDS, PatternKind };
+
+/// Rarity of the patterns ending at a point by level starting from 1, `0` for the most
+/// frequent pattern of the level and `1` for the one that never occurred
+#[derive(Debug, Clone)]
+pub struct PointAnomaly {
+    pub point: DataPoint2D,
+    pub absolute_rarity: Vec<f32>,
+    pub relative_rarity: Vec<f32>,
+    pub score: f32
+}
+
+/// Consecutive points of a sequence scored at least the anomaly threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalySegment {
+    pub start: usize,
+    pub end: usize,
+    pub score: f32
+}
+
+impl SMAGDS {
+    /// Scores every point of `sequence` with the mean rarity of the patterns ending at it
+    pub fn anomaly_scores<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, sequence: &[(X, Y)]
+    ) -> anyhow::Result<Vec<PointAnomaly>> where DataTypeValue: From<X> + From<Y> {
+        let mut sequence = Self::convert_data(sequence);
+        Self::sort_by_x(&mut sequence, |point| &point.x)?;
+        self.point_anomalies(&sequence)
+    }
+
+    /// Segments of `sequence` whose points are scored at least `threshold`
+    pub fn anomalies<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, sequence: &[(X, Y)], threshold: f32
+    ) -> anyhow::Result<Vec<AnomalySegment>> where DataTypeValue: From<X> + From<Y> {
+        Ok(Self::anomalous_segments(&self.anomaly_scores(sequence)?, threshold))
+    }
+
+    /// Groups consecutive `anomalies` scored at least `threshold` into segments
+    /// described by their first and last index and the highest score
+    pub fn anomalous_segments(anomalies: &[PointAnomaly], threshold: f32) -> Vec<AnomalySegment> {
+        let mut segments: Vec<AnomalySegment> = Vec::new();
+        let mut current: Option<AnomalySegment> = None;
+        for (i, anomaly) in anomalies.iter().enumerate() {
+            if anomaly.score < threshold {
+                if let Some(segment) = current.take() { segments.push(segment); }
+                continue
+            }
+            match &mut current {
+                Some(segment) => {
+                    segment.end = i;
+                    segment.score = f32::max(segment.score, anomaly.score);
+                }
+                None => current = Some(AnomalySegment { start: i, end: i, score: anomaly.score })
+            }
+        }
+        if let Some(segment) = current { segments.push(segment); }
+        segments
+    }
+
+    pub(crate) fn point_anomalies(
+        &self, sequence: &[DataPoint2D]
+    ) -> anyhow::Result<Vec<PointAnomaly>> {
+        self.check_data_types(sequence)?;
+
+        let is_numeric = sequence.iter().all(|point| point.y.to_f64().is_some());
+        let absolute_max_counters = self.max_counters(PatternKind::Absolute);
+        let relative_max_counters = self.max_counters(PatternKind::Relative);
+
+        let mut anomalies = Vec::new();
+        for i in 0..sequence.len() {
+            let levels = 1..=usize::min(self.max_pattern_level(), i);
+            let absolute_rarity: Vec<f32> = levels.clone()
+                .map(|level| {
+                    let points = &sequence[i - level..=i];
+                    self.rarity(PatternKind::Absolute, points, absolute_max_counters[level - 1])
+                })
+                .collect();
+            let relative_rarity: Vec<f32> = if is_numeric {
+                levels
+                    .map(|level| {
+                        let points = &sequence[i - level..=i];
+                        self.rarity(PatternKind::Relative, points, relative_max_counters[level - 1])
+                    })
+                    .collect()
+            } else { Vec::new() };
+
+            let rarities = absolute_rarity.len() + relative_rarity.len();
+            let score = if rarities == 0 { 0.0 } else {
+                absolute_rarity.iter().chain(&relative_rarity).sum::<f32>() / rarities as f32
+            };
+            anomalies.push(PointAnomaly {
+                point: sequence[i].clone(), absolute_rarity, relative_rarity, score
+            });
+        }
+
+        Ok(anomalies)
+    }
+
+    fn rarity(&self, kind: PatternKind, points: &[DataPoint2D], max_counter: usize) -> f32 {
+        match self.match_points(kind, points) {
+            Some(neuron) => {
+                let counter = neuron.read().unwrap().counter();
+                f32::max(1.0 - counter as f32 / max_counter.max(1) as f32, 0.0)
+            }
+            None => 1.0
+        }
+    }
+
+    /// Counter of the most frequent pattern of each level starting from 1
+    fn max_counters(&self, kind: PatternKind) -> Vec<usize> {
+        (1..=self.max_pattern_level())
+            .map(|level| {
+                self.pattern_neurons(kind, level).into_iter().flatten()
+                    .map(|neuron| neuron.read().unwrap().counter())
+                    .max()
+                    .unwrap_or(1)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::smagds::SMAGDS;
+
+    #[test]
+    fn anomalies() {
+        let data: Vec<(i32, f64)> = (1..=40).map(|x| (x, (x % 4) as f64)).collect();
+        let smagds = SMAGDS::new(&data).unwrap();
+
+        let mut sequence: Vec<(i32, f64)> = (1..=30).map(|x| (x, (x % 4) as f64)).collect();
+        sequence[9].1 = 0.0;
+
+        let scores = smagds.anomaly_scores(&sequence).unwrap();
+        assert_eq!(scores.len(), 30);
+        assert_eq!(scores[0].score, 0.0);
+        assert!(scores[..9].iter().all(|anomaly| anomaly.score < 0.5));
+        assert_eq!(scores[9].absolute_rarity.len(), 9);
+        assert_eq!(scores[9].relative_rarity.len(), 9);
+        assert_eq!(scores[9].score, 1.0);
+        assert!(scores[25..].iter().all(|anomaly| anomaly.score < 0.5));
+
+        let segments = smagds.anomalies(&sequence, 0.5).unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].start, 9);
+        assert!(segments[0].end > 9 && segments[0].end < 19);
+        assert_eq!(segments[0].score, 1.0);
+
+        assert!(smagds.anomalies(&data[..30], 0.5).unwrap().is_empty());
+    }
+}
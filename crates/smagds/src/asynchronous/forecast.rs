@@ -0,0 +1,299 @@
+use std::sync::{ Arc, RwLock };
+
+use witchnet_common::{
+    data::{ DataTypeValue, DataTypeValueStr, DataPoint2D, DataType, DataDeductor },
+    sensor::{ SensorData, SensorAsync },
+    neuron::NeuronAsync
+};
+
+use flex_points::measures;
+
+use super::smagds::{ SMAGDS, SMAGDSParams, PatternKind };
+
+/// Forecasted point with the probability of the stored continuations that led to it
+#[derive(Debug, Clone)]
+pub struct ForecastPoint {
+    pub point: DataPoint2D,
+    pub confidence: f32
+}
+
+/// Forecast compared with the actual continuation of a sequence
+#[derive(Debug, Clone)]
+pub struct BacktestScore {
+    pub forecast: Vec<ForecastPoint>,
+    pub rmse: f64,
+    pub nrmse: f64
+}
+
+/// Pattern neuron describing the end of the forecasted sequence
+struct PatternContext {
+    neuron: Arc<RwLock<dyn NeuronAsync>>,
+    level: usize,
+    kind: PatternKind
+}
+
+impl SMAGDS {
+    /// Continues `history` with up to `horizon` points, stops earlier when none of the stored
+    /// patterns matching its tail has a continuation
+    pub fn forecast<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, history: &[(X, Y)], horizon: usize
+    ) -> anyhow::Result<Vec<ForecastPoint>> where DataTypeValue: From<X> + From<Y> {
+        let mut sequence = Self::convert_data(history);
+        Self::sort_by_x(&mut sequence, |point| &point.x)?;
+        self.forecast_points(&mut sequence, horizon)
+    }
+
+    /// Forecasts the points following `history` and scores them against `actual` point by point
+    pub fn backtest<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, history: &[(X, Y)], actual: &[(X, Y)]
+    ) -> anyhow::Result<BacktestScore> where DataTypeValue: From<X> + From<Y> {
+        let mut actual = Self::convert_data(actual);
+        Self::sort_by_x(&mut actual, |point| &point.x)?;
+        let forecast = self.forecast(history, actual.len())?;
+        Self::score(&actual, forecast)
+    }
+
+    /// Builds the model of `data` without its last `horizon` points and backtests it on them
+    pub fn backtest_holdout<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        data: &[(X, Y)], horizon: usize, params: SMAGDSParams
+    ) -> anyhow::Result<BacktestScore> where DataTypeValue: From<X> + From<Y> {
+        if horizon == 0 { anyhow::bail!("horizon must be > 0") }
+        if data.len() < horizon + 2 { anyhow::bail!("data length must be >= horizon + 2") }
+
+        let mut order: Vec<(usize, DataPoint2D)> = Self::convert_data(data).into_iter()
+            .enumerate()
+            .collect();
+        Self::sort_by_x(&mut order, |(_, point)| &point.x)?;
+        let (train, test): (Vec<_>, Vec<_>) = order.into_iter()
+            .map(|(i, _)| {
+                let (x, y) = &data[i];
+                (*dyn_clone::clone_box(x), *dyn_clone::clone_box(y))
+            })
+            .enumerate()
+            .partition(|(i, _)| *i < data.len() - horizon);
+        let train: Vec<(X, Y)> = train.into_iter().map(|(_, point)| point).collect();
+        let test: Vec<(X, Y)> = test.into_iter().map(|(_, point)| point).collect();
+
+        SMAGDS::new_custom(&train, params)?.backtest(&train, &test)
+    }
+
+    pub(crate) fn forecast_points(
+        &self, sequence: &mut Vec<DataPoint2D>, horizon: usize
+    ) -> anyhow::Result<Vec<ForecastPoint>> {
+        if sequence.len() < 2 { anyhow::bail!("history length must be >= 2") }
+        self.check_data_types(sequence)?;
+
+        let mut forecast = Vec::new();
+        let mut context = None;
+        let mut confidence = 1.0f32;
+        while forecast.len() < horizon {
+            let (next_context, point, probability) = match self.next_point(sequence, context) {
+                Some(next) => next,
+                None => break
+            };
+            confidence *= probability;
+            sequence.push(point.clone());
+            forecast.push(ForecastPoint { point, confidence });
+            context = Some(next_context);
+        }
+
+        Ok(forecast)
+    }
+
+    /// Follows `context` one level up, or the longest matching tail of `sequence` if it can't,
+    /// absolute patterns are preferred over the relative ones
+    fn next_point(
+        &self, sequence: &[DataPoint2D], context: Option<PatternContext>
+    ) -> Option<(PatternContext, DataPoint2D, f32)> {
+        if let Some(context) = context {
+            if let Some(next) = self.continue_pattern(sequence, context) { return Some(next) }
+        }
+
+        let max_level = self.max_pattern_level();
+        if max_level == 0 { return None }
+        let mut kinds = vec![PatternKind::Absolute];
+        if sequence.last()?.y.to_f64().is_some() { kinds.push(PatternKind::Relative); }
+        for kind in kinds {
+            let min_level = if kind == PatternKind::Absolute { 0 } else { 1 };
+            let max_level = usize::min(max_level - 1, sequence.len() - 1);
+            for level in (min_level..=max_level).rev() {
+                let tail = &sequence[sequence.len() - level - 1..];
+                let neuron = match self.match_points(kind, tail) {
+                    Some(neuron) => neuron,
+                    None => continue
+                };
+                let context = PatternContext { neuron, level, kind };
+                if let Some(next) = self.continue_pattern(sequence, context) { return Some(next) }
+            }
+        }
+        None
+    }
+
+    /// The most frequent pattern one level above `context` that extends it,
+    /// along with the point it adds and its share among all such patterns
+    fn continue_pattern(
+        &self, sequence: &[DataPoint2D], context: PatternContext
+    ) -> Option<(PatternContext, DataPoint2D, f32)> {
+        let continuations = self.continuations(context.kind, context.level, &context.neuron);
+        let total: usize = continuations.iter().map(|n| n.read().unwrap().counter()).sum();
+        let best = continuations.into_iter().max_by_key(|n| n.read().unwrap().counter())?;
+        let point = self.pattern_point(context.kind, &best, sequence.last()?)?;
+        let probability = best.read().unwrap().counter() as f32 / total as f32;
+
+        let context = PatternContext { neuron: best, level: context.level + 1, kind: context.kind };
+        Some((context, point, probability))
+    }
+
+    /// Patterns one level above `neuron` of `level` that extend it
+    pub(crate) fn continuations(
+        &self, kind: PatternKind, level: usize, neuron: &Arc<RwLock<dyn NeuronAsync>>
+    ) -> Vec<Arc<RwLock<dyn NeuronAsync>>> {
+        let level_neurons = match self.pattern_neurons(kind, level + 1) {
+            Some(level_neurons) => level_neurons,
+            None => return Vec::new()
+        };
+        let neuron = neuron.read().unwrap();
+        neuron.defined_neurons().iter()
+            .filter(|defined| Self::is_level_neuron(level_neurons, defined))
+            .cloned()
+            .collect()
+    }
+
+    /// Point following `last` by the last step of the pattern `neuron`
+    pub(crate) fn pattern_point(
+        &self, kind: PatternKind, neuron: &Arc<RwLock<dyn NeuronAsync>>, last: &DataPoint2D
+    ) -> Option<DataPoint2D> {
+        let neuron = neuron.read().unwrap();
+        let x_interval_id = self.sensors.x_interval.read().unwrap().id();
+        let x_diff = neuron.explain_one(x_interval_id)?.to_f64()?;
+        let y = match kind {
+            PatternKind::Absolute => neuron.explain_one(self.sensors.y.read().unwrap().id())?,
+            PatternKind::Relative => {
+                let y_interval_id = self.sensors.y_interval.read().unwrap().id();
+                shift(&last.y, neuron.explain_one(y_interval_id)?.to_f64()?)
+            }
+        };
+        Some(DataPoint2D { x: shift(&last.x, x_diff), y })
+    }
+
+    fn score(actual: &[DataPoint2D], forecast: Vec<ForecastPoint>) -> anyhow::Result<BacktestScore> {
+        if forecast.is_empty() { anyhow::bail!("no stored pattern continues the history") }
+
+        let mut data = Vec::new();
+        let mut samples = Vec::new();
+        for (actual, forecasted) in actual.iter().zip(&forecast) {
+            let x = actual.x.to_f64()
+                .ok_or_else(|| anyhow::anyhow!("x values must be numeric to be scored"))?;
+            let y = actual.y.to_f64()
+                .ok_or_else(|| anyhow::anyhow!("y values must be numeric to be scored"))?;
+            let forecasted_y = forecasted.point.y.to_f64()
+                .ok_or_else(|| anyhow::anyhow!("y values must be numeric to be scored"))?;
+            data.push([x, y]);
+            samples.push([x, forecasted_y]);
+        }
+
+        Ok(BacktestScore {
+            rmse: measures::rmse(&data, &samples)?,
+            nrmse: measures::nrmse(&data, &samples)?,
+            forecast
+        })
+    }
+}
+
+/// `value + delta` in the data type of `value` if it can hold the result
+fn shift(value: &DataTypeValue, delta: f64) -> DataTypeValue {
+    let shifted = match value.to_f64() {
+        Some(number) => number + delta,
+        None => return value.clone()
+    };
+    let data_type = DataType::from(value);
+    DataTypeValueStr(&shifted.to_string()).data_type_value(data_type)
+        .or_else(|| DataTypeValueStr(&shifted.round().to_string()).data_type_value(data_type))
+        .unwrap_or_else(|| shifted.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use witchnet_common::{ data::DataTypeValue, sensor::SensorAsync };
+
+    use super::super::smagds::{ SMAGDS, SMAGDSParams };
+
+    fn sawtooth(offset: f64) -> Vec<(i32, f64)> {
+        (1..=40).map(|x| (x, (x % 4) as f64 + offset)).collect()
+    }
+
+    #[test]
+    fn forecast() {
+        let data = sawtooth(0.0);
+        let smagds = SMAGDS::new(&data).unwrap();
+
+        let forecast = smagds.forecast(&data[32..], 6).unwrap();
+        assert_eq!(forecast.len(), 6);
+        for (i, forecasted) in forecast.iter().enumerate() {
+            let x = 41 + i as i32;
+            assert_eq!(forecasted.point.x, DataTypeValue::I32(x));
+            assert_eq!(forecasted.point.y, DataTypeValue::F64((x % 4) as f64));
+            assert_eq!(forecasted.confidence, 1.0);
+        }
+
+        let shifted = sawtooth(10.0);
+        let forecast = smagds.forecast(&shifted[34..], 4).unwrap();
+        let y: Vec<_> = forecast.iter().map(|f| f.point.y.to_f64().unwrap()).collect();
+        assert_eq!(y, vec![11.0, 12.0, 13.0, 10.0]);
+
+        assert!(smagds.forecast(&data[..1], 3).is_err());
+        assert!(smagds.forecast(&[(1.0, 1.0), (2.0, 2.0)], 3).is_err());
+    }
+
+    #[test]
+    fn backtest() {
+        let data = sawtooth(1.0);
+        let smagds = SMAGDS::new(&data[..36]).unwrap();
+        let score = smagds.backtest(&data[..36], &data[36..]).unwrap();
+        assert_eq!(score.forecast.len(), 4);
+        assert!(score.rmse < 1e-9);
+        assert!(score.nrmse < 1e-9);
+
+        let score = SMAGDS::backtest_holdout(&data, 8, SMAGDSParams::default()).unwrap();
+        assert_eq!(score.forecast.len(), 8);
+        assert!(score.rmse < 1e-9);
+
+        assert!(SMAGDS::backtest_holdout(&data[..3], 2, SMAGDSParams::default()).is_err());
+
+        let nan = [(1.0, 1.0), (f64::NAN, 2.0), (3.0, 3.0), (4.0, 1.0)];
+        assert!(SMAGDS::new(&nan).is_err());
+        assert!(SMAGDS::backtest_holdout(&nan, 1, SMAGDSParams::default()).is_err());
+        let smagds = SMAGDS::new(&nan[2..]).unwrap();
+        assert!(smagds.forecast(&nan[..2], 2).is_err());
+    }
+
+    #[test]
+    fn concurrent_forecasts() {
+        let smagds = SMAGDS::new(&sawtooth(0.0)).unwrap();
+        let values = |smagds: &SMAGDS| -> Vec<Vec<DataTypeValue>> {
+            smagds.magds.sensors().iter().map(|sensor| sensor.read().unwrap().values()).collect()
+        };
+        let sensor_values = values(&smagds);
+
+        thread::scope(|scope| {
+            for offset in [0.0, 10.0, 0.0, 10.0] {
+                let smagds = &smagds;
+                scope.spawn(move || {
+                    let history = sawtooth(offset);
+                    for _ in 0..20 {
+                        let forecast = smagds.forecast(&history[34..], 4).unwrap();
+                        let y: Vec<_> = forecast.iter()
+                            .map(|f| f.point.y.to_f64().unwrap() - offset)
+                            .collect();
+                        assert_eq!(y, vec![1.0, 2.0, 3.0, 0.0]);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(values(&smagds), sensor_values);
+        assert!(smagds.magds.neurons().iter().all(|n| n.read().unwrap().activation() == 0.0));
+    }
+}
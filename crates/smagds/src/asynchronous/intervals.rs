@@ -0,0 +1,195 @@
+use std::{
+    sync::{ Arc, RwLock },
+    collections::BTreeMap
+};
+
+use magds::asynchronous::sensor::SensorConatiner;
+
+use witchnet_common::{
+    sensor::{ SensorData, SensorAsync },
+    neuron::{ NeuronAsync, NeuronID },
+    connection::ConnectionKind
+};
+
+use super::smagds::{ SMAGDS, PatternKind };
+
+/// Interval in x between pattern occurrences and how many times it was observed
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalStatistic {
+    pub interval: f64,
+    pub count: usize
+}
+
+impl SMAGDS {
+    /// Intervals between repeated occurrences of the same pattern, the most common first,
+    /// so the first one is the dominant period of the data
+    pub fn periodicity(&self, kind: PatternKind) -> Vec<IntervalStatistic> {
+        self.interval_statistics(true, kind)
+    }
+
+    /// Intervals after which a pattern repeated at consecutive positions gives way
+    /// to a different one, the most common first
+    pub fn seasonality(&self, kind: PatternKind) -> Vec<IntervalStatistic> {
+        self.interval_statistics(false, kind)
+    }
+
+    /// Fills the same and different patterns interval sensors with neurons connecting
+    /// each interval with the pattern it follows
+    pub(crate) fn create_interval_neurons(&mut self) {
+        let th = self.params.signal_similarity_threshold;
+        for kind in [PatternKind::Absolute, PatternKind::Relative] {
+            for same in [true, false] {
+                let intervals = self.pattern_intervals(same, kind);
+                if intervals.is_empty() { continue }
+
+                let (sensor, group_id) = self.interval_sensor(same, kind);
+                let sensor = sensor.clone();
+                let mut sensor = sensor.write().unwrap();
+                let min = intervals.iter().map(|(_, _, interval)| *interval).fold(f64::MAX, f64::min);
+                let max = intervals.iter().map(|(_, _, interval)| *interval).fold(f64::MIN, f64::max);
+                sensor.insert(&min.into()); sensor.insert(&max.into());
+
+                for (level, id, interval) in intervals {
+                    let pattern = self.pattern_neurons(kind, level).unwrap()[id as usize].clone();
+                    let interval_sn = Self::fuzzy_search_insert(&mut sensor, interval.into(), th);
+                    self.add_interval_neuron(group_id, &pattern, &interval_sn);
+                }
+            }
+        }
+    }
+
+    /// `(level, pattern id, interval)` between the repeated occurrences of the same patterns,
+    /// or between the starts of consecutive runs of different patterns
+    fn pattern_intervals(&self, same: bool, kind: PatternKind) -> Vec<(usize, u32, f64)> {
+        let distance = |a: usize, b: usize| self.data[b].x.distance(&self.data[a].x);
+
+        let mut intervals = Vec::new();
+        for level in 1..=self.max_pattern_level() {
+            let starts: BTreeMap<u32, &Vec<usize>> = self.pattern_occurrences.iter()
+                .filter(|((k, l, _), _)| *k == kind && *l == level)
+                .map(|((_, _, id), starts)| (*id, starts))
+                .collect();
+
+            if same {
+                for (id, starts) in starts {
+                    for window in starts.windows(2) {
+                        intervals.push((level, id, distance(window[0], window[1])));
+                    }
+                }
+            } else {
+                let mut occurrences: Vec<(usize, u32)> = starts.into_iter()
+                    .flat_map(|(id, starts)| starts.iter().map(move |start| (*start, id)))
+                    .collect();
+                occurrences.sort();
+
+                let mut runs: Vec<(usize, u32)> = Vec::new();
+                for (start, id) in occurrences {
+                    if runs.last().map_or(true, |(_, last_id)| *last_id != id) {
+                        runs.push((start, id));
+                    }
+                }
+                for window in runs.windows(2) {
+                    intervals.push((level, window[0].1, distance(window[0].0, window[1].0)));
+                }
+            }
+        }
+        intervals
+    }
+
+    fn add_interval_neuron(
+        &mut self,
+        group_id: u32,
+        pattern: &Arc<RwLock<dyn NeuronAsync>>,
+        interval_sn: &Arc<RwLock<dyn NeuronAsync>>
+    ) {
+        let group = self.interval_neurons.entry(group_id).or_default();
+        match Self::match_pattern_neuron(group, &[pattern, interval_sn]) {
+            Some(neuron) => { neuron.write().unwrap().increment_counter(); }
+            None => {
+                let neuron = Self::add_pattern_neuron(
+                    &mut self.magds, NeuronID { id: group.len() as u32, parent_id: group_id }
+                );
+                group.push(neuron.clone());
+                for input in [pattern, interval_sn] {
+                    input.write().unwrap().connect_bilateral(
+                        neuron.clone(), false, ConnectionKind::Defining
+                    ).unwrap();
+                }
+            }
+        }
+    }
+
+    fn interval_statistics(&self, same: bool, kind: PatternKind) -> Vec<IntervalStatistic> {
+        let (sensor, group_id) = self.interval_sensor(same, kind);
+        let sensor_id = sensor.read().unwrap().id();
+
+        let mut statistics: Vec<IntervalStatistic> = Vec::new();
+        for neuron in self.interval_neurons.get(&group_id).into_iter().flatten() {
+            let neuron = neuron.read().unwrap();
+            let interval = match neuron.explain_one(sensor_id).and_then(|v| v.to_f64()) {
+                Some(interval) => interval,
+                None => continue
+            };
+            match statistics.iter_mut().find(|statistic| statistic.interval == interval) {
+                Some(statistic) => statistic.count += neuron.counter(),
+                None => statistics.push(IntervalStatistic { interval, count: neuron.counter() })
+            }
+        }
+
+        statistics.sort_by(|a, b| {
+            b.count.cmp(&a.count).then(a.interval.partial_cmp(&b.interval).unwrap())
+        });
+        statistics
+    }
+
+    fn interval_sensor(
+        &self, same: bool, kind: PatternKind
+    ) -> (&Arc<RwLock<SensorConatiner>>, u32) {
+        let sensors = &self.sensors;
+        let groups = &self.neuron_groups;
+        match (same, kind) {
+            (true, PatternKind::Absolute) => (
+                &sensors.same_absolute_patterns_interval, groups.same_absolute_patterns_interval
+            ),
+            (true, PatternKind::Relative) => (
+                &sensors.same_relative_patterns_interval, groups.same_relative_patterns_interval
+            ),
+            (false, PatternKind::Absolute) => (
+                &sensors.different_absolute_patterns_interval,
+                groups.different_absolute_patterns_interval
+            ),
+            (false, PatternKind::Relative) => (
+                &sensors.different_relative_patterns_interval,
+                groups.different_relative_patterns_interval
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::smagds::{ SMAGDS, PatternKind };
+
+    #[test]
+    fn periodicity_and_seasonality() {
+        let sawtooth: Vec<(i32, f64)> = (1..=40).map(|x| (x, (x % 4) as f64)).collect();
+        let smagds = SMAGDS::new(&sawtooth).unwrap();
+        assert_eq!(smagds.periodicity(PatternKind::Absolute)[0].interval, 4.0);
+        assert_eq!(smagds.periodicity(PatternKind::Relative)[0].interval, 4.0);
+        assert_eq!(smagds.seasonality(PatternKind::Absolute)[0].interval, 1.0);
+
+        let plateaus: Vec<(i32, f64)> = (1..=36)
+            .map(|x| (x, if (x - 1) % 6 < 3 { 0.0 } else { 5.0 }))
+            .collect();
+        let smagds = SMAGDS::new(&plateaus).unwrap();
+        let periodicity = smagds.periodicity(PatternKind::Absolute);
+        assert_eq!(periodicity[0].interval, 6.0);
+        assert!(periodicity.iter().any(|statistic| statistic.interval == 1.0));
+        let seasonality = smagds.seasonality(PatternKind::Relative);
+        assert!(seasonality.iter().any(|statistic| statistic.interval == 2.0));
+
+        let smagds = SMAGDS::new(&[(1, 1.0), (2, 2.0)]).unwrap();
+        assert!(smagds.periodicity(PatternKind::Absolute).is_empty());
+        assert!(smagds.seasonality(PatternKind::Absolute).is_empty());
+    }
+}
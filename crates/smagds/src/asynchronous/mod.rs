@@ -1 +1,6 @@
-pub mod smagds;
\ No newline at end of file
+pub mod smagds;
+pub mod forecast;
+pub mod search;
+pub mod anomaly;
+pub mod intervals;
+pub mod multivariate;
\ No newline at end of file
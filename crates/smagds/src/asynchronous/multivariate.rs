@@ -0,0 +1,518 @@
+use std::{
+    sync::{ Arc, RwLock },
+    collections::HashMap
+};
+
+use magds::asynchronous::magds::MAGDS;
+
+use witchnet_common::{
+    data::{ DataTypeValue, DataPoint2D, DataDeductor },
+    sensor::SensorData,
+    neuron::{ NeuronAsync, NeuronID },
+    connection::ConnectionKind
+};
+
+use super::{
+    smagds::{ SMAGDS, SMAGDSParams, PatternKind },
+    search::PatternOccurrence
+};
+
+type CrossPatternNeurons = HashMap<(PatternKind, usize), Vec<Arc<RwLock<dyn NeuronAsync>>>>;
+
+/// Forecasted values of all the channels at one x
+#[derive(Debug, Clone)]
+pub struct MultivariateForecastPoint {
+    pub x: DataTypeValue,
+    pub y: Vec<DataTypeValue>,
+    pub confidence: f32
+}
+
+/// Channels sharing the x axis modelled in one graph, with a single x interval sensor,
+/// the y sensors and patterns of every channel, and cross-channel pattern neurons defined
+/// by the channel patterns occurring at the same time
+#[derive(Debug, Clone)]
+pub struct MultivariateSMAGDS {
+    pub magds: MAGDS,
+    /// Channel models whose sensors and neurons live in `magds`
+    pub(crate) channels: Vec<SMAGDS>,
+    pub neuron_groups: HashMap<(PatternKind, usize), u32>,
+    pub(crate) cross_pattern_neurons: CrossPatternNeurons,
+    pub(crate) cross_pattern_occurrences: HashMap<(PatternKind, usize, u32), Vec<usize>>,
+    pub params: SMAGDSParams
+}
+
+/// Pattern neurons of every channel describing the end of the forecasted sequences
+struct CrossPatternContext {
+    neurons: Vec<Arc<RwLock<dyn NeuronAsync>>>,
+    level: usize,
+    kind: PatternKind
+}
+
+impl MultivariateSMAGDS {
+    pub fn new<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        data: &[(X, Vec<Y>)]
+    ) -> anyhow::Result<Self> where DataTypeValue: From<X> + From<Y> {
+        Self::new_custom(data, SMAGDSParams::default())
+    }
+
+    pub fn new_custom<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        data: &[(X, Vec<Y>)], params: SMAGDSParams
+    ) -> anyhow::Result<Self> where DataTypeValue: From<X> + From<Y> {
+        if data.len() < 2 { anyhow::bail!("data length must be >= 2") }
+
+        let mut channels_data = vec![Vec::new(); Self::channels_len(data)?];
+        for (x, y) in data {
+            for (channel_data, y) in channels_data.iter_mut().zip(y) {
+                channel_data.push(DataPoint2D {
+                    x: (*dyn_clone::clone_box(x)).into(),
+                    y: (*dyn_clone::clone_box(y)).into()
+                });
+            }
+        }
+        for channel_data in &mut channels_data {
+            SMAGDS::sort_by_x(channel_data, |point| &point.x)?;
+        }
+        Ok(Self::build(channels_data, params))
+    }
+
+    /// Adds the rows of `data` to the loaded ones and rebuilds the model, so that the channel
+    /// and cross-channel patterns and their occurrences describe the merged data
+    pub fn add<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &mut self, data: &[(X, Vec<Y>)]
+    ) -> anyhow::Result<()> where DataTypeValue: From<X> + From<Y> {
+        if data.is_empty() { return Ok(()) }
+
+        let sequences = self.channel_points(data)?;
+        let channels_data = self.channels.iter_mut().zip(sequences)
+            .map(|(channel, mut points)| {
+                let mut channel_data = std::mem::take(&mut channel.data);
+                channel_data.append(&mut points);
+                channel_data
+            })
+            .collect();
+        *self = Self::build(channels_data, self.params.clone());
+
+        Ok(())
+    }
+
+    /// Data of the channel `channel` sorted by x
+    pub fn channel_data(&self, channel: usize) -> Option<&[DataPoint2D]> {
+        self.channels.get(channel).map(|channel| channel.data.as_slice())
+    }
+
+    fn build(channels_data: Vec<Vec<DataPoint2D>>, params: SMAGDSParams) -> Self {
+        let mut magds = MAGDS::new();
+        let mut x_interval = None;
+        let mut channels = Vec::new();
+        for (i, data) in channels_data.into_iter().enumerate() {
+            let mut channel = SMAGDS::build_in(
+                magds, data, params.clone(), &format!("channel {i} "), x_interval.clone()
+            );
+            x_interval = Some(channel.sensors.x_interval.clone());
+            magds = std::mem::replace(&mut channel.magds, MAGDS::new());
+            channels.push(channel);
+        }
+
+        let first_id = magds.neuron_group_ids().into_iter().max().unwrap_or(0);
+        let mut neuron_groups = HashMap::new();
+        let mut cross_pattern_neurons = HashMap::new();
+        let max_level = channels[0].max_pattern_level();
+        for level in 1..=max_level {
+            let absolute_id = first_id + level as u32;
+            let relative_id = first_id + (max_level + level) as u32;
+            magds.add_neuron_group(
+                &format!("cross-channel absolute pattern level {level}"), Some(absolute_id)
+            );
+            magds.add_neuron_group(
+                &format!("cross-channel relative pattern level {level}"), Some(relative_id)
+            );
+            neuron_groups.insert((PatternKind::Absolute, level), absolute_id);
+            neuron_groups.insert((PatternKind::Relative, level), relative_id);
+            cross_pattern_neurons.insert((PatternKind::Absolute, level), Vec::new());
+            cross_pattern_neurons.insert((PatternKind::Relative, level), Vec::new());
+        }
+
+        let mut smagds = Self {
+            magds,
+            channels,
+            neuron_groups,
+            cross_pattern_neurons,
+            cross_pattern_occurrences: HashMap::new(),
+            params
+        };
+        smagds.create_cross_neurons();
+
+        smagds
+    }
+
+    /// Continues all the channels of `history` with up to `horizon` rows, stops earlier
+    /// when no cross-channel pattern matching its tail has a continuation
+    pub fn forecast<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, history: &[(X, Vec<Y>)], horizon: usize
+    ) -> anyhow::Result<Vec<MultivariateForecastPoint>> where DataTypeValue: From<X> + From<Y> {
+        let mut sequences = self.channel_points(history)?;
+        if sequences[0].len() < 2 { anyhow::bail!("history length must be >= 2") }
+
+        let mut forecast = Vec::new();
+        let mut context = None;
+        let mut confidence = 1.0f32;
+        while forecast.len() < horizon {
+            let (next_context, points, probability) = match self.next_rows(&sequences, context) {
+                Some(next) => next,
+                None => break
+            };
+            confidence *= probability;
+            forecast.push(MultivariateForecastPoint {
+                x: points[0].x.clone(),
+                y: points.iter().map(|point| point.y.clone()).collect(),
+                confidence
+            });
+            for (sequence, point) in sequences.iter_mut().zip(points) { sequence.push(point); }
+            context = Some(next_context);
+        }
+
+        Ok(forecast)
+    }
+
+    /// Every place in the data where the patterns of all the channels of `query`
+    /// occurred together, the most similar first
+    pub fn find_pattern<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, query: &[(X, Vec<Y>)]
+    ) -> anyhow::Result<Vec<PatternOccurrence>> where DataTypeValue: From<X> + From<Y> {
+        let query = self.channel_points(query)?;
+        if query[0].len() < 2 { anyhow::bail!("query length must be >= 2") }
+        let level = query[0].len() - 1;
+        let max_level = self.channels[0].max_pattern_level();
+        if level > max_level { anyhow::bail!("query length must be <= {}", max_level + 1) }
+
+        let mut kinds = vec![PatternKind::Absolute];
+        if query.iter().flatten().all(|point| point.y.to_f64().is_some()) {
+            kinds.push(PatternKind::Relative);
+        }
+
+        let mut occurrences: Vec<PatternOccurrence> = Vec::new();
+        for kind in kinds {
+            let channel_patterns: Vec<_> = self.channels.iter().zip(&query)
+                .map(|(channel, points)| channel.similar_patterns(kind, points))
+                .collect();
+            for neuron in self.cross_patterns(kind, level, &channel_patterns) {
+                let id = neuron.read().unwrap().id().id;
+                let starts = self.cross_pattern_occurrences.get(&(kind, level, id));
+                for &start in starts.into_iter().flatten() {
+                    if occurrences.iter().any(|occurrence| occurrence.start == start) { continue }
+                    let similarity = self.channels.iter().zip(&query)
+                        .map(|(channel, points)| {
+                            let found = &channel.data[start..=start + level];
+                            channel.pattern_similarity(kind, points, found)
+                        })
+                        .sum::<f32>() / self.channels.len() as f32;
+                    occurrences.push(PatternOccurrence { kind, start, similarity });
+                }
+            }
+        }
+
+        occurrences.sort_by(|a, b| {
+            b.similarity.partial_cmp(&a.similarity).unwrap().then(a.start.cmp(&b.start))
+        });
+        Ok(occurrences)
+    }
+
+    fn channels_len<X, Y>(data: &[(X, Vec<Y>)]) -> anyhow::Result<usize> {
+        let channels_len = match data.first() {
+            Some((_, y)) => y.len(),
+            None => anyhow::bail!("data is empty")
+        };
+        if channels_len == 0 { anyhow::bail!("at least one channel is required") }
+        for (i, (_, y)) in data.iter().enumerate() {
+            if y.len() != channels_len {
+                anyhow::bail!("row {i} has {} channels instead of {channels_len}", y.len())
+            }
+        }
+        Ok(channels_len)
+    }
+
+    /// Points of every channel of `data` sorted by x
+    fn channel_points<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, data: &[(X, Vec<Y>)]
+    ) -> anyhow::Result<Vec<Vec<DataPoint2D>>> where DataTypeValue: From<X> + From<Y> {
+        let channels_len = Self::channels_len(data)?;
+        if channels_len != self.channels.len() {
+            anyhow::bail!("rows must have {} channels, not {channels_len}", self.channels.len())
+        }
+
+        let mut sequences = Vec::new();
+        for (i, channel) in self.channels.iter().enumerate() {
+            let mut points: Vec<DataPoint2D> = data.iter()
+                .map(|(x, y)| {
+                    DataPoint2D {
+                        x: (*dyn_clone::clone_box(x)).into(),
+                        y: (*dyn_clone::clone_box(&y[i])).into()
+                    }
+                }).collect();
+            SMAGDS::sort_by_x(&mut points, |point| &point.x)?;
+            channel.check_data_types(&points)?;
+            sequences.push(points);
+        }
+        Ok(sequences)
+    }
+
+    fn create_cross_neurons(&mut self) {
+        let data_len = self.channels[0].data.len();
+        let max_level = self.channels[0].max_pattern_level();
+        for kind in [PatternKind::Absolute, PatternKind::Relative] {
+            let patterns: Vec<HashMap<(usize, usize), u32>> = self.channels.iter()
+                .map(|channel| {
+                    channel.pattern_occurrences.iter()
+                        .filter(|((k, _, _), _)| *k == kind)
+                        .flat_map(|((_, level, id), starts)| {
+                            starts.iter().map(move |start| ((*level, *start), *id))
+                        })
+                        .collect()
+                }).collect();
+
+            for level in 1..=max_level {
+                for start in 0..data_len {
+                    let inputs: Option<Vec<_>> = self.channels.iter().zip(&patterns)
+                        .map(|(channel, patterns)| {
+                            let id = patterns.get(&(level, start))?;
+                            Some(channel.pattern_neurons(kind, level)?[*id as usize].clone())
+                        }).collect();
+                    let inputs = match inputs { Some(inputs) => inputs, None => continue };
+
+                    let neuron = self.add_cross_neuron(kind, level, &inputs);
+                    let id = neuron.read().unwrap().id().id;
+                    self.cross_pattern_occurrences.entry((kind, level, id)).or_default().push(start);
+                }
+            }
+        }
+    }
+
+    fn add_cross_neuron(
+        &mut self, kind: PatternKind, level: usize, inputs: &[Arc<RwLock<dyn NeuronAsync>>]
+    ) -> Arc<RwLock<dyn NeuronAsync>> {
+        let group_id = self.neuron_groups[&(kind, level)];
+        let level_neurons = self.cross_pattern_neurons.get_mut(&(kind, level)).unwrap();
+        let inputs: Vec<_> = inputs.iter().collect();
+        if let Some(neuron) = SMAGDS::match_pattern_neuron(level_neurons, &inputs) {
+            neuron.write().unwrap().increment_counter();
+            return neuron
+        }
+
+        let neuron = SMAGDS::add_pattern_neuron(
+            &mut self.magds, NeuronID { id: level_neurons.len() as u32, parent_id: group_id }
+        );
+        level_neurons.push(neuron.clone());
+        for input in inputs {
+            input.write().unwrap().connect_bilateral(
+                neuron.clone(), false, ConnectionKind::Defining
+            ).unwrap();
+        }
+        neuron
+    }
+
+    /// Cross-channel patterns of `kind` and `level` defined by one of the `channel_patterns`
+    /// of every channel
+    fn cross_patterns(
+        &self,
+        kind: PatternKind,
+        level: usize,
+        channel_patterns: &[Vec<Arc<RwLock<dyn NeuronAsync>>>]
+    ) -> Vec<Arc<RwLock<dyn NeuronAsync>>> {
+        let (cross_neurons, (first, rest)) = match (
+            self.cross_pattern_neurons.get(&(kind, level)), channel_patterns.split_first()
+        ) {
+            (Some(cross_neurons), Some(patterns)) => (cross_neurons, patterns),
+            _ => return Vec::new()
+        };
+        first.iter()
+            .flat_map(|neuron| neuron.read().unwrap().defined_neurons().to_vec())
+            .filter(|neuron| SMAGDS::is_level_neuron(cross_neurons, neuron))
+            .filter(|neuron| {
+                rest.iter().all(|patterns| patterns.iter().any(|p| SMAGDS::defines(p, neuron)))
+            })
+            .collect()
+    }
+
+    /// Follows `context` one level up, or the longest tail matched in all the channels
+    /// if it can't, absolute patterns are preferred over the relative ones
+    fn next_rows(
+        &self, sequences: &[Vec<DataPoint2D>], context: Option<CrossPatternContext>
+    ) -> Option<(CrossPatternContext, Vec<DataPoint2D>, f32)> {
+        if let Some(context) = context {
+            if let Some(next) = self.continue_cross_pattern(sequences, context) {
+                return Some(next)
+            }
+        }
+
+        let max_level = self.channels[0].max_pattern_level();
+        if max_level == 0 { return None }
+        let mut kinds = vec![PatternKind::Absolute];
+        if sequences.iter().all(|sequence| sequence.last().and_then(|p| p.y.to_f64()).is_some()) {
+            kinds.push(PatternKind::Relative);
+        }
+        let len = sequences[0].len();
+        for kind in kinds {
+            let min_level = if kind == PatternKind::Absolute { 0 } else { 1 };
+            for level in (min_level..=usize::min(max_level - 1, len - 1)).rev() {
+                let neurons: Option<Vec<_>> = self.channels.iter().zip(sequences)
+                    .map(|(channel, sequence)| channel.match_points(kind, &sequence[len - level - 1..]))
+                    .collect();
+                let neurons = match neurons { Some(neurons) => neurons, None => continue };
+                let context = CrossPatternContext { neurons, level, kind };
+                if let Some(next) = self.continue_cross_pattern(sequences, context) {
+                    return Some(next)
+                }
+            }
+        }
+        None
+    }
+
+    /// The most frequent cross-channel pattern made of continuations of every channel
+    /// pattern of `context`, along with the points it adds and its share among all such patterns
+    fn continue_cross_pattern(
+        &self, sequences: &[Vec<DataPoint2D>], context: CrossPatternContext
+    ) -> Option<(CrossPatternContext, Vec<DataPoint2D>, f32)> {
+        let CrossPatternContext { neurons, level, kind } = context;
+        let continuations: Vec<Vec<_>> = self.channels.iter().zip(&neurons)
+            .map(|(channel, neuron)| channel.continuations(kind, level, neuron))
+            .collect();
+        let candidates = self.cross_patterns(kind, level + 1, &continuations);
+
+        let total: usize = candidates.iter().map(|n| n.read().unwrap().counter()).sum();
+        let best = candidates.into_iter().max_by_key(|n| n.read().unwrap().counter())?;
+        let probability = best.read().unwrap().counter() as f32 / total as f32;
+
+        let mut next_neurons = Vec::new();
+        let mut points = Vec::new();
+        let channels = self.channels.iter().zip(sequences).zip(&continuations);
+        for ((channel, sequence), continuations) in channels {
+            let neuron = continuations.iter().find(|neuron| SMAGDS::defines(neuron, &best))?.clone();
+            points.push(channel.pattern_point(kind, &neuron, sequence.last()?)?);
+            next_neurons.push(neuron);
+        }
+        let x = points[0].x.clone();
+        for point in &mut points { point.x = x.clone(); }
+
+        let context = CrossPatternContext { neurons: next_neurons, level: level + 1, kind };
+        Some((context, points, probability))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use witchnet_common::{ data::DataTypeValue, sensor::SensorAsync };
+
+    use super::MultivariateSMAGDS;
+    use super::super::smagds::{ SMAGDS, PatternKind };
+
+    fn rows(offset: f64) -> Vec<(i32, Vec<f64>)> {
+        (1..=40).map(|x| (x, vec![(x % 4) as f64 + offset, (x % 2) as f64 * 10.0])).collect()
+    }
+
+    #[test]
+    fn new() {
+        let smagds = MultivariateSMAGDS::new(&rows(0.0)).unwrap();
+        assert_eq!(smagds.channels.len(), 2);
+        assert_eq!(smagds.channel_data(1).unwrap().len(), 40);
+        assert!(smagds.channel_data(2).is_none());
+        assert!(!smagds.cross_pattern_neurons[&(PatternKind::Absolute, 1)].is_empty());
+
+        let magds = &smagds.magds;
+        assert_eq!(magds.sensor_ids("x interval").unwrap().len(), 1);
+        assert!(magds.sensor_ids("channel 0 x interval").is_none());
+        assert_eq!(magds.sensor_ids("channel 1 y").unwrap().len(), 1);
+        let x_interval = &smagds.channels[1].sensors.x_interval;
+        assert!(Arc::ptr_eq(&smagds.channels[0].sensors.x_interval, x_interval));
+        let counter = |smagds: &SMAGDS| -> usize {
+            smagds.sensors.x_interval.read().unwrap().neurons().iter()
+                .map(|neuron| neuron.read().unwrap().counter())
+                .sum()
+        };
+        let channel: Vec<(i32, f64)> = rows(0.0).into_iter().map(|(x, y)| (x, y[0])).collect();
+        assert_eq!(counter(&smagds.channels[1]), counter(&SMAGDS::new(&channel).unwrap()));
+
+        for neuron in &smagds.cross_pattern_neurons[&(PatternKind::Relative, 2)] {
+            let id = neuron.read().unwrap().id();
+            assert!(magds.neuron_from_id(&id).is_some());
+        }
+        for channel in &smagds.channels {
+            for neuron in channel.pattern_neurons(PatternKind::Absolute, 1).unwrap() {
+                let id = neuron.read().unwrap().id();
+                assert!(magds.neuron_from_id(&id).is_some());
+            }
+        }
+
+        assert!(MultivariateSMAGDS::new(&[(1, vec![1.0])]).is_err());
+        assert!(MultivariateSMAGDS::new(&[(1, Vec::<f64>::new()), (2, Vec::new())]).is_err());
+        assert!(MultivariateSMAGDS::new(&[(1, vec![1.0, 2.0]), (2, vec![1.0])]).is_err());
+    }
+
+    #[test]
+    fn add() {
+        let data = rows(0.0);
+        let mut smagds = MultivariateSMAGDS::new(&data[20..]).unwrap();
+        smagds.add(&data[..20]).unwrap();
+        assert_eq!(smagds.channel_data(0).unwrap().len(), 40);
+        assert_eq!(smagds.channel_data(0).unwrap()[0].x, DataTypeValue::I32(1));
+
+        let query = [(5, vec![1.0, 10.0]), (6, vec![2.0, 0.0]), (7, vec![3.0, 10.0])];
+        let occurrences = smagds.find_pattern(&query).unwrap();
+        let all = MultivariateSMAGDS::new(&data).unwrap().find_pattern(&query).unwrap();
+        assert_eq!(occurrences, all);
+
+        assert!(smagds.add(&[(41, vec![1.0])]).is_err());
+        assert!(smagds.add(&[(41.0, vec![1.0, 10.0])]).is_err());
+    }
+
+    #[test]
+    fn forecast() {
+        let data = rows(0.0);
+        let smagds = MultivariateSMAGDS::new(&data).unwrap();
+
+        let forecast = smagds.forecast(&data[32..], 6).unwrap();
+        assert_eq!(forecast.len(), 6);
+        for (i, row) in forecast.iter().enumerate() {
+            let x = 41 + i as i32;
+            assert_eq!(row.x, DataTypeValue::I32(x));
+            assert_eq!(
+                row.y,
+                vec![DataTypeValue::F64((x % 4) as f64), DataTypeValue::F64((x % 2) as f64 * 10.0)]
+            );
+            assert_eq!(row.confidence, 1.0);
+        }
+
+        let shifted = rows(10.0);
+        let forecast = smagds.forecast(&shifted[34..], 2).unwrap();
+        let y: Vec<Vec<f64>> = forecast.iter()
+            .map(|row| row.y.iter().map(|y| y.to_f64().unwrap()).collect())
+            .collect();
+        assert_eq!(y, vec![vec![11.0, 10.0], vec![12.0, 0.0]]);
+
+        assert!(smagds.forecast(&[(1, vec![1.0])], 2).is_err());
+    }
+
+    #[test]
+    fn find_pattern() {
+        let smagds = MultivariateSMAGDS::new(&rows(0.0)).unwrap();
+
+        let query = [(5, vec![1.0, 10.0]), (6, vec![2.0, 0.0]), (7, vec![3.0, 10.0])];
+        let occurrences = smagds.find_pattern(&query).unwrap();
+        let absolute: Vec<usize> = occurrences.iter()
+            .filter(|o| o.kind == PatternKind::Absolute)
+            .map(|o| o.start)
+            .collect();
+        assert_eq!(absolute, (0..=36).step_by(4).collect::<Vec<_>>());
+        assert!(occurrences.iter().all(|o| o.similarity == 1.0));
+
+        let query = [(5, vec![1.0, 0.0]), (6, vec![2.0, 10.0]), (7, vec![3.0, 0.0])];
+        let occurrences = smagds.find_pattern(&query).unwrap();
+        assert_eq!(occurrences.len(), 9);
+        assert!(occurrences.iter().all(|o| o.kind == PatternKind::Relative && o.start % 4 == 3));
+
+        let query = [(5, vec![1.0, 10.0]), (6, vec![2.0, 10.0]), (7, vec![3.0, 10.0])];
+        assert!(smagds.find_pattern(&query).unwrap().is_empty());
+        assert!(smagds.find_pattern(&[(1, vec![1.0, 10.0])]).is_err());
+    }
+}
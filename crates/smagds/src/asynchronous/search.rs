@@ -0,0 +1,269 @@
+use std::sync::{ Arc, RwLock };
+
+use witchnet_common::{
+    data::{ DataTypeValue, DataPoint2D, DataDeductor },
+    sensor::{ SensorData, SensorAsync },
+    neuron::NeuronAsync
+};
+
+use super::smagds::{ SMAGDS, PatternKind };
+
+/// Index in `SMAGDS::data` at which a pattern similar to the query starts
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternOccurrence {
+    pub kind: PatternKind,
+    pub start: usize,
+    pub similarity: f32
+}
+
+impl SMAGDS {
+    /// Every place in the data where the absolute or relative pattern of `query` occurred,
+    /// the most similar first, places matching both kinds are reported as absolute ones
+    pub fn find_pattern<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &self, query: &[(X, Y)]
+    ) -> anyhow::Result<Vec<PatternOccurrence>> where DataTypeValue: From<X> + From<Y> {
+        let mut query = Self::convert_data(query);
+        Self::sort_by_x(&mut query, |point| &point.x)?;
+        self.find_points(&query)
+    }
+
+    pub(crate) fn find_points(
+        &self, query: &[DataPoint2D]
+    ) -> anyhow::Result<Vec<PatternOccurrence>> {
+        if query.len() < 2 { anyhow::bail!("query length must be >= 2") }
+        let level = query.len() - 1;
+        if level > self.max_pattern_level() {
+            anyhow::bail!("query length must be <= {}", self.max_pattern_level() + 1)
+        }
+        self.check_data_types(query)?;
+
+        let mut kinds = vec![PatternKind::Absolute];
+        if query.iter().all(|point| point.y.to_f64().is_some()) {
+            kinds.push(PatternKind::Relative);
+        }
+
+        let mut occurrences: Vec<PatternOccurrence> = Vec::new();
+        for kind in kinds {
+            for neuron in self.similar_patterns(kind, query) {
+                let id = neuron.read().unwrap().id().id;
+                let starts = self.pattern_occurrences.get(&(kind, level, id)).into_iter().flatten();
+                for &start in starts {
+                    if occurrences.iter().any(|occurrence| occurrence.start == start) { continue }
+                    let found = &self.data[start..=start + level];
+                    let similarity = self.pattern_similarity(kind, query, found);
+                    occurrences.push(PatternOccurrence { kind, start, similarity });
+                }
+            }
+        }
+
+        occurrences.sort_by(|a, b| {
+            b.similarity.partial_cmp(&a.similarity).unwrap().then(a.start.cmp(&b.start))
+        });
+        Ok(occurrences)
+    }
+
+    /// Pattern neurons of level `query.len() - 1` whose sensor neurons are activated by
+    /// the steps of `query` at least `signal_similarity_threshold` on average, found level
+    /// by level from the patterns that can still reach it
+    pub(crate) fn similar_patterns(
+        &self, kind: PatternKind, query: &[DataPoint2D]
+    ) -> Vec<Arc<RwLock<dyn NeuronAsync>>> {
+        let entry_inputs = if kind == PatternKind::Absolute { 1 } else { 0 };
+        let inputs_len = (2 * (query.len() - 1) + entry_inputs) as f32;
+        let th = self.params.signal_similarity_threshold;
+
+        let mut candidates: Vec<(Arc<RwLock<dyn NeuronAsync>>, f32)> = Vec::new();
+        for (i, window) in query.windows(2).enumerate() {
+            let level_neurons = match self.pattern_neurons(kind, i + 1) {
+                Some(level_neurons) => level_neurons,
+                None => return Vec::new()
+            };
+
+            let x_diff = window[1].x.distance(&window[0].x).into();
+            let mut contexts = vec![(&self.sensors.x_interval, x_diff)];
+            match kind {
+                PatternKind::Absolute => contexts.push((&self.sensors.y, window[1].y.clone())),
+                PatternKind::Relative => {
+                    let y_diff = Self::difference(&window[0].y, &window[1].y).into();
+                    contexts.push((&self.sensors.y_interval, y_diff));
+                }
+            }
+            if i == 0 && kind == PatternKind::Absolute {
+                contexts.push((&self.sensors.y_entry, window[0].y.clone()));
+            }
+            let contexts: Vec<_> = contexts.into_iter()
+                .map(|(sensor, value)| {
+                    (sensor.read().unwrap().id(), Self::sensor_context(sensor, &value))
+                })
+                .collect();
+            let step_activation = |neuron: &Arc<RwLock<dyn NeuronAsync>>| -> f32 {
+                neuron.read().unwrap().explain().iter()
+                    .map(|sn| {
+                        let sn = sn.read().unwrap();
+                        contexts.iter()
+                            .find(|(sensor_id, _)| *sensor_id == sn.id().parent_id)
+                            .map_or(0.0, |(_, context)| sn.activation_in(context))
+                    })
+                    .sum()
+            };
+
+            let extended: Vec<_> = if i == 0 {
+                level_neurons.iter()
+                    .map(|neuron| (neuron.clone(), step_activation(neuron)))
+                    .collect()
+            } else {
+                candidates.iter()
+                    .flat_map(|(base, activation)| {
+                        base.read().unwrap().defined_neurons().iter()
+                            .filter(|neuron| Self::is_level_neuron(level_neurons, neuron))
+                            .map(|neuron| (neuron.clone(), activation + step_activation(neuron)))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            };
+
+            let missing_inputs = inputs_len - (2 * (i + 1) + entry_inputs) as f32;
+            candidates = extended.into_iter()
+                .filter(|(_, activation)| (activation + missing_inputs) / inputs_len >= th)
+                .collect();
+        }
+
+        candidates.into_iter().map(|(neuron, _)| neuron).collect()
+    }
+
+    /// Mean of `1 - distance / range` over the x intervals and the values (absolute)
+    /// or value changes (relative) of two sequences of the same length
+    pub(crate) fn pattern_similarity(
+        &self, kind: PatternKind, query: &[DataPoint2D], found: &[DataPoint2D]
+    ) -> f32 {
+        let x_range = self.spreads.x_interval;
+        let y_range = match kind {
+            PatternKind::Absolute => self.spreads.y,
+            PatternKind::Relative => self.spreads.y_interval
+        };
+
+        let mut similarities = Vec::new();
+        if kind == PatternKind::Absolute {
+            similarities.push(similarity(query[0].y.distance(&found[0].y), y_range));
+        }
+        for (q, f) in query.windows(2).zip(found.windows(2)) {
+            let x_distance = q[1].x.distance(&q[0].x) - f[1].x.distance(&f[0].x);
+            similarities.push(similarity(x_distance.abs(), x_range));
+            let y_distance = match kind {
+                PatternKind::Absolute => q[1].y.distance(&f[1].y),
+                PatternKind::Relative => {
+                    (Self::difference(&q[0].y, &q[1].y) - Self::difference(&f[0].y, &f[1].y)).abs()
+                }
+            };
+            similarities.push(similarity(y_distance, y_range));
+        }
+        similarities.iter().sum::<f32>() / similarities.len() as f32
+    }
+}
+
+/// Spreads of the x intervals, the values and the value changes of the data
+#[derive(Debug, Clone)]
+pub(crate) struct SMAGDSSpreads {
+    pub(crate) x_interval: f64,
+    pub(crate) y: f64,
+    pub(crate) y_interval: f64
+}
+
+impl SMAGDSSpreads {
+    pub(crate) fn new(data: &[DataPoint2D]) -> Self {
+        Self {
+            x_interval: spread(data.windows(2).map(|w| w[1].x.distance(&w[0].x))),
+            y: spread(data.iter().filter_map(|point| point.y.to_f64())),
+            y_interval: spread(data.windows(2).map(|w| SMAGDS::difference(&w[0].y, &w[1].y)))
+        }
+    }
+}
+
+fn spread(values: impl Iterator<Item = f64>) -> f64 {
+    let (min, max) = values.fold(
+        (f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v))
+    );
+    if min.is_finite() && max.is_finite() { max - min } else { 1.0 }
+}
+
+fn similarity(distance: f64, range: f64) -> f32 {
+    if range > 0.0 {
+        f64::max(1.0 - distance / range, 0.0) as f32
+    } else if distance == 0.0 { 1.0 } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::smagds::{ SMAGDS, PatternKind };
+
+    #[test]
+    fn find_pattern() {
+        let data: Vec<(i32, f64)> = (1..=40).map(|x| (x, (x % 4) as f64)).collect();
+        let smagds = SMAGDS::new(&data).unwrap();
+
+        let occurrences = smagds.find_pattern(&[(5, 1.0), (6, 2.0), (7, 3.0)]).unwrap();
+        assert_eq!(occurrences.len(), 19);
+        assert!(occurrences.iter().all(|o| o.similarity == 1.0));
+        let absolute: Vec<usize> = occurrences.iter()
+            .filter(|o| o.kind == PatternKind::Absolute)
+            .map(|o| o.start)
+            .collect();
+        assert_eq!(absolute, (0..=36).step_by(4).collect::<Vec<_>>());
+        assert!(
+            occurrences.iter()
+                .filter(|o| o.kind == PatternKind::Relative)
+                .all(|o| o.start % 4 == 3)
+        );
+
+        let occurrences = smagds.find_pattern(&[(5, 1.0), (6, 2.02), (7, 3.0)]).unwrap();
+        assert_eq!(occurrences.len(), 19);
+        assert!(occurrences.iter().all(|o| o.similarity < 1.0 && o.similarity > 0.99));
+        assert_eq!(occurrences[0].kind, PatternKind::Absolute);
+
+        let occurrences = smagds.find_pattern(&[(50, 11.0), (51, 12.0), (52, 13.0)]).unwrap();
+        assert_eq!(occurrences.len(), 19);
+        assert!(occurrences.iter().all(|o| o.kind == PatternKind::Relative));
+        assert_eq!(occurrences[0].start, 0);
+        assert_eq!(occurrences[1].start, 3);
+
+        assert!(smagds.find_pattern(&[(1, 1.0)]).is_err());
+        assert!(smagds.find_pattern(&[(1, 7.0), (2, 7.0)]).unwrap().is_empty());
+        let too_long: Vec<(i32, f64)> = (1..=12).map(|x| (x, 1.0)).collect();
+        assert!(smagds.find_pattern(&too_long).is_err());
+    }
+
+    #[test]
+    fn find_pattern_after_add() {
+        let data: Vec<(i32, f64)> = (1..=40).map(|x| (x, (x % 4) as f64)).collect();
+        let mut smagds = SMAGDS::new(&data[20..]).unwrap();
+        smagds.add(&data[..20]).unwrap();
+
+        let query = [(5, 1.0), (6, 2.0), (7, 3.0)];
+        let occurrences = smagds.find_pattern(&query).unwrap();
+        assert_eq!(occurrences, SMAGDS::new(&data).unwrap().find_pattern(&query).unwrap());
+        for occurrence in occurrences.iter().filter(|o| o.kind == PatternKind::Absolute) {
+            let found: Vec<f64> = smagds.data[occurrence.start..=occurrence.start + 2].iter()
+                .map(|point| point.y.to_f64().unwrap())
+                .collect();
+            assert_eq!(found, vec![1.0, 2.0, 3.0]);
+        }
+    }
+
+    #[test]
+    fn find_similar_patterns() {
+        let data: Vec<(i32, f64)> = (1..=40)
+            .map(|x| (x, if x % 8 == 2 { 2.3 } else { (x % 4) as f64 }))
+            .collect();
+        let smagds = SMAGDS::new(&data).unwrap();
+
+        let occurrences = smagds.find_pattern(&[(5, 1.0), (6, 2.1), (7, 3.0)]).unwrap();
+        let absolute: Vec<_> = occurrences.iter()
+            .filter(|o| o.kind == PatternKind::Absolute)
+            .collect();
+        assert_eq!(absolute.len(), 10);
+        assert!(absolute[..5].iter().all(|o| o.start % 8 == 4));
+        assert!(absolute[5..].iter().all(|o| o.start % 8 == 0));
+        assert!(absolute[0].similarity > absolute[5].similarity);
+        assert!(occurrences.iter().all(|o| o.similarity < 1.0));
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     sync::{ Arc, RwLock },
-    collections::HashMap
+    collections::HashMap,
+    cmp::Ordering
 };
 
 use magds::{
@@ -14,13 +15,15 @@ use magds::{
 use witchnet_common::{
     data::{ DataTypeValue, DataPoint2D, DataType, DataDeductor },
     sensor::{ SensorData, SensorAsync }, 
-    neuron::{ NeuronAsync, NeuronID }, 
+    neuron::{ NeuronAsync, NeuronID, ActivationContext }, 
     connection::{
         ConnectionKind,
         collective::defining::ConstantOneWeightAsync
     }
 };
 
+use super::search::SMAGDSSpreads;
+
 #[derive(Debug, Clone)]
 pub struct SMAGDSParams {
     pub max_pattern_length: Option<f64>,
@@ -40,16 +43,23 @@ impl Default for SMAGDSParams {
     }
 }
 
+/// Absolute patterns are made of the values, relative ones of the changes between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternKind {
+    Absolute,
+    Relative
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct SMAGDSSensors {
-    x_interval: Arc<RwLock<SensorConatiner>>,
-    y: Arc<RwLock<SensorConatiner>>,
-    y_interval: Arc<RwLock<SensorConatiner>>,
-    y_entry: Arc<RwLock<SensorConatiner>>,
-    same_absolute_patterns_interval: Arc<RwLock<SensorConatiner>>,
-    same_relative_patterns_interval: Arc<RwLock<SensorConatiner>>,
-    different_absolute_patterns_interval: Arc<RwLock<SensorConatiner>>,
-    different_relative_patterns_interval: Arc<RwLock<SensorConatiner>>
+    pub(crate) x_interval: Arc<RwLock<SensorConatiner>>,
+    pub(crate) y: Arc<RwLock<SensorConatiner>>,
+    pub(crate) y_interval: Arc<RwLock<SensorConatiner>>,
+    pub(crate) y_entry: Arc<RwLock<SensorConatiner>>,
+    pub(crate) same_absolute_patterns_interval: Arc<RwLock<SensorConatiner>>,
+    pub(crate) same_relative_patterns_interval: Arc<RwLock<SensorConatiner>>,
+    pub(crate) different_absolute_patterns_interval: Arc<RwLock<SensorConatiner>>,
+    pub(crate) different_relative_patterns_interval: Arc<RwLock<SensorConatiner>>
 }
 
 #[derive(Debug, Clone)]
@@ -70,6 +80,9 @@ pub struct SMAGDS {
     pub neuron_groups: SMAGDSNeuronGropuIds,
     pub(crate) absolute_pattern_neurons: HashMap<usize, Vec<Arc<RwLock<dyn NeuronAsync>>>>,
     pub(crate) relative_pattern_neurons: HashMap<usize, Vec<Arc<RwLock<dyn NeuronAsync>>>>,
+    pub(crate) pattern_occurrences: HashMap<(PatternKind, usize, u32), Vec<usize>>,
+    pub(crate) interval_neurons: HashMap<u32, Vec<Arc<RwLock<dyn NeuronAsync>>>>,
+    pub(crate) spreads: SMAGDSSpreads,
     pub params: SMAGDSParams
 }
 
@@ -84,90 +97,158 @@ impl SMAGDS {
         data: &[(X, Y)], params: SMAGDSParams
     ) -> anyhow::Result<Self> where DataTypeValue: From<X> + From<Y> {
         if data.len() < 2 { anyhow::bail!("data length must be >= 2") }
+        let mut data = Self::convert_data(data);
+        Self::sort_by_x(&mut data, |point| &point.x)?;
+        Ok(Self::build(data, params))
+    }
 
-        let mut converted_data: Vec<DataPoint2D> = data.into_iter()
-            .map(|(x, y)| {
-                DataPoint2D {
-                    x: (*dyn_clone::clone_box(x)).into(), 
-                    y: (*dyn_clone::clone_box(y)).into()
-                }
-            }).collect();
-        converted_data.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+    /// Adds `data` to the loaded one and rebuilds the model, so that all the patterns
+    /// and their occurrences describe the merged data
+    pub fn add<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        &mut self, data: &[(X, Y)]
+    ) -> anyhow::Result<()> where DataTypeValue: From<X> + From<Y> {
+        if data.is_empty() { return Ok(()) }
+
+        let mut converted_data = Self::convert_data(data);
+        self.check_data_types(&converted_data)?;
+        Self::sort_by_x(&mut converted_data, |point| &point.x)?;
+
+        let mut merged_data = std::mem::take(&mut self.data);
+        merged_data.append(&mut converted_data);
+        *self = Self::build(merged_data, self.params.clone());
+
+        Ok(())
+    }
 
-        let mut magds = MAGDS::new();
+    fn build(data: Vec<DataPoint2D>, params: SMAGDSParams) -> Self {
+        Self::build_in(MAGDS::new(), data, params, "", None)
+    }
+
+    /// Builds the model into `magds` next to the sensors and neuron groups it already has,
+    /// naming its own ones with `prefix`, the x intervals of `x_interval` are used as they are
+    /// if it's shared with another model of the same x values
+    pub(crate) fn build_in(
+        mut magds: MAGDS,
+        mut data: Vec<DataPoint2D>,
+        params: SMAGDSParams,
+        prefix: &str,
+        x_interval: Option<Arc<RwLock<SensorConatiner>>>
+    ) -> Self {
+        data.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+
+        let is_x_interval_shared = x_interval.is_some();
         let absolute_pattern_neurons = Self::prepare_pattern_level_neurons(
             params.max_pattern_level, data.len()
         );
         let mut smagds = Self {
-            sensors: Self::prepare_sensory_fields(&mut magds, &converted_data),
+            sensors: Self::prepare_sensory_fields(&mut magds, &data, prefix, x_interval),
             neuron_groups: Self::prepare_neuron_gropus(
-                &mut magds, params.max_pattern_level, data.len()
+                &mut magds, params.max_pattern_level, data.len(), prefix
             ),
+            spreads: SMAGDSSpreads::new(&data),
             params,
             magds,
-            data: converted_data,
+            data,
             relative_pattern_neurons: absolute_pattern_neurons.clone(),
-            absolute_pattern_neurons
+            absolute_pattern_neurons,
+            pattern_occurrences: HashMap::new(),
+            interval_neurons: HashMap::new()
         };
 
-        smagds.create_neurons();
+        smagds.create_neurons(!is_x_interval_shared);
+        smagds.create_interval_neurons();
 
-        Ok(smagds)
+        smagds
     }
 
-    pub fn add<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
-        &mut self, data: &[(X, Y)]
-    ) -> anyhow::Result<()> where DataTypeValue: From<X> + From<Y> {
-        if data.is_empty() { return Ok(()) }
-
-        let mut converted_data: Vec<DataPoint2D> = data.into_iter()
+    pub(crate) fn convert_data<X: SensorData + DataDeductor, Y: SensorData + DataDeductor>(
+        data: &[(X, Y)]
+    ) -> Vec<DataPoint2D> where DataTypeValue: From<X> + From<Y> {
+        data.iter()
             .map(|(x, y)| {
                 DataPoint2D {
                     x: (*dyn_clone::clone_box(x)).into(), 
                     y: (*dyn_clone::clone_box(y)).into()
                 }
-            }).collect();
-        
-        let first_converted = &converted_data[0];
-        let is_loaded_data_empty = self.data.is_empty();
-        if is_loaded_data_empty || first_converted.x.is_type_same_as(&self.data[0].x) {
-            if is_loaded_data_empty || first_converted.y.is_type_same_as(&self.data[0].y) {
-                self.data.append(&mut converted_data);
-                self.data.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
-            } else {
-                anyhow::bail!(
-                    "y: input data type {} and loaded data type {} are different", 
-                    DataType::from(&first_converted.y),
-                    DataType::from(&self.data[0].y),
-                )
-            }
-        } else { 
+            }).collect()
+    }
+
+    /// Sorts `items` by their x values, fails if some of them cannot be compared like NaN
+    pub(crate) fn sort_by_x<T>(
+        items: &mut [T], x: impl Fn(&T) -> &DataTypeValue
+    ) -> anyhow::Result<()> {
+        let mut is_comparable = true;
+        items.sort_by(|a, b| {
+            x(a).partial_cmp(x(b)).unwrap_or_else(|| {
+                is_comparable = false;
+                Ordering::Equal
+            })
+        });
+        if !is_comparable { anyhow::bail!("x values must be comparable, NaN cannot be sorted") }
+        Ok(())
+    }
+
+    /// Fails if `points` don't have the data types of the loaded data
+    pub(crate) fn check_data_types(&self, points: &[DataPoint2D]) -> anyhow::Result<()> {
+        let (first, loaded) = match (points.first(), self.data.first()) {
+            (Some(first), Some(loaded)) => (first, loaded),
+            _ => return Ok(())
+        };
+        if !first.x.is_type_same_as(&loaded.x) {
             anyhow::bail!(
-                "x: input data type {} and loaded data type {} are different", 
-                DataType::from(&first_converted.x),
-                DataType::from(&self.data[0].x),
+                "x: input data type {} and loaded data type {} are different",
+                DataType::from(&first.x),
+                DataType::from(&loaded.x)
+            )
+        }
+        if !first.y.is_type_same_as(&loaded.y) {
+            anyhow::bail!(
+                "y: input data type {} and loaded data type {} are different",
+                DataType::from(&first.y),
+                DataType::from(&loaded.y)
             )
         }
-
         Ok(())
     }
 
-    fn prepare_sensory_fields(magds: &mut MAGDS, data: &[DataPoint2D]) -> SMAGDSSensors {
-        let x_data_type: DataType = (&data[0].x).into();
+    pub(crate) fn pattern_neurons(
+        &self, kind: PatternKind, level: usize
+    ) -> Option<&Vec<Arc<RwLock<dyn NeuronAsync>>>> {
+        match kind {
+            PatternKind::Absolute => self.absolute_pattern_neurons.get(&level),
+            PatternKind::Relative => self.relative_pattern_neurons.get(&level)
+        }
+    }
+
+    pub(crate) fn max_pattern_level(&self) -> usize { self.absolute_pattern_neurons.len() }
+
+    fn prepare_sensory_fields(
+        magds: &mut MAGDS,
+        data: &[DataPoint2D],
+        prefix: &str,
+        x_interval: Option<Arc<RwLock<SensorConatiner>>>
+    ) -> SMAGDSSensors {
         let y_data_type: DataType = (&data[0].y).into();
 
-        let (x_interval, _) = magds.create_sensor("x interval", DataType::F64);
-        let (y, _) = magds.create_sensor("y", y_data_type);
-        let (y_interval, _) = magds.create_sensor("y interval", DataType::F64);
-        let (y_entry, _) = magds.create_sensor("y entry", y_data_type);
-        let (same_absolute_patterns_interval, _) = 
-            magds.create_sensor("same absolute patterns interval", x_data_type);
-        let (same_relative_patterns_interval, _) = 
-            magds.create_sensor("same relative patterns interval", y_data_type);
-        let (different_absolute_patterns_interval, _) = 
-            magds.create_sensor("different absolute patterns interval", x_data_type);
-        let (different_relative_patterns_interval, _) = 
-            magds.create_sensor("different relative patterns interval", y_data_type);
+        // x values are the same for all the models sharing `x_interval`, so it has no prefix
+        let x_interval = match x_interval {
+            Some(x_interval) => x_interval,
+            None => magds.create_sensor("x interval", DataType::F64).0
+        };
+        let mut create_sensor = |name: &str, data_type| {
+            magds.create_sensor(&format!("{prefix}{name}"), data_type).0
+        };
+        let y = create_sensor("y", y_data_type);
+        let y_interval = create_sensor("y interval", DataType::F64);
+        let y_entry = create_sensor("y entry", y_data_type);
+        let same_absolute_patterns_interval = 
+            create_sensor("same absolute patterns interval", DataType::F64);
+        let same_relative_patterns_interval = 
+            create_sensor("same relative patterns interval", DataType::F64);
+        let different_absolute_patterns_interval = 
+            create_sensor("different absolute patterns interval", DataType::F64);
+        let different_relative_patterns_interval = 
+            create_sensor("different relative patterns interval", DataType::F64);
 
         SMAGDSSensors {
             x_interval,
@@ -182,44 +263,45 @@ impl SMAGDS {
     }
 
     fn prepare_neuron_gropus(
-        magds: &mut MAGDS, max_pattern_level: usize, data_len: usize
+        magds: &mut MAGDS, max_pattern_level: usize, data_len: usize, prefix: &str
     ) -> SMAGDSNeuronGropuIds {
+        let first_id = magds.neuron_group_ids().into_iter().max().unwrap_or(0);
         let mut ids = SMAGDSNeuronGropuIds {
             absolute_pattern_level: HashMap::new(),
             relative_pattern_level: HashMap::new(),
-            same_absolute_patterns_interval: 1,
-            same_relative_patterns_interval: 2,
-            different_absolute_patterns_interval: 3,
-            different_relative_patterns_interval: 4
+            same_absolute_patterns_interval: first_id + 1,
+            same_relative_patterns_interval: first_id + 2,
+            different_absolute_patterns_interval: first_id + 3,
+            different_relative_patterns_interval: first_id + 4
         };
 
         let corrected_max_pattern_level = usize::min(max_pattern_level, data_len);
         for lvl in 1..=corrected_max_pattern_level {
-            let absolute_id = 4 + lvl as u32;
-            let relative_id = 4 + corrected_max_pattern_level as u32 + lvl as u32;
+            let absolute_id = first_id + 4 + lvl as u32;
+            let relative_id = first_id + 4 + corrected_max_pattern_level as u32 + lvl as u32;
             ids.absolute_pattern_level.insert(lvl, absolute_id);
             ids.relative_pattern_level.insert(lvl, relative_id);
-            magds.add_neuron_group(&format!("absolute pattern level {lvl}"), Some(absolute_id));
-            magds.add_neuron_group(&format!("relative pattern level {lvl}"), Some(relative_id));
+            magds.add_neuron_group(
+                &format!("{prefix}absolute pattern level {lvl}"), Some(absolute_id)
+            );
+            magds.add_neuron_group(
+                &format!("{prefix}relative pattern level {lvl}"), Some(relative_id)
+            );
+        }
+        for (name, id) in [
+            ("same absolute patterns interval", ids.same_absolute_patterns_interval),
+            ("same relative patterns interval", ids.same_relative_patterns_interval),
+            ("different absolute patterns interval", ids.different_absolute_patterns_interval),
+            ("different relative patterns interval", ids.different_relative_patterns_interval)
+        ] {
+            magds.add_neuron_group(&format!("{prefix}{name}"), Some(id));
         }
-        magds.add_neuron_group(
-            "same absolute patterns interval", Some(ids.same_absolute_patterns_interval)
-        );
-        magds.add_neuron_group(
-            "same relative patterns interval", Some(ids.same_relative_patterns_interval)
-        );
-        magds.add_neuron_group(
-            "different absolute patterns interval", Some(ids.different_absolute_patterns_interval)
-        );
-        magds.add_neuron_group(
-            "different relative patterns interval", Some(ids.different_relative_patterns_interval)
-        );
 
         ids
     }
 
-    fn create_neurons(&mut self) {
-        self.insert_data_to_sensors();
+    fn create_neurons(&mut self, insert_x_intervals: bool) {
+        self.insert_data_to_sensors(insert_x_intervals);
 
         let mut magds = &mut self.magds;
         
@@ -241,6 +323,7 @@ impl SMAGDS {
         let relative_pattern_id = &mut self.neuron_groups.relative_pattern_level;
         let mut absolute_pattern_neurons = &mut self.absolute_pattern_neurons;
         let mut relative_pattern_neurons = &mut self.relative_pattern_neurons;
+        let occurrences = &mut self.pattern_occurrences;
         
         let patterns: HashMap<usize, Arc<RwLock<dyn NeuronAsync>>> = HashMap::new();
         let th = *signal_similarity_threshold;
@@ -248,7 +331,7 @@ impl SMAGDS {
             let first_point = &data[i - 1]; let y1 = &first_point.y;
             let second_point = &data[i]; let y2 = &second_point.y;
             let x_diff = second_point.x.distance(&first_point.x);
-            let y_diff = second_point.y.distance(&first_point.y);
+            let y_diff = Self::difference(y1, y2);
             
             let (x_diff_sn, _) = x_interval.fuzzy_search(&x_diff.into(), th, false).unwrap();
             let (y_entry_sn, _) = y_entry.fuzzy_search(&y1.clone().into(), th, false).unwrap();
@@ -263,19 +346,25 @@ impl SMAGDS {
             );
             let relative_pattern_lvl1_neuron = Self::add_relative_pattern_neuron(
                 magds, 1,
-                absolute_pattern_neurons, &self.neuron_groups,
+                relative_pattern_neurons, &self.neuron_groups,
                 &x_diff_sn, None, &y_diff_sn,
                 *epsilon
             );
+            Self::add_occurrence(
+                occurrences, PatternKind::Absolute, 1, &absolute_pattern_lvl1_neuron, i - 1
+            );
+            Self::add_occurrence(
+                occurrences, PatternKind::Relative, 1, &relative_pattern_lvl1_neuron, i - 1
+            );
 
             let mut current_pattern_len = x_diff;
             let mut current_absolute_pattern = absolute_pattern_lvl1_neuron;
             let mut current_relative_pattern = relative_pattern_lvl1_neuron;
             for j in (i + 1)..usize::min(i + max_pattern_level, data.len()) {
-                let first_point = &data[i - 1];
-                let second_point = &data[i]; let y2 = &second_point.y;
+                let first_point = &data[j - 1]; let y1 = &first_point.y;
+                let second_point = &data[j]; let y2 = &second_point.y;
                 let x_diff = second_point.x.distance(&first_point.x);
-                let y_diff = second_point.y.distance(&first_point.y);
+                let y_diff = Self::difference(y1, y2);
 
                 if let Some(max_pattern_length) = max_pattern_length {
                     current_pattern_len += x_diff;
@@ -295,15 +384,21 @@ impl SMAGDS {
                 );
                 current_relative_pattern = Self::add_relative_pattern_neuron(
                     magds, level,
-                    absolute_pattern_neurons, &self.neuron_groups,
+                    relative_pattern_neurons, &self.neuron_groups,
                     &x_diff_sn, Some(&current_relative_pattern), &y_diff_sn,
                     *epsilon
                 );
+                Self::add_occurrence(
+                    occurrences, PatternKind::Absolute, level, &current_absolute_pattern, i - 1
+                );
+                Self::add_occurrence(
+                    occurrences, PatternKind::Relative, level, &current_relative_pattern, i - 1
+                );
             }
         }
     }
 
-    fn insert_data_to_sensors(&mut self) {
+    fn insert_data_to_sensors(&mut self, insert_x_intervals: bool) {
         let data = &self.data;
         if data.len() < 2 { return }
 
@@ -318,14 +413,14 @@ impl SMAGDS {
         let mut y_entry_max = data[0].y.clone();
         let mut y_min = data[0].y.clone();
         let mut y_max = data[0].y.clone();
-        let mut y_interval_min = data[1].y.distance(&data[0].y);
-        let mut y_interval_max = data[1].y.distance(&data[0].y);
+        let mut y_interval_min = Self::difference(&data[0].y, &data[1].y);
+        let mut y_interval_max = Self::difference(&data[0].y, &data[1].y);
 
         for i in 1..data.len() {
             let first_point = &data[i - 1];
             let second_point = &data[i];
             let points_x_interval = second_point.x.distance(&first_point.x);
-            let points_y_interval = second_point.y.distance(&first_point.y);
+            let points_y_interval = Self::difference(&first_point.y, &second_point.y);
 
             if second_point.y < y_min { 
                 y_entry_min = second_point.y.clone(); 
@@ -341,7 +436,9 @@ impl SMAGDS {
             if points_y_interval > y_interval_max { y_interval_max = points_y_interval }
         }
 
-        x_interval.insert(&x_interval_min.into()); x_interval.insert(&x_interval_max.into());
+        if insert_x_intervals {
+            x_interval.insert(&x_interval_min.into()); x_interval.insert(&x_interval_max.into());
+        }
         y_entry.insert(&y_entry_min); y_entry.insert(&y_entry_max);
         y.insert(&y_min); y.insert(&y_max);
         y_interval.insert(&y_interval_min.into()); y_interval.insert(&y_interval_max.into());
@@ -351,16 +448,18 @@ impl SMAGDS {
             let first_point = &data[i - 1];
             let second_point = &data[i];
             let x_diff = second_point.x.distance(&first_point.x);
-            let y_diff = second_point.y.distance(&first_point.y);
+            let y_diff = Self::difference(&first_point.y, &second_point.y);
 
-            Self::fuzzy_search_insert(&mut x_interval, x_diff.into(), th);
+            if insert_x_intervals { Self::fuzzy_search_insert(&mut x_interval, x_diff.into(), th); }
             Self::fuzzy_search_insert(&mut y_entry, first_point.y.clone().into(), th);
-            Self::fuzzy_search_insert(&mut y, first_point.y.clone().into(), th);
+            Self::fuzzy_search_insert(&mut y, second_point.y.clone(), th);
             Self::fuzzy_search_insert(&mut y_interval, y_diff.into(), th);
         }
     }
 
-    fn add_pattern_neuron(magds: &mut MAGDS, neuron_id: NeuronID) -> Arc<RwLock<SimpleNeuron>> {
+    pub(crate) fn add_pattern_neuron(
+        magds: &mut MAGDS, neuron_id: NeuronID
+    ) -> Arc<RwLock<SimpleNeuron>> {
         let neuron = SimpleNeuron::new_custom(neuron_id, Arc::new(ConstantOneWeightAsync));
         magds.add_neuron(neuron.clone());
         neuron
@@ -481,6 +580,17 @@ impl SMAGDS {
         relative_pattern_lvl1_neuron
     }
 
+    fn add_occurrence(
+        occurrences: &mut HashMap<(PatternKind, usize, u32), Vec<usize>>,
+        kind: PatternKind,
+        level: usize,
+        neuron: &Arc<RwLock<dyn NeuronAsync>>,
+        start: usize
+    ) {
+        let id = neuron.read().unwrap().id().id;
+        occurrences.entry((kind, level, id)).or_default().push(start);
+    }
+
     fn prepare_pattern_level_neurons(
         max_pattern_level: usize, data_len: usize
     ) -> HashMap<usize, Vec<Arc<RwLock<dyn NeuronAsync>>>> {
@@ -492,7 +602,7 @@ impl SMAGDS {
         result
     }
 
-    fn fuzzy_search_insert(
+    pub(crate) fn fuzzy_search_insert(
         sensor: &mut SensorConatiner, 
         data: DataTypeValue,
         signal_similarity_threshold: f32
@@ -504,10 +614,113 @@ impl SMAGDS {
             sn
         } else { sensor.insert(&data.into()) }
     }
+
+    /// Signed `second - first` for numbers so relative patterns keep their direction
+    pub(crate) fn difference(first: &DataTypeValue, second: &DataTypeValue) -> f64 {
+        match (first.to_f64(), second.to_f64()) {
+            (Some(first), Some(second)) => second - first,
+            _ => second.distance(first)
+        }
+    }
+
+    /// Existing sensor neuron similar enough to `value`, a missing value is only activated
+    /// in a context, so the sensor is left untouched
+    pub(crate) fn sensor_neuron(
+        &self, sensor: &Arc<RwLock<SensorConatiner>>, value: DataTypeValue
+    ) -> Option<Arc<RwLock<dyn NeuronAsync>>> {
+        if let Some(neuron) = sensor.read().unwrap().search(&value) { return Some(neuron) }
+
+        let context = Self::sensor_context(sensor, &value);
+        let th = self.params.signal_similarity_threshold;
+        sensor.read().unwrap().neurons().into_iter()
+            .map(|neuron| {
+                let activation = neuron.read().unwrap().activation_in(&context);
+                (neuron, activation)
+            })
+            .filter(|(_, activation)| *activation >= th)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(neuron, _)| neuron)
+    }
+
+    /// Similarities of the sensor neurons of `sensor` to `value` as their activations
+    /// in a new context, empty if a missing categorical `value` can't be activated
+    pub(crate) fn sensor_context(
+        sensor: &Arc<RwLock<SensorConatiner>>, value: &DataTypeValue
+    ) -> ActivationContext {
+        let mut context = ActivationContext::new();
+        if sensor.read().unwrap().activate_in(value, 1.0, true, false, &mut context).is_err() {
+            context.clear();
+        }
+        context
+    }
+
+    /// Pattern neuron of level `points.len() - 1` describing `points`, for absolute patterns
+    /// a single point is described by its entry sensor neuron
+    pub(crate) fn match_points(
+        &self, kind: PatternKind, points: &[DataPoint2D]
+    ) -> Option<Arc<RwLock<dyn NeuronAsync>>> {
+        let mut pattern = None;
+        if kind == PatternKind::Absolute {
+            pattern = Some(self.sensor_neuron(&self.sensors.y_entry, points[0].y.clone())?);
+        }
+        for (i, window) in points.windows(2).enumerate() {
+            let level_neurons = self.pattern_neurons(kind, i + 1)?;
+            let x_diff = window[1].x.distance(&window[0].x);
+            let x_diff_sn = self.sensor_neuron(&self.sensors.x_interval, x_diff.into())?;
+            let y_sn = match kind {
+                PatternKind::Absolute => self.sensor_neuron(&self.sensors.y, window[1].y.clone())?,
+                PatternKind::Relative => {
+                    let y_diff = Self::difference(&window[0].y, &window[1].y);
+                    self.sensor_neuron(&self.sensors.y_interval, y_diff.into())?
+                }
+            };
+            let mut inputs = vec![&x_diff_sn, &y_sn];
+            if let Some(base) = &pattern { inputs.push(base); }
+            pattern = Some(Self::match_pattern_neuron(level_neurons, &inputs)?);
+        }
+        pattern
+    }
+
+    /// The most frequent pattern neuron of `level_neurons` defined by all the `inputs` at once,
+    /// found by following their connections without activating anything
+    pub(crate) fn match_pattern_neuron(
+        level_neurons: &[Arc<RwLock<dyn NeuronAsync>>],
+        inputs: &[&Arc<RwLock<dyn NeuronAsync>>]
+    ) -> Option<Arc<RwLock<dyn NeuronAsync>>> {
+        let (first, rest) = inputs.split_first()?;
+        let first = first.read().unwrap();
+        first.defined_neurons().iter()
+            .filter(|neuron| Self::is_level_neuron(level_neurons, neuron))
+            .filter(|neuron| rest.iter().all(|input| Self::defines(input, neuron)))
+            .max_by_key(|neuron| neuron.read().unwrap().counter())
+            .cloned()
+    }
+
+    /// Whether `neuron` is one of `level_neurons`, whose ids are their indices
+    pub(crate) fn is_level_neuron(
+        level_neurons: &[Arc<RwLock<dyn NeuronAsync>>], neuron: &Arc<RwLock<dyn NeuronAsync>>
+    ) -> bool {
+        let id = neuron.read().unwrap().id().id as usize;
+        level_neurons.get(id).map_or(false, |level_neuron| same_neuron(level_neuron, neuron))
+    }
+
+    /// Whether `input` is connected to `neuron` as one of its defining neurons
+    pub(crate) fn defines(
+        input: &Arc<RwLock<dyn NeuronAsync>>, neuron: &Arc<RwLock<dyn NeuronAsync>>
+    ) -> bool {
+        input.read().unwrap().defined_neurons().iter().any(|defined| same_neuron(defined, neuron))
+    }
+}
+
+fn same_neuron(a: &Arc<RwLock<dyn NeuronAsync>>, b: &Arc<RwLock<dyn NeuronAsync>>) -> bool {
+    Arc::as_ptr(a) as *const () == Arc::as_ptr(b) as *const ()
 }
 
+#[cfg(test)]
 mod tests {
-    use super::SMAGDS;
+    use witchnet_common::{ data::DataTypeValue, sensor::SensorAsync };
+
+    use super::{ SMAGDS, PatternKind };
 
     #[test]
     fn new() {
@@ -552,4 +765,39 @@ mod tests {
             ).is_err()
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn pattern_neurons() {
+        let smagds = SMAGDS::new(&[(1, 1.0), (2, 3.0), (4, 6.0), (5, 3.0)]).unwrap();
+        let x_interval_id = smagds.sensors.x_interval.read().unwrap().id();
+        let y_id = smagds.sensors.y.read().unwrap().id();
+        let y_interval_id = smagds.sensors.y_interval.read().unwrap().id();
+
+        let absolute = smagds.pattern_neurons(PatternKind::Absolute, 1).unwrap();
+        let y: Vec<_> = absolute.iter()
+            .map(|neuron| neuron.read().unwrap().explain_one(y_id).unwrap())
+            .collect();
+        assert_eq!(y, [3.0, 6.0, 3.0].map(DataTypeValue::F64));
+
+        let absolute = smagds.pattern_neurons(PatternKind::Absolute, 2).unwrap();
+        assert_eq!(absolute.len(), 2);
+        let steps: Vec<_> = absolute.iter()
+            .map(|neuron| {
+                let neuron = neuron.read().unwrap();
+                (neuron.explain_one(x_interval_id), neuron.explain_one(y_id))
+            })
+            .collect();
+        assert_eq!(steps, vec![
+            (Some(DataTypeValue::F64(2.0)), Some(DataTypeValue::F64(6.0))),
+            (Some(DataTypeValue::F64(1.0)), Some(DataTypeValue::F64(3.0)))
+        ]);
+
+        let relative = smagds.pattern_neurons(PatternKind::Relative, 1).unwrap();
+        let y_diff: Vec<_> = relative.iter()
+            .map(|neuron| neuron.read().unwrap().explain_one(y_interval_id).unwrap())
+            .collect();
+        assert_eq!(y_diff, [2.0, 3.0, -3.0].map(DataTypeValue::F64));
+        let relative_id = smagds.neuron_groups.relative_pattern_level[&1];
+        assert!(relative.iter().all(|neuron| neuron.read().unwrap().id().parent_id == relative_id));
+    }
+}
@@ -0,0 +1,43 @@
+//! `Arc<RwLock>` instantiation of the sources in `generic`
+
+#[path = "../generic/neuron.rs"]
+pub mod neuron;
+#[path = "../generic/sensor.rs"]
+pub mod sensor;
+#[path = "../generic/collective/mod.rs"]
+pub mod collective;
+
+pub mod pointer {
+    pub use std::sync::{ Arc as Ptr, Weak as WeakPtr, RwLock as Lock };
+
+    use std::sync::{ RwLockReadGuard, RwLockWriteGuard, TryLockResult };
+
+    pub trait ThreadSafety: Sync + Send {}
+
+    impl<T: ?Sized + Sync + Send> ThreadSafety for T {}
+
+    /// `RefCell` like access, so generic sources read the same for both pointer kinds.
+    ///
+    /// Like a conflicting `RefCell` borrow, `borrow` and `borrow_mut` panic when the lock
+    /// is poisoned by a thread that panicked while writing, use `try_borrow` or the `RwLock`
+    /// methods to recover the data instead.
+    pub trait LockBorrow<T: ?Sized> {
+        fn borrow(&self) -> RwLockReadGuard<'_, T>;
+
+        fn borrow_mut(&self) -> RwLockWriteGuard<'_, T>;
+
+        fn try_borrow(&self) -> TryLockResult<RwLockReadGuard<'_, T>>;
+    }
+
+    impl<T: ?Sized> LockBorrow<T> for Lock<T> {
+        fn borrow(&self) -> RwLockReadGuard<'_, T> {
+            self.read().expect("lock poisoned by a panicked writer")
+        }
+
+        fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
+            self.write().expect("lock poisoned by a panicked writer")
+        }
+
+        fn try_borrow(&self) -> TryLockResult<RwLockReadGuard<'_, T>> { self.try_read() }
+    }
+}
@@ -1,17 +1,4 @@
-use std::{
-    rc::Rc,
-    cell::RefCell,
-    sync::{ Arc, RwLock }
+pub use crate::{
+    synchronous::collective::inhibitory::InhibitoryConnections,
+    asynchronous::collective::inhibitory::InhibitoryConnections as InhibitoryConnectionsAsync
 };
-
-use crate::neuron::{ Neuron, NeuronAsync };
-
-#[derive(Debug, Clone)]
-pub struct InhibitoryConnections {
-    pub connections: Vec<Rc<RefCell<dyn Neuron>>>
-}
-
-#[derive(Debug, Clone)]
-pub struct InhibitoryConnectionsAsync {
-    pub connections: Vec<Arc<RwLock<dyn NeuronAsync>>>
-}
@@ -1,17 +1,4 @@
-use std::{
-    rc::Rc,
-    cell::RefCell,
-    sync::{ Arc, RwLock }
+pub use crate::{
+    synchronous::collective::sequential::SequentialConnections,
+    asynchronous::collective::sequential::SequentialConnections as SequentialConnectionsAsync
 };
-
-use crate::neuron::{ Neuron, NeuronAsync };
-
-#[derive(Debug, Clone)]
-pub struct SequentialConnections {
-    pub connections: Vec<Rc<RefCell<dyn Neuron>>>
-}
-
-#[derive(Debug, Clone)]
-pub struct SequentialConnectionsAsync {
-    pub connections: Vec<Arc<RwLock<dyn NeuronAsync>>>
-}
\ No newline at end of file
@@ -1,17 +1,4 @@
-use std::{
-    rc::Rc,
-    cell::RefCell,
-    sync::{ Arc, RwLock }
+pub use crate::{
+    synchronous::collective::similarity::SimilarityConnections,
+    asynchronous::collective::similarity::SimilarityConnections as SimilarityConnectionsAsync
 };
-
-use crate::neuron::{ Neuron, NeuronAsync };
-
-#[derive(Debug, Clone)]
-pub struct SimilarityConnections {
-    pub connections: Vec<Rc<RefCell<dyn Neuron>>>
-}
-
-#[derive(Debug, Clone)]
-pub struct SimilarityConnectionsAsync {
-    pub connections: Vec<Arc<RwLock<dyn NeuronAsync>>>
-}
\ No newline at end of file
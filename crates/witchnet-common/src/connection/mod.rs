@@ -1,15 +1,19 @@
 pub mod collective;
 pub mod standalone;
 
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
+
 use crate::neuron::NeuronID;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConnectionID {
     pub from: NeuronID,
     pub to: NeuronID
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ConnectionKind {
     Defining,
     Explanatory,
@@ -6,15 +6,20 @@ use std::{
 
 use enum_as_inner::EnumAsInner;
 
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
+
 use crate::{
     distances::{ 
         Distance, 
         DistanceChecked::{ self, * }
     },
-    polars
+    polars,
+    temporal::{ Date, DateTime, Duration }
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, EnumAsInner)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataCategory {
     Continuous,
     Categorical,
@@ -75,7 +80,8 @@ macro_rules! impl_categorical {
 
 impl_discrete! { 
     i8, i16, i32, i64, i128, isize,
-    u8, u16, u32, u64, u128, usize
+    u8, u16, u32, u64, u128, usize,
+    Date, DateTime, Duration
 }
 
 impl_continuous! { f32, f64 }
@@ -83,6 +89,7 @@ impl_continuous! { f32, f64 }
 impl_categorical! { String, Arc<str>, bool }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, EnumAsInner)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataType {
     Bool,
     U8,
@@ -101,31 +108,59 @@ pub enum DataType {
     F64,
     ArcStr,
     String,
+    Date,
+    DateTime,
+    Duration,
     Unknown
 }
 
 #[derive(EnumAsInner, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum DataTypeValue {
     Bool(bool),
     U8(u8),
     U16(u16),
     U32(u32),
     U64(u64),
+    #[cfg_attr(feature = "serde", serde(with = "serde_as_string"))]
     U128(u128),
     USize(usize),
     I8(i8),
     I16(i16),
     I32(i32),
     I64(i64),
+    #[cfg_attr(feature = "serde", serde(with = "serde_as_string"))]
     I128(i128),
     ISize(isize),
     F32(f32),
     F64(f64),
     ArcStr(Arc<str>),
     String(String),
+    Date(Date),
+    DateTime(DateTime),
+    Duration(Duration),
+    Null,
     Unknown
 }
 
+// 128-bit integers are kept as strings, most serde formats and consumers can't hold them losslessly
+#[cfg(feature = "serde")]
+mod serde_as_string {
+    use std::{ fmt::Display, str::FromStr };
+
+    use serde::{ Serializer, Deserializer, Deserialize, de::Error };
+
+    pub fn serialize<T: Display, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(v)
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+    where T: FromStr, T::Err: Display, D: Deserializer<'de> {
+        String::deserialize(d)?.parse().map_err(D::Error::custom)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct DataPoint2D {
     pub x: DataTypeValue,
@@ -163,6 +198,10 @@ impl DataTypeValue {
             DataTypeValue::F64(v) => Some(*v as f64),
             DataTypeValue::ArcStr(_) => None,
             DataTypeValue::String(_) => None,
+            DataTypeValue::Date(v) => Some(v.0 as f64),
+            DataTypeValue::DateTime(v) => Some(v.0 as f64),
+            DataTypeValue::Duration(v) => Some(v.0 as f64),
+            DataTypeValue::Null => None,
             DataTypeValue::Unknown => None
         }
     }
@@ -186,6 +225,10 @@ impl DataTypeValue {
             DataTypeValue::F64(v) => v.to_string(),
             DataTypeValue::ArcStr(v) => v.to_string(),
             DataTypeValue::String(v) => v.clone(),
+            DataTypeValue::Date(v) => v.to_string(),
+            DataTypeValue::DateTime(v) => v.to_string(),
+            DataTypeValue::Duration(v) => v.to_string(),
+            DataTypeValue::Null => String::from(""),
             DataTypeValue::Unknown => String::from("")
         }
     }
@@ -219,6 +262,10 @@ impl DataTypeValue {
                     .map(|x| DataTypeValue::ArcStr(x.into()))
                     .collect::<Vec<_>>()
             },
+            DataTypeValue::Date(v) => vec![DataTypeValue::Date(*v)],
+            DataTypeValue::DateTime(v) => vec![DataTypeValue::DateTime(*v)],
+            DataTypeValue::Duration(v) => vec![DataTypeValue::Duration(*v)],
+            DataTypeValue::Null => vec![DataTypeValue::Null],
             DataTypeValue::Unknown => vec![]
         }
     }
@@ -256,6 +303,10 @@ impl From<&DataTypeValue> for DataCategory {
             DataTypeValue::F64(_) => DataCategory::Continuous,
             DataTypeValue::ArcStr(_) => DataCategory::Categorical,
             DataTypeValue::String(_) => DataCategory::Categorical,
+            DataTypeValue::Date(_) => DataCategory::Discrete,
+            DataTypeValue::DateTime(_) => DataCategory::Discrete,
+            DataTypeValue::Duration(_) => DataCategory::Discrete,
+            DataTypeValue::Null => DataCategory::Categorical,
             DataTypeValue::Unknown => DataCategory::Categorical
         }
     }
@@ -281,6 +332,10 @@ impl From<&DataTypeValue> for DataType {
             DataTypeValue::F64(_) => DataType::F64,
             DataTypeValue::ArcStr(_) => DataType::ArcStr,
             DataTypeValue::String(_) => DataType::String,
+            DataTypeValue::Date(_) => DataType::Date,
+            DataTypeValue::DateTime(_) => DataType::DateTime,
+            DataTypeValue::Duration(_) => DataType::Duration,
+            DataTypeValue::Null => DataType::Unknown,
             DataTypeValue::Unknown => DataType::Unknown
         }
     }
@@ -353,6 +408,16 @@ impl Distance for DataTypeValue {
                     }
                 }
             }
+            DataTypeValue::Date(lhs) => {
+                (lhs.0 as f64 - v.to_f64().unwrap()).abs()
+            }
+            DataTypeValue::DateTime(lhs) => {
+                (lhs.0 as f64 - v.to_f64().unwrap()).abs()
+            }
+            DataTypeValue::Duration(lhs) => {
+                (lhs.0 as f64 - v.to_f64().unwrap()).abs()
+            }
+            DataTypeValue::Null => if v.is_null() { 0.0 } else { 1.0 },
             DataTypeValue::Unknown => f64::NAN
         }
     }
@@ -427,6 +492,16 @@ impl Distance for DataTypeValue {
                     }
                 }
             }
+            DataTypeValue::Date(lhs) => {
+                Comparable((lhs.0 as f64 - v.to_f64().unwrap()).abs())
+            }
+            DataTypeValue::DateTime(lhs) => {
+                Comparable((lhs.0 as f64 - v.to_f64().unwrap()).abs())
+            }
+            DataTypeValue::Duration(lhs) => {
+                Comparable((lhs.0 as f64 - v.to_f64().unwrap()).abs())
+            }
+            DataTypeValue::Null => if v.is_null() { Comparable(0.0) } else { Incomparable },
             DataTypeValue::Unknown => Incomparable
         }
     }
@@ -500,6 +575,18 @@ impl From<String> for DataTypeValue {
     fn from(v: String) -> DataTypeValue { DataTypeValue::String(v) } 
 }
 
+impl From<Date> for DataTypeValue { 
+    fn from(v: Date) -> DataTypeValue { DataTypeValue::Date(v) } 
+}
+
+impl From<DateTime> for DataTypeValue { 
+    fn from(v: DateTime) -> DataTypeValue { DataTypeValue::DateTime(v) } 
+}
+
+impl From<Duration> for DataTypeValue { 
+    fn from(v: Duration) -> DataTypeValue { DataTypeValue::Duration(v) } 
+}
+
 impl From<DataTypeValue> for Option<bool> { 
     fn from(v: DataTypeValue) -> Option<bool> { v.into_bool().ok() } 
 }
@@ -568,6 +655,18 @@ impl From<DataTypeValue> for Option<String> {
     fn from(v: DataTypeValue) -> Option<String> { v.into_string().ok() } 
 }
 
+impl From<DataTypeValue> for Option<Date> { 
+    fn from(v: DataTypeValue) -> Option<Date> { v.into_date().ok() } 
+}
+
+impl From<DataTypeValue> for Option<DateTime> { 
+    fn from(v: DataTypeValue) -> Option<DateTime> { v.into_date_time().ok() } 
+}
+
+impl From<DataTypeValue> for Option<Duration> { 
+    fn from(v: DataTypeValue) -> Option<Duration> { v.into_duration().ok() } 
+}
+
 pub struct DataTypeValueStr<'a>(pub &'a str);
 
 impl<'a> DataTypeValueStr<'a> {
@@ -590,6 +689,9 @@ impl<'a> DataTypeValueStr<'a> {
             DataType::F64 => DataTypeValue::F64(self.0.parse().ok()?),
             DataType::ArcStr => DataTypeValue::ArcStr(self.0.into()),
             DataType::String => DataTypeValue::String(self.0.parse().ok()?),
+            DataType::Date => DataTypeValue::Date(self.0.parse().ok()?),
+            DataType::DateTime => DataTypeValue::DateTime(self.0.parse().ok()?),
+            DataType::Duration => DataTypeValue::Duration(self.0.parse().ok()?),
             DataType::Unknown => return None
         };
         Some(result)
@@ -615,6 +717,9 @@ impl !UnknownDataTypeMarker for f32 {}
 impl !UnknownDataTypeMarker for f64 {}
 impl !UnknownDataTypeMarker for Arc<str> {}
 impl !UnknownDataTypeMarker for String {}
+impl !UnknownDataTypeMarker for Date {}
+impl !UnknownDataTypeMarker for DateTime {}
+impl !UnknownDataTypeMarker for Duration {}
 
 impl !UnknownDataTypeMarker for PhantomData<bool> {}
 impl !UnknownDataTypeMarker for PhantomData<u8> {}
@@ -633,6 +738,9 @@ impl !UnknownDataTypeMarker for PhantomData<f32> {}
 impl !UnknownDataTypeMarker for PhantomData<f64> {}
 impl !UnknownDataTypeMarker for PhantomData<Arc<str>> {}
 impl !UnknownDataTypeMarker for PhantomData<String> {}
+impl !UnknownDataTypeMarker for PhantomData<Date> {}
+impl !UnknownDataTypeMarker for PhantomData<DateTime> {}
+impl !UnknownDataTypeMarker for PhantomData<Duration> {}
 
 pub trait DataDeductor { 
     fn data_type(&self) -> DataType;
@@ -724,6 +832,21 @@ impl DataDeductor for String {
     fn data_category(&self) -> DataCategory { DataCategory::Categorical }
 }
 
+impl DataDeductor for Date {
+    fn data_type(&self) -> DataType { DataType::Date }
+    fn data_category(&self) -> DataCategory { DataCategory::Continuous }
+}
+
+impl DataDeductor for DateTime {
+    fn data_type(&self) -> DataType { DataType::DateTime }
+    fn data_category(&self) -> DataCategory { DataCategory::Continuous }
+}
+
+impl DataDeductor for Duration {
+    fn data_type(&self) -> DataType { DataType::Duration }
+    fn data_category(&self) -> DataCategory { DataCategory::Continuous }
+}
+
 impl DataDeductor for PhantomData<bool> {
     fn data_type(&self) -> DataType { DataType::Bool }
     fn data_category(&self) -> DataCategory { DataCategory::Categorical }
@@ -809,10 +932,27 @@ impl DataDeductor for PhantomData<String> {
     fn data_category(&self) -> DataCategory { DataCategory::Categorical }
 }
 
+impl DataDeductor for PhantomData<Date> {
+    fn data_type(&self) -> DataType { DataType::Date }
+    fn data_category(&self) -> DataCategory { DataCategory::Continuous }
+}
+
+impl DataDeductor for PhantomData<DateTime> {
+    fn data_type(&self) -> DataType { DataType::DateTime }
+    fn data_category(&self) -> DataCategory { DataCategory::Continuous }
+}
+
+impl DataDeductor for PhantomData<Duration> {
+    fn data_type(&self) -> DataType { DataType::Duration }
+    fn data_category(&self) -> DataCategory { DataCategory::Continuous }
+}
+
 #[allow(unused_imports)]
 mod tests {
     use super::*;
 
+    use crate::{ neuron::NeuronID, connection::ConnectionID };
+
     #[test]
     fn data_type_value_distance() {
         let x: DataTypeValue = 1.0f32.into();
@@ -845,4 +985,49 @@ mod tests {
         assert_eq!(y.distance(&x), 2.0f64);
         
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let values: Vec<DataTypeValue> = vec![
+            true.into(), 8u8.into(), 16u16.into(), 32u32.into(), 64u64.into(), u128::MAX.into(),
+            7usize.into(), (-8i8).into(), (-16i16).into(), (-32i32).into(), (-64i64).into(),
+            i128::MIN.into(), (-7isize).into(), 1.5f32.into(), (-2.5f64).into(),
+            Arc::<str>::from("arc").into(), "string".to_string().into(),
+            Date(19_270).into(), DateTime(-1).into(), Duration(1500).into(), DataTypeValue::Unknown
+        ];
+        for value in &values {
+            let json = serde_json::to_string(value).unwrap();
+            let deserialized: DataTypeValue = serde_json::from_str(&json).unwrap();
+            assert_eq!(&deserialized, value);
+            assert_eq!(DataType::from(&deserialized), DataType::from(value));
+            let data_category: DataCategory = serde_json::from_str(
+                &serde_json::to_string(&DataCategory::from(value)).unwrap()
+            ).unwrap();
+            assert_eq!(data_category, DataCategory::from(value));
+        }
+
+        assert_eq!(
+            serde_json::to_string(&DataTypeValue::U128(u128::MAX)).unwrap(),
+            format!(r#"{{"type":"U128","value":"{}"}}"#, u128::MAX)
+        );
+        assert_eq!(
+            serde_json::to_string(&DataTypeValue::I128(-1)).unwrap(),
+            r#"{"type":"I128","value":"-1"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&DataTypeValue::ArcStr(Arc::from("a"))).unwrap(),
+            r#"{"type":"ArcStr","value":"a"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&DataTypeValue::Unknown).unwrap(), r#"{"type":"Unknown"}"#
+        );
+        assert_eq!(serde_json::to_string(&DataType::ArcStr).unwrap(), r#""ArcStr""#);
+        assert!(serde_json::from_str::<DataTypeValue>(r#"{"type":"U128","value":"x"}"#).is_err());
+
+        let connection_id = ConnectionID { from: NeuronID::new(1, 2), to: NeuronID::new(3, 4) };
+        let json = serde_json::to_string(&connection_id).unwrap();
+        assert_eq!(json, r#"{"from":{"id":1,"parent_id":2},"to":{"id":3,"parent_id":4}}"#);
+        assert_eq!(serde_json::from_str::<ConnectionID>(&json).unwrap(), connection_id);
+    }
 }
\ No newline at end of file
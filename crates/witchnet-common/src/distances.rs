@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use num_traits::ToPrimitive;
 
+use crate::temporal::{ Date, DateTime, Duration };
+
 use DistanceChecked::*;
 
 #[derive(Debug, Copy, Clone)]
@@ -40,7 +42,8 @@ macro_rules! impl_distance_numerical {
 impl_distance_numerical! { 
     i8, i16, i32, i64, i128, isize,
     u8, u16, u32, u64, u128, usize,
-    f32, f64
+    f32, f64,
+    Date, DateTime, Duration
 }
 
 macro_rules! impl_distance_categorical {
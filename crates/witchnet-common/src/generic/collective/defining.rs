@@ -0,0 +1,108 @@
+use std::fmt::{ Debug, Formatter, Result as FmtResult };
+
+use crate::connection::collective::{
+    WeightingStrategy,
+    defining::{
+        ConstantZeroWeight,
+        ConstantOneWeight,
+        OneOverOuts,
+        OneOverOutsUpperHalf,
+        OneOverOutsUpperQuarter
+    }
+};
+
+use super::{
+    CollectiveConnections,
+    super::{ pointer::*, neuron::Neuron }
+};
+
+pub trait DefiningWeightingStrategy: ThreadSafety {
+    fn weight(&self, defining_connections: &DefiningConnections) -> f32;
+
+    fn name(&self) -> &str { "Custom" }
+}
+
+pub fn weighting_strategy_from_name(name: &str) -> Option<Ptr<dyn DefiningWeightingStrategy>> {
+    match name {
+        "ConstantZeroWeight" => Some(Ptr::new(ConstantZeroWeight)),
+        "ConstantOneWeight" => Some(Ptr::new(ConstantOneWeight)),
+        "OneOverOuts" => Some(Ptr::new(OneOverOuts)),
+        "OneOverOutsUpperHalf" => Some(Ptr::new(OneOverOutsUpperHalf)),
+        "OneOverOutsUpperQuarter" => Some(Ptr::new(OneOverOutsUpperQuarter)),
+        _ => None
+    }
+}
+
+impl Debug for dyn DefiningWeightingStrategy {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { write!(f, "strategy {:?}", self) }
+}
+
+impl DefiningWeightingStrategy for ConstantZeroWeight {
+    fn weight(&self, _defining_connections: &DefiningConnections) -> f32 { 0.0 }
+
+    fn name(&self) -> &str { "ConstantZeroWeight" }
+}
+
+impl DefiningWeightingStrategy for ConstantOneWeight {
+    fn weight(&self, _defining_connections: &DefiningConnections) -> f32 { 1.0 }
+
+    fn name(&self) -> &str { "ConstantOneWeight" }
+}
+
+impl DefiningWeightingStrategy for OneOverOuts {
+    fn weight(&self, defining_connections: &DefiningConnections) -> f32 {
+        1.0f32 / f32::max(defining_connections.connections.len() as f32, 1.0f32)
+    }
+
+    fn name(&self) -> &str { "OneOverOuts" }
+}
+
+impl DefiningWeightingStrategy for OneOverOutsUpperHalf {
+    fn weight(&self, defining_connections: &DefiningConnections) -> f32 {
+        0.5f32 + (1.0f32 / f32::max(defining_connections.connections.len() as f32, 1.0f32) / 2f32)
+    }
+
+    fn name(&self) -> &str { "OneOverOutsUpperHalf" }
+}
+
+impl DefiningWeightingStrategy for OneOverOutsUpperQuarter {
+    fn weight(&self, defining_connections: &DefiningConnections) -> f32 {
+        0.75f32 + (1.0f32 / f32::max(defining_connections.connections.len() as f32, 1.0f32) / 4f32)
+    }
+
+    fn name(&self) -> &str { "OneOverOutsUpperQuarter" }
+}
+
+#[derive(Debug, Clone)]
+pub struct DefiningConnections {
+    pub connections: Vec<Ptr<Lock<dyn Neuron>>>,
+    pub weighting_strategy: Ptr<dyn DefiningWeightingStrategy>
+}
+
+impl CollectiveConnections for DefiningConnections {  
+    fn add(&mut self, other: Ptr<Lock<dyn Neuron>>) {
+        self.connections.push(other);
+    }
+
+    fn connected_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] { &self.connections }
+
+    fn remove(&mut self, other: &Ptr<Lock<dyn Neuron>>) -> bool {
+        let len = self.connections.len();
+        self.connections.retain(|x| !Ptr::ptr_eq(x, other));
+        self.connections.len() != len
+    }
+}
+
+impl WeightingStrategy for DefiningConnections {  
+    fn common_weight(&self) -> f32 { self.weighting_strategy.weight(self) }
+}
+
+impl DefiningConnections {
+    pub fn new(weighting_strategy: Ptr<dyn DefiningWeightingStrategy>) -> DefiningConnections { 
+        DefiningConnections { connections: Vec::new(), weighting_strategy }
+    }
+
+    pub fn output_signal(&self, neuron: Ptr<Lock<dyn Neuron>>) -> f32 {
+        neuron.borrow().activation() * self.common_weight()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::connection::collective::WeightingStrategy;
+
+use super::{
+    CollectiveConnections,
+    super::{ pointer::*, neuron::Neuron }
+};
+
+#[derive(Debug, Clone)]
+pub struct ExplanatoryConnections {
+    pub connections: Vec<Ptr<Lock<dyn Neuron>>>
+}
+
+impl CollectiveConnections for ExplanatoryConnections {  
+    fn add(&mut self, other: Ptr<Lock<dyn Neuron>>) {
+        self.connections.push(other);
+    }
+    
+    fn connected_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] { &self.connections }
+
+    fn remove(&mut self, other: &Ptr<Lock<dyn Neuron>>) -> bool {
+        let len = self.connections.len();
+        self.connections.retain(|x| !Ptr::ptr_eq(x, other));
+        self.connections.len() != len
+    }
+}
+
+impl WeightingStrategy for ExplanatoryConnections {  
+    fn common_weight(&self) -> f32 { 1.0f32 }
+}
+
+impl ExplanatoryConnections {
+    pub fn new() -> ExplanatoryConnections { 
+        ExplanatoryConnections { connections: Vec::new() } 
+    }
+
+    pub fn output_signal(&self, neuron: Ptr<Lock<dyn Neuron>>) -> f32 { 
+        neuron.borrow().activation()
+    }
+}
@@ -0,0 +1,11 @@
+use crate::connection::collective::WeightingStrategy;
+
+use super::{
+    CollectiveConnections,
+    super::{ pointer::*, neuron::Neuron }
+};
+
+weighted_connections! {
+    /// Lateral connections to competing neurons, they receive `-weight` times the incoming signal
+    InhibitoryConnections
+}
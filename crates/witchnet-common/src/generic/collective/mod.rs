@@ -0,0 +1,78 @@
+/// Defines a collective connections struct in which every connected neuron has its own weight
+macro_rules! weighted_connections {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            pub connections: Vec<Ptr<Lock<dyn Neuron>>>,
+            pub weights: Vec<f32>
+        }
+
+        impl $name {
+            pub fn new() -> $name { $name { connections: Vec::new(), weights: Vec::new() } }
+
+            /// Connects `other` with `weight`, the weight of an existing connection is replaced
+            pub fn add_weighted(&mut self, other: Ptr<Lock<dyn Neuron>>, weight: f32) {
+                match self.connections.iter().position(|x| Ptr::ptr_eq(x, &other)) {
+                    Some(i) => self.weights[i] = weight,
+                    None => {
+                        self.connections.push(other);
+                        self.weights.push(weight);
+                    }
+                }
+            }
+
+            pub fn weight(&self, other: &Ptr<Lock<dyn Neuron>>) -> Option<f32> {
+                let i = self.connections.iter().position(|x| Ptr::ptr_eq(x, other))?;
+                Some(self.weights[i])
+            }
+
+            pub fn weighted_neurons(&self) -> Vec<(Ptr<Lock<dyn Neuron>>, f32)> {
+                self.connections.iter().cloned().zip(self.weights.iter().copied()).collect()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> $name { $name::new() }
+        }
+
+        impl CollectiveConnections for $name {
+            fn add(&mut self, other: Ptr<Lock<dyn Neuron>>) { self.add_weighted(other, 1.0f32) }
+
+            fn connected_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] { &self.connections }
+
+            fn remove(&mut self, other: &Ptr<Lock<dyn Neuron>>) -> bool {
+                match self.connections.iter().position(|x| Ptr::ptr_eq(x, other)) {
+                    Some(i) => {
+                        self.connections.remove(i);
+                        self.weights.remove(i);
+                        true
+                    }
+                    None => false
+                }
+            }
+        }
+
+        impl WeightingStrategy for $name {
+            fn common_weight(&self) -> f32 { 1.0f32 }
+        }
+    };
+}
+
+pub mod defining;
+pub mod explanatory;
+pub mod similarity;
+pub mod inhibitory;
+pub mod sequential;
+
+use crate::connection::collective::WeightingStrategy;
+
+use super::{ pointer::*, neuron::Neuron };
+
+pub trait CollectiveConnections: WeightingStrategy {
+    fn add(&mut self, other: Ptr<Lock<dyn Neuron>>);
+
+    fn connected_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>];
+
+    fn remove(&mut self, other: &Ptr<Lock<dyn Neuron>>) -> bool;
+}
@@ -0,0 +1,11 @@
+use crate::connection::collective::WeightingStrategy;
+
+use super::{
+    CollectiveConnections,
+    super::{ pointer::*, neuron::Neuron }
+};
+
+weighted_connections! {
+    /// One-way connections to the next neurons of a sequence, they receive `weight` times the signal
+    SequentialConnections
+}
@@ -0,0 +1,11 @@
+use crate::connection::collective::WeightingStrategy;
+
+use super::{
+    CollectiveConnections,
+    super::{ pointer::*, neuron::Neuron }
+};
+
+weighted_connections! {
+    /// Lateral connections to similar neurons, they receive `weight` times the incoming signal
+    SimilarityConnections
+}
@@ -0,0 +1,120 @@
+use std::fmt::{ Debug, Display, Formatter, Result as FmtResult };
+
+use anyhow::Result;
+
+use crate::{
+    connection::ConnectionKind,
+    data::{ DataTypeValue, DataType },
+    neuron::{ NeuronID, ActivationContext }
+};
+
+use super::pointer::*;
+
+pub trait Neuron: ThreadSafety {
+    fn id(&self) -> NeuronID;
+
+    fn value(&self) -> DataTypeValue;
+
+    fn activation(&self) -> f32;
+
+    fn activation_in(&self, context: &ActivationContext) -> f32 {
+        context.activation(&self.id(), self.is_sensor())
+    }
+
+    fn is_sensor(&self) -> bool;
+
+    fn data_type(&self) -> DataType;
+
+    fn increment_counter(&mut self) -> usize;
+
+    fn decrement_counter(&mut self) -> usize;
+
+    fn counter(&self) -> usize;
+
+    fn explain(&self) -> &[Ptr<Lock<dyn Neuron>>];
+
+    fn explain_one(&self, parent: u32) -> Option<DataTypeValue>;
+
+    fn defined_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>];
+
+    /// Neurons defining this one, the reverse of their `defined_neurons`
+    fn defining_neurons(&self) -> &[Ptr<Lock<dyn Neuron>>] { &[] }
+
+    fn activate(
+        &mut self, signal: f32, propagate_horizontal: bool, propagate_vertical: bool
+    ) -> f32;
+
+    fn activate_defining(&mut self, signal: f32) -> f32;
+
+    /// Same propagation as `activate`, but the activations are accumulated in `context`
+    /// so the neuron itself is left untouched.
+    fn activate_in(
+        &self, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool, 
+        context: &mut ActivationContext
+    ) -> f32;
+
+    fn deactivate(&mut self, propagate_horizontal: bool, propagate_vertical: bool);
+
+    fn connect_to(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()>;
+
+    fn connect_bilateral(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()>;
+
+    /// Same as `connect_to` for the kinds whose connections carry their own weight
+    fn connect_to_weighted(
+        &mut self, _to: Ptr<Lock<dyn Neuron>>, _is_to_sensor: bool, kind: ConnectionKind, _weight: f32
+    ) -> Result<()> {
+        anyhow::bail!("weighted {:?} connections are not supported", kind)
+    }
+
+    /// Same as `connect_bilateral` for the kinds whose connections carry their own weight
+    fn connect_bilateral_weighted(
+        &mut self, _to: Ptr<Lock<dyn Neuron>>, _is_to_sensor: bool, kind: ConnectionKind, _weight: f32
+    ) -> Result<()> {
+        anyhow::bail!("weighted {:?} connections are not supported", kind)
+    }
+
+    /// Name of the strategy weighting the connections to the defined neurons, if the neuron has one
+    fn weighting_strategy_name(&self) -> Option<&str> { None }
+
+    /// Neurons connected through the weighted `kind` connections along with their weights
+    fn weighted_connections(&self, _kind: ConnectionKind) -> Vec<(Ptr<Lock<dyn Neuron>>, f32)> {
+        Vec::new()
+    }
+
+    fn disconnect_from(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()>;
+
+    fn disconnect_bilateral(
+        &mut self, to: Ptr<Lock<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
+    ) -> Result<()>;
+}
+
+impl Display for dyn Neuron {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f, "[{}|c:{}|a:{}]",
+            self.id(), 
+            self.counter(), 
+            self.activation()
+        )
+    }
+}
+
+impl Debug for dyn Neuron {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f, "[{}|c:{}|a:{}]",
+            self.id(), 
+            self.counter(), 
+            self.activation()
+        )
+    }
+}
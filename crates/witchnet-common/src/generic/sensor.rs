@@ -0,0 +1,82 @@
+use std::{
+    fmt::{ Debug, Display, Formatter, Result as FmtResult },
+    any::Any
+};
+
+use anyhow::Result;
+
+use crate::{
+    data::{ DataCategory, DataType },
+    sensor::SensorData,
+    neuron::ActivationContext
+};
+
+use super::{
+    pointer::*,
+    neuron::Neuron,
+    collective::defining::DefiningWeightingStrategy
+};
+
+pub trait Sensor<D: SensorData>: Any + Display + ThreadSafety {
+    fn id(&self) -> u32;
+
+    fn data_type(&self) -> DataType;
+
+    fn data_category(&self) -> DataCategory;
+
+    fn insert(&mut self, item: &D) -> Ptr<Lock<dyn Neuron>>;
+
+    fn insert_custom(
+        &mut self, 
+        item: &D, 
+        weighting_strategy: Ptr<dyn DefiningWeightingStrategy>,
+        interelement_activation_threshold: f32,
+        interelement_activation_exponent: i32
+    ) -> Ptr<Lock<dyn Neuron>>;
+    
+    fn search(&self, item: &D) -> Option<Ptr<Lock<dyn Neuron>>>;
+    
+    fn fuzzy_search(
+        &mut self, item: &D, threshold: f32, perserve_inserted_neuron: bool
+    ) -> Option<(Ptr<Lock<dyn Neuron>>, f32)>;
+
+    fn remove(&mut self, item: &D) -> bool;
+
+    fn activate(
+        &mut self, 
+        item: &D, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool
+    ) -> Result<f32>;
+
+    /// `activate` accumulating in `context`, missing items are not inserted
+    fn activate_in(
+        &self, 
+        item: &D, 
+        signal: f32, 
+        propagate_horizontal: bool, 
+        propagate_vertical: bool,
+        context: &mut ActivationContext
+    ) -> Result<f32>;
+
+    fn activate_defining(&mut self, item: &D, signal: f32) -> Result<f32>;
+    
+    fn deactivate(
+        &mut self, item: &D, propagate_horizontal: bool, propagate_vertical: bool
+    ) -> Result<()>;
+
+    fn deactivate_sensor(&mut self);
+
+    fn neurons(&self) -> Vec<Ptr<Lock<dyn Neuron>>>;
+
+    fn values(&self) -> Vec<D>;
+
+    // fn iterator(&self) -> Vec<D>;
+}
+
+impl<D: SensorData> Debug for dyn Sensor<D> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self)
+    }
+}
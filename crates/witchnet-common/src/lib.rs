@@ -1,12 +1,17 @@
 #![feature(negative_impls)]
 #![feature(auto_traits)]
+#![allow(clippy::duplicate_mod)] // `generic` sources are compiled once per pointer family
 
 pub mod sensor;
 pub mod distances;
 pub mod data;
+pub mod temporal;
+pub mod tokenizer;
 pub mod algorithms;
 pub mod neuron;
 pub mod connection;
 pub mod polars;
 pub mod performance;
-pub mod benchmark;
\ No newline at end of file
+pub mod benchmark;
+pub mod synchronous;
+pub mod asynchronous;
\ No newline at end of file
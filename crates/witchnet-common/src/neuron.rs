@@ -1,19 +1,19 @@
 use std::{
-    sync::{ Arc, RwLock },
-    rc::Rc, 
-    cell::RefCell,
     hash::Hash,
-    fmt::{ Debug, Display, Formatter, Result as FmtResult }
+    collections::HashMap,
+    fmt::{ Display, Formatter, Result as FmtResult }
 };
 
-use anyhow::Result;
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
 
-use crate::{
-    connection::ConnectionKind, 
-    data::{ DataTypeValue, DataType }
+pub use crate::{
+    synchronous::neuron::Neuron,
+    asynchronous::neuron::Neuron as NeuronAsync
 };
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NeuronID {
     pub id: u32,
     pub parent_id: u32
@@ -31,126 +31,70 @@ impl Display for NeuronID {
     }
 }
 
-pub trait Neuron {
-    fn id(&self) -> NeuronID;
-
-    fn value(&self) -> DataTypeValue;
-
-    fn activation(&self) -> f32;
-
-    fn is_sensor(&self) -> bool;
-
-    fn data_type(&self) -> DataType;
-
-    fn increment_counter(&mut self) -> usize;
-
-    fn decrement_counter(&mut self) -> usize;
-
-    fn counter(&self) -> usize;
-
-    fn explain(&self) -> &[Rc<RefCell<dyn Neuron>>];
-
-    fn explain_one(&self, parent: u32) -> Option<DataTypeValue>;
-
-    fn defined_neurons(&self) -> &[Rc<RefCell<dyn Neuron>>];
-
-    fn activate(
-        &mut self, signal: f32, propagate_horizontal: bool, propagate_vertical: bool
-    ) -> f32;
-
-    fn activate_defining(&mut self, signal: f32) -> f32;
-
-    fn deactivate(&mut self, propagate_horizontal: bool, propagate_vertical: bool);
-
-    fn connect_to(
-        &mut self, to: Rc<RefCell<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
-    ) -> Result<()>;
-
-    fn connect_bilateral(
-        &mut self, to: Rc<RefCell<dyn Neuron>>, is_to_sensor: bool, kind: ConnectionKind
-    ) -> Result<()>;
+/// Sparse per-query activations keyed by `NeuronID`, so queries leave no residue in the graph
+/// and can run side by side. Sensor elements are kept apart as their ids can overlap
+/// with the neuron ids.
+#[derive(Debug, Clone, Default)]
+pub struct ActivationContext {
+    sensors: HashMap<NeuronID, f32>,
+    neurons: HashMap<NeuronID, f32>
 }
 
-impl Display for dyn Neuron {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(
-            f, "[{}|c:{}|a:{}]",
-            self.id(), 
-            self.counter(), 
-            self.activation()
-        )
-    }
-}
+impl ActivationContext {
+    pub fn new() -> ActivationContext { ActivationContext::default() }
 
-impl Debug for dyn Neuron {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(
-            f, "[{}|c:{}|a:{}]",
-            self.id(), 
-            self.counter(), 
-            self.activation()
-        )
+    pub fn activation(&self, id: &NeuronID, is_sensor: bool) -> f32 {
+        let activations = if is_sensor { &self.sensors } else { &self.neurons };
+        activations.get(id).copied().unwrap_or_default()
     }
-}
-
-pub trait NeuronAsync: Sync + Send {
-    fn id(&self) -> NeuronID;
-
-    fn value(&self) -> DataTypeValue;
-
-    fn activation(&self) -> f32;
 
-    fn is_sensor(&self) -> bool;
-
-    fn data_type(&self) -> DataType;
-
-    fn increment_counter(&mut self) -> usize;
-    
-    fn decrement_counter(&mut self) -> usize;
-
-    fn counter(&self) -> usize;
+    /// adds `signal` and returns the accumulated activation
+    pub fn add(&mut self, id: NeuronID, is_sensor: bool, signal: f32) -> f32 {
+        let activations = if is_sensor { &mut self.sensors } else { &mut self.neurons };
+        let activation = activations.entry(id).or_default();
+        *activation += signal;
+        *activation
+    }
 
-    fn explain(&self) -> &[Arc<RwLock<dyn NeuronAsync>>];
+    /// activated neurons, sensor elements excluded
+    pub fn neurons(&self) -> impl Iterator<Item = (&NeuronID, f32)> {
+        self.neurons.iter().map(|(id, activation)| (id, *activation))
+    }
 
-    fn explain_one(&self, parent: u32) -> Option<DataTypeValue>;
+    /// activated sensor elements
+    pub fn sensors(&self) -> impl Iterator<Item = (&NeuronID, f32)> {
+        self.sensors.iter().map(|(id, activation)| (id, *activation))
+    }
 
-    fn defined_neurons(&self) -> &[Arc<RwLock<dyn NeuronAsync>>];
+    pub fn is_empty(&self) -> bool { self.sensors.is_empty() && self.neurons.is_empty() }
 
-    fn activate(
-        &mut self, signal: f32, propagate_horizontal: bool, propagate_vertical: bool
-    ) -> f32;
+    pub fn clear(&mut self) {
+        self.sensors.clear();
+        self.neurons.clear();
+    }
+}
 
-    fn activate_defining(&mut self, signal: f32) -> f32;
+#[cfg(test)]
+mod tests {
+    use super::{ ActivationContext, NeuronID };
 
-    fn deactivate(&mut self, propagate_horizontal: bool, propagate_vertical: bool);
+    #[test]
+    fn activation_context() {
+        let mut context = ActivationContext::new();
+        assert!(context.is_empty());
 
-    fn connect_to(
-        &mut self, to: Arc<RwLock<dyn NeuronAsync>>, is_to_sensor: bool, kind: ConnectionKind
-    ) -> Result<()>;
+        let id = NeuronID::new(1, 1);
+        assert_eq!(context.add(id.clone(), true, 0.5), 0.5);
+        assert_eq!(context.add(id.clone(), true, 0.25), 0.75);
+        assert_eq!(context.activation(&id, true), 0.75);
+        assert_eq!(context.activation(&id, false), 0.0);
 
-    fn connect_bilateral(
-        &mut self, to: Arc<RwLock<dyn NeuronAsync>>, is_to_sensor: bool, kind: ConnectionKind
-    ) -> Result<()>;
-}
+        context.add(id.clone(), false, 1.0);
+        assert_eq!(context.neurons().count(), 1);
+        assert_eq!(context.sensors().count(), 1);
 
-impl Display for dyn NeuronAsync {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(
-            f, "[{}|c:{}|a:{}]",
-            self.id(), 
-            self.counter(), 
-            self.activation()
-        )
+        context.clear();
+        assert!(context.is_empty());
+        assert_eq!(context.activation(&id, true), 0.0);
     }
 }
-
-impl Debug for dyn NeuronAsync {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(
-            f, "[{}|c:{}|a:{}]",
-            self.id(), 
-            self.counter(), 
-            self.activation()
-        )
-    }
-}
\ No newline at end of file
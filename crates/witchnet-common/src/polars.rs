@@ -7,7 +7,10 @@ use polars::prelude::*;
 
 use regex::Regex;
 
-use crate::data::DataTypeValue;
+use crate::{
+    data::DataTypeValue,
+    temporal::{ Date, DateTime, Duration }
+};
 
 pub enum DataVec {
     BoolVec(Vec<bool>),
@@ -70,6 +73,9 @@ pub enum DataVecOption {
     Float32Vec(Vec<Option<f32>>),
     Float64Vec(Vec<Option<f64>>),
     Utf8Vec(Vec<Option<Arc<str>>>),
+    DateVec(Vec<Option<Date>>),
+    DateTimeVec(Vec<Option<DateTime>>),
+    DurationVec(Vec<Option<Duration>>),
     Unknown
 }
 
@@ -112,6 +118,9 @@ impl DataVecOption {
             DataVecOption::Utf8Vec(v) => if let Some(x) = v.get(index) { 
                 if let Some(ix) = x.clone() { Some(ix.into()) } else { None } 
             } else { None },
+            DataVecOption::DateVec(v) => v.get(index).copied().flatten().map(DataTypeValue::from),
+            DataVecOption::DateTimeVec(v) => v.get(index).copied().flatten().map(DataTypeValue::from),
+            DataVecOption::DurationVec(v) => v.get(index).copied().flatten().map(DataTypeValue::from),
             DataVecOption::Unknown => None
         }
     }
@@ -119,7 +128,11 @@ impl DataVecOption {
 
 pub fn csv_to_dataframe(filename: &str, skip: &[&str]) -> PolarsResult<DataFrame> {
     let file = File::open(filename)?;
-    let mut df = CsvReader::new(file).infer_schema(None).has_header(true).finish()?;
+    let mut df = CsvReader::new(file)
+        .infer_schema(None)
+        .has_header(true)
+        .with_parse_dates(true)
+        .finish()?;
     for column_name in skip {
         let _ = df.drop_in_place(column_name)?;
         log::info!("skipping {column_name} since it is on skip list");
@@ -185,6 +198,19 @@ pub fn series_to_datavec(series: &Series) -> PolarsResult<DataVecOption> {
                 .map(|x| match x { Some(y) => Some(Arc::from(y)), None => None })
                 .collect()
         )),
+        DataType::Date => Ok(DataVecOption::DateVec(
+            series.date()?.into_iter().map(|x| x.map(Date)).collect()
+        )),
+        DataType::Datetime(time_unit, _) => Ok(DataVecOption::DateTimeVec(
+            series.datetime()?.into_iter()
+                .map(|x| x.map(|y| DateTime::from_time_unit(y, *time_unit)))
+                .collect()
+        )),
+        DataType::Duration(time_unit) => Ok(DataVecOption::DurationVec(
+            series.duration()?.into_iter()
+                .map(|x| x.map(|y| Duration::from_time_unit(y, *time_unit)))
+                .collect()
+        )),
         _ => Ok(DataVecOption::Unknown)
     }
 }
@@ -229,6 +255,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn temporal_series_to_datavec() {
+        use polars::prelude::*;
+
+        use crate::temporal::{ Date, DateTime, Duration };
+
+        let dates = Series::new("dates", &[Some(19_270i32), None]).cast(&DataType::Date).unwrap();
+        let date_times = Series::new("date_times", &[Some(1_500_000i64)])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap();
+        let durations = Series::new("durations", &[Some(2_000_000_000i64)])
+            .cast(&DataType::Duration(TimeUnit::Nanoseconds))
+            .unwrap();
+
+        let datavec = super::series_to_datavec(&dates).unwrap();
+        assert_eq!(datavec.get(0), Some(Date(19_270).into()));
+        assert_eq!(datavec.get(1), None);
+        let datavec = super::series_to_datavec(&date_times).unwrap();
+        assert_eq!(datavec.get(0), Some(DateTime(1_500).into()));
+        let datavec = super::series_to_datavec(&durations).unwrap();
+        assert_eq!(datavec.get(0), Some(Duration(2_000).into()));
+    }
+
     #[test]
     fn string_to_vec() {
         assert_eq!(super::string_to_vec("a"), vec!["a"]);
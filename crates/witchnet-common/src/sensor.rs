@@ -1,28 +1,27 @@
 use std::{
-    sync::{ Arc, RwLock },
+    sync::Arc,
     rc::Rc,
     cell::RefCell,
-    fmt::{ Debug, Display, Formatter, Result as FmtResult },
+    fmt::Display,
     cmp::Ordering,
     any::Any,
     marker::PhantomData,
     mem
 };
 
-use anyhow::Result;
-
 use num_traits::ToPrimitive;
 
 use dyn_clone::DynClone;
 
 use crate::{
     data::{ DataCategory, DataType, DataTypeValue, DataDeductor, UnknownDataTypeMarker },
-    neuron::{ Neuron, NeuronAsync },
-    distances::Distance, 
-    connection::collective::defining::{
-        DefiningWeightingStrategy, 
-        DefiningWeightingStrategyAsync
-    }
+    distances::Distance,
+    temporal::{ Date, DateTime, Duration }
+};
+
+pub use crate::{
+    synchronous::sensor::Sensor,
+    asynchronous::sensor::Sensor as SensorAsync
 };
 
 pub trait AnyCast {
@@ -87,7 +86,8 @@ macro_rules! impl_sensor_data_categoric {
 impl_sensor_data_numeric! { 
     i8, i16, i32, i64, i128, isize,
     u8, u16, u32, u64, u128, usize,
-    f32, f64
+    f32, f64,
+    Date, DateTime, Duration
 }
 
 impl_sensor_data_categoric! {
@@ -138,114 +138,6 @@ impl<T: UnknownDataTypeMarker + SensorDataMarker> DataDeductor for PhantomData<T
     fn data_category(&self) -> DataCategory { DataCategory::Categorical }
 }
 
-pub trait Sensor<D: SensorData>: Any + Display {
-    fn id(&self) -> u32;
-
-    fn data_type(&self) -> DataType;
-
-    fn data_category(&self) -> DataCategory;
-
-    fn insert(&mut self, item: &D) -> Rc<RefCell<dyn Neuron>>;
-
-    fn insert_custom(
-        &mut self, 
-        item: &D, 
-        weighting_strategy: Rc<dyn DefiningWeightingStrategy>,
-        interelement_activation_threshold: f32,
-        interelement_activation_exponent: i32
-    ) -> Rc<RefCell<dyn Neuron>>;
-    
-    fn search(&self, item: &D) -> Option<Rc<RefCell<dyn Neuron>>>;
-    
-    fn fuzzy_search(
-        &mut self, item: &D, threshold: f32, perserve_inserted_neuron: bool
-    ) -> Option<(Rc<RefCell<dyn Neuron>>, f32)>;
-
-    fn remove(&mut self, item: &D) -> bool;
-
-    fn activate(
-        &mut self, 
-        item: &D, 
-        signal: f32, 
-        propagate_horizontal: bool, 
-        propagate_vertical: bool
-    ) -> Result<f32>;
-
-    fn activate_defining(&mut self, item: &D, signal: f32) -> Result<f32>;
-    
-    fn deactivate(
-        &mut self, item: &D, propagate_horizontal: bool, propagate_vertical: bool
-    ) -> Result<()>;
-
-    fn deactivate_sensor(&mut self);
-
-    fn neurons(&self) -> Vec<Rc<RefCell<dyn Neuron>>>;
-
-    fn values(&self) -> Vec<D>;
-
-    // fn iterator(&self) -> Vec<D>;
-}
-
-impl<D: SensorData> Debug for dyn Sensor<D> {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "{}", self)
-    }
-}
-
-pub trait SensorAsync<D: SensorData>: Any + Display + Sync + Send {
-    fn id(&self) -> u32;
-
-    fn data_type(&self) -> DataType;
-
-    fn data_category(&self) -> DataCategory;
-
-    fn insert(&mut self, item: &D) -> Arc<RwLock<dyn NeuronAsync>>;
-
-    fn insert_custom(
-        &mut self, 
-        item: &D, 
-        weighting_strategy: Arc<dyn DefiningWeightingStrategyAsync>,
-        interelement_activation_threshold: f32,
-        interelement_activation_exponent: i32
-    ) -> Arc<RwLock<dyn NeuronAsync>>;
-    
-    fn search(&self, item: &D) -> Option<Arc<RwLock<dyn NeuronAsync>>>;
-
-    fn fuzzy_search(
-        &mut self, item: &D, threshold: f32, perserve_inserted_neuron: bool
-    ) -> Option<(Arc<RwLock<dyn NeuronAsync>>, f32)>;
-
-    fn remove(&mut self, item: &D) -> bool;
-
-    fn activate(
-        &mut self, 
-        item: &D, 
-        signal: f32, 
-        propagate_horizontal: bool, 
-        propagate_vertical: bool
-    ) -> Result<f32>;
-
-    fn activate_defining(&mut self, item: &D, signal: f32) -> Result<f32>;
-    
-    fn deactivate(
-        &mut self, item: &D, propagate_horizontal: bool, propagate_vertical: bool
-    ) -> Result<()>;
-
-    fn deactivate_sensor(&mut self);
-
-    fn neurons(&self) -> Vec<Arc<RwLock<dyn NeuronAsync>>>;
-
-    fn values(&self) -> Vec<D>;
-
-    // fn iterator(&self) -> Vec<D>;
-}
-
-impl<D: SensorData> Debug for dyn SensorAsync<D> {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "{}", self)
-    }
-}
-
 pub trait SensorDynamicDowncast<D: SensorData> {
     fn sensor_dynamic_downcast(
         sensor: Rc<RefCell<dyn Sensor<dyn SensorData>>>
@@ -0,0 +1,19 @@
+//! `Rc<RefCell>` instantiation of the sources in `generic`
+
+#[path = "../generic/neuron.rs"]
+pub mod neuron;
+#[path = "../generic/sensor.rs"]
+pub mod sensor;
+#[path = "../generic/collective/mod.rs"]
+pub mod collective;
+
+pub mod pointer {
+    pub use std::{
+        rc::{ Rc as Ptr, Weak as WeakPtr },
+        cell::RefCell as Lock
+    };
+
+    pub trait ThreadSafety {}
+
+    impl<T: ?Sized> ThreadSafety for T {}
+}
@@ -0,0 +1,218 @@
+use std::{
+    fmt::{ Display, Formatter, Result as FmtResult },
+    str::FromStr
+};
+
+use anyhow::Result;
+
+use chrono::{ NaiveDate, NaiveTime, Datelike, Timelike };
+
+use num_traits::ToPrimitive;
+
+use polars::prelude::TimeUnit;
+
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
+
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+const MILLIS_PER_DAY: i64 = 86_400_000;
+const MILLIS_PER_HOUR: i64 = 3_600_000;
+
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"
+];
+
+/// Calendar date kept as days since 1970-01-01, the same way polars keeps it
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Date(pub i32);
+
+/// Timezone-less timestamp kept as milliseconds since 1970-01-01 00:00:00
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DateTime(pub i64);
+
+/// Time span in milliseconds
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Duration(pub i64);
+
+impl Date {
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Date> {
+        Some(NaiveDate::from_ymd_opt(year, month, day)?.into())
+    }
+
+    pub fn naive(&self) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(self.0.checked_add(UNIX_EPOCH_DAYS_FROM_CE)?)
+    }
+
+    /// Day of the week, 0 is monday
+    pub fn weekday(&self) -> u8 { (self.0 as i64 + 3).rem_euclid(7) as u8 }
+
+    /// Month of the year, 1 is january
+    pub fn month(&self) -> Option<u8> { Some(self.naive()?.month() as u8) }
+}
+
+impl DateTime {
+    pub fn from_ymd_hms(
+        year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32
+    ) -> Option<DateTime> {
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+        let time = NaiveTime::from_hms_opt(hour, minute, second)?;
+        Some(DateTime::from_date_time(date, time))
+    }
+
+    pub fn from_time_unit(value: i64, time_unit: TimeUnit) -> DateTime {
+        DateTime(millis(value, time_unit))
+    }
+
+    pub fn date(&self) -> Date { Date(self.0.div_euclid(MILLIS_PER_DAY) as i32) }
+
+    /// Day of the week, 0 is monday
+    pub fn weekday(&self) -> u8 { self.date().weekday() }
+
+    /// Month of the year, 1 is january
+    pub fn month(&self) -> Option<u8> { self.date().month() }
+
+    pub fn hour(&self) -> u8 { (self.0.rem_euclid(MILLIS_PER_DAY) / MILLIS_PER_HOUR) as u8 }
+
+    fn from_date_time(date: NaiveDate, time: NaiveTime) -> DateTime {
+        let days = Date::from(date).0 as i64;
+        let millis = time.num_seconds_from_midnight() as i64 * 1000
+            + time.nanosecond() as i64 / 1_000_000;
+        DateTime(days * MILLIS_PER_DAY + millis)
+    }
+}
+
+impl Duration {
+    pub fn from_time_unit(value: i64, time_unit: TimeUnit) -> Duration {
+        Duration(millis(value, time_unit))
+    }
+}
+
+fn millis(value: i64, time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Nanoseconds => value.div_euclid(1_000_000),
+        TimeUnit::Microseconds => value.div_euclid(1_000),
+        TimeUnit::Milliseconds => value
+    }
+}
+
+impl From<NaiveDate> for Date {
+    fn from(date: NaiveDate) -> Date { Date(date.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE) }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.naive() {
+            Some(date) => write!(f, "{date}"),
+            None => write!(f, "{}d", self.0)
+        }
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let millis = self.0.rem_euclid(MILLIS_PER_DAY);
+        let time = NaiveTime::from_num_seconds_from_midnight_opt(
+            (millis / 1000) as u32, (millis % 1000) as u32 * 1_000_000
+        );
+        match (self.date().naive(), time) {
+            (Some(date), Some(time)) => write!(f, "{}", date.and_time(time)),
+            _ => write!(f, "{}ms", self.0)
+        }
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult { write!(f, "{}ms", self.0) }
+}
+
+impl FromStr for Date {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Date> {
+        Ok(NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")?.into())
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<DateTime> {
+        let s = s.trim();
+        for format in DATE_TIME_FORMATS {
+            if let Ok(date_time) = chrono::NaiveDateTime::parse_from_str(s, format) {
+                return Ok(DateTime::from_date_time(date_time.date(), date_time.time()))
+            }
+        }
+        Ok(DateTime::from_date_time(NaiveDate::parse_from_str(s, "%Y-%m-%d")?, NaiveTime::MIN))
+    }
+}
+
+impl FromStr for Duration {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Duration> {
+        let s = s.trim();
+        Ok(Duration(s.strip_suffix("ms").unwrap_or(s).trim().parse()?))
+    }
+}
+
+macro_rules! impl_to_primitive {
+    ( $($t:ty),* ) => {
+        $( impl ToPrimitive for $t {
+            fn to_i64(&self) -> Option<i64> { Some(self.0 as i64) }
+            fn to_u64(&self) -> Option<u64> { self.0.to_u64() }
+            fn to_f64(&self) -> Option<f64> { Some(self.0 as f64) }
+        }) *
+    }
+}
+
+impl_to_primitive! { Date, DateTime, Duration }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use polars::prelude::TimeUnit;
+
+    use super::{ Date, DateTime, Duration };
+
+    #[test]
+    fn date() {
+        assert_eq!(Date::from_ymd(1970, 1, 1).unwrap(), Date(0));
+        let date: Date = "2022-10-05".parse().unwrap();
+        assert_eq!(date, Date::from_ymd(2022, 10, 5).unwrap());
+        assert_eq!(date.to_string(), "2022-10-05");
+        assert_eq!(date.weekday(), 2);
+        assert_eq!(date.month(), Some(10));
+        assert_eq!(Date(-1).to_string(), "1969-12-31");
+        assert_eq!(Date(-1).weekday(), 2);
+        assert!("05.10.2022".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn date_time() {
+        let date_time: DateTime = "2022-10-05 13:45:10".parse().unwrap();
+        assert_eq!(date_time, DateTime::from_ymd_hms(2022, 10, 5, 13, 45, 10).unwrap());
+        assert_eq!(date_time, "2022-10-05T13:45:10".parse().unwrap());
+        assert_eq!(date_time.to_string(), "2022-10-05 13:45:10");
+        assert_eq!(date_time.date(), Date::from_ymd(2022, 10, 5).unwrap());
+        assert_eq!(date_time.hour(), 13);
+        assert_eq!(date_time.weekday(), 2);
+        assert_eq!(date_time.month(), Some(10));
+
+        let midnight: DateTime = "2022-10-05".parse().unwrap();
+        assert_eq!(midnight.hour(), 0);
+        assert_eq!(date_time.0 - midnight.0, (13 * 3600 + 45 * 60 + 10) * 1000);
+
+        let nanos = date_time.0 * 1_000_000 + 999_999;
+        assert_eq!(DateTime::from_time_unit(nanos, TimeUnit::Nanoseconds), date_time);
+        assert_eq!(DateTime(-1).to_string(), "1969-12-31 23:59:59.999");
+        assert_eq!(DateTime(-1).hour(), 23);
+
+        assert_eq!("1500ms".parse::<Duration>().unwrap(), Duration(1500));
+        assert_eq!(Duration::from_time_unit(1500, TimeUnit::Microseconds).to_string(), "1ms");
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
+
+use crate::polars::string_to_vec;
+
+const WORD_SEPARATORS: &str = ".,;:!?|/\\()[]{}<>\"'`";
+
+/// Splits a text cell into the set of tokens connected to one object,
+/// `[a, b]` lists are unpacked before splitting
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tokenizer {
+    pub separators: Vec<char>,
+    pub split_whitespace: bool,
+    pub lowercase: bool,
+    pub min_length: usize
+}
+
+impl Tokenizer {
+    /// Items of comma, semicolon or pipe separated lists
+    pub fn list() -> Tokenizer {
+        Tokenizer {
+            separators: vec![',', ';', '|'],
+            split_whitespace: false,
+            lowercase: false,
+            min_length: 1
+        }
+    }
+
+    /// Lowercase words of free text
+    pub fn words() -> Tokenizer {
+        Tokenizer {
+            separators: WORD_SEPARATORS.chars().collect(),
+            split_whitespace: true,
+            lowercase: true,
+            min_length: 2
+        }
+    }
+
+    /// Distinct tokens of `text` in the order of their first occurrence
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = Vec::new();
+        for item in string_to_vec(text) {
+            let parts = item.split(|c: char| {
+                self.separators.contains(&c) || (self.split_whitespace && c.is_whitespace())
+            });
+            for part in parts {
+                let part = part.trim();
+                let token = if self.lowercase { part.to_lowercase() } else { part.to_string() };
+                if token.chars().count() < self.min_length.max(1) || tokens.contains(&token) {
+                    continue
+                }
+                tokens.push(token);
+            }
+        }
+        tokens
+    }
+
+    /// Text of `tokens` that tokenizes back into them
+    pub fn join(&self, tokens: &[String]) -> String {
+        match self.separators.first() {
+            Some(separator) if !self.split_whitespace => tokens.join(&format!("{separator} ")),
+            _ => tokens.join(" ")
+        }
+    }
+}
+
+impl Default for Tokenizer {
+    fn default() -> Tokenizer { Tokenizer::list() }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Tokenizer;
+
+    #[test]
+    fn tokenize() {
+        let list = Tokenizer::list();
+        assert_eq!(
+            list.tokenize("leather, sunroof, navigation"), vec!["leather", "sunroof", "navigation"]
+        );
+        assert_eq!(list.tokenize("['leather', 'sunroof']"), vec!["leather", "sunroof"]);
+        assert_eq!(list.tokenize("leather;Leather | leather,,"), vec!["leather", "Leather"]);
+        assert_eq!(list.tokenize("heated seats"), vec!["heated seats"]);
+        assert!(list.tokenize(" , ").is_empty());
+
+        let words = Tokenizer::words();
+        assert_eq!(
+            words.tokenize("Heated seats, a (new) Sunroof! seats"),
+            vec!["heated", "seats", "new", "sunroof"]
+        );
+    }
+
+    #[test]
+    fn join() {
+        let tokens = vec!["leather".to_string(), "heated seats".to_string()];
+        let list = Tokenizer::list();
+        assert_eq!(list.join(&tokens), "leather, heated seats");
+        assert_eq!(list.tokenize(&list.join(&tokens)), tokens);
+
+        let words = Tokenizer::words();
+        let tokens = vec!["heated".to_string(), "seats".to_string()];
+        assert_eq!(words.tokenize(&words.join(&tokens)), tokens);
+    }
+}
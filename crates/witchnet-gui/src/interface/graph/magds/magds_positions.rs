@@ -10,7 +10,8 @@ use rand::{thread_rng, seq::SliceRandom};
 use witchnet_common::{
     sensor::{SensorAsync, SensorData},
     neuron::{NeuronID, NeuronAsync}, 
-    data::{ DataType, DataTypeValue, DataDeductor }
+    data::{ DataType, DataTypeValue, DataDeductor },
+    temporal::{ Date, DateTime, Duration }
 };
 
 use asa_graphs::neural_async::{
@@ -294,6 +295,21 @@ fn sensor_to_asa_3_levels(
             let graph = ASAGraph::<f64, 3>::new_box_from_vec(sensor_id, &data);
             levels(magds, &graph)
         }
+        DataType::Date => {
+            let data: Vec<Date> = data.into_iter().map(|x| *x.as_date().unwrap()).collect();
+            let graph = ASAGraph::<Date, 3>::new_box_from_vec(sensor_id, &data);
+            levels(magds, &graph)
+        }
+        DataType::DateTime => {
+            let data: Vec<DateTime> = data.into_iter().map(|x| *x.as_date_time().unwrap()).collect();
+            let graph = ASAGraph::<DateTime, 3>::new_box_from_vec(sensor_id, &data);
+            levels(magds, &graph)
+        }
+        DataType::Duration => {
+            let data: Vec<Duration> = data.into_iter().map(|x| *x.as_duration().unwrap()).collect();
+            let graph = ASAGraph::<Duration, 3>::new_box_from_vec(sensor_id, &data);
+            levels(magds, &graph)
+        }
         DataType::ArcStr => {
             let data: Vec<Arc<str>> = data.into_iter().map(
                 |x| x.as_arc_str().unwrap().clone()
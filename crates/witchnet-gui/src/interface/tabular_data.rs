@@ -177,7 +177,8 @@ pub(crate) fn add_magds_button_row(
                             data_file.random_pick,
                             Arc::new(ConstantOneWeightAsync),
                             0.00001,
-                            1
+                            1,
+                            false
                         );
                     }
 